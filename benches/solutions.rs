@@ -0,0 +1,50 @@
+//! Criterion benchmarks of every solution with its input on disk, run with `cargo bench`. Days
+//! that are `Solver`s are timed per stage, parsing apart from each part, the others as a whole.
+//! `cargo bench -- 2023/01` only runs the benchmarks of that day.
+
+use advent_of_code::{ inputs, registry::Registry, solution::RuntimeTier };
+use criterion::{ criterion_group, criterion_main, Criterion };
+use std::hint::black_box;
+use std::time::Duration;
+
+fn solutions(c: &mut Criterion) {
+    let registry = Registry::builtin();
+    for date in registry.dates() {
+        let solution = registry.get(date).unwrap();
+        let metadata = solution.metadata();
+        let Ok(input) = inputs::read(&metadata) else {
+            eprintln!("{date}: {}: no input, skipped", metadata.title);
+            continue;
+        };
+
+        let mut group = c.benchmark_group(format!("{}/{:02}", date.year(), date.day()));
+        // Slow days would take minutes at the default 100 samples
+        if metadata.runtime == RuntimeTier::Slow {
+            group.sample_size(10).measurement_time(Duration::from_secs(30));
+        }
+
+        match solution.stages() {
+            Some(stages) => {
+                let parsed = match stages.parse(&input) {
+                    Ok(parsed) => parsed,
+                    Err(err) => {
+                        eprintln!("{date}: {}: {err}", metadata.title);
+                        continue;
+                    }
+                };
+
+                group.bench_function("parse", |b| b.iter(|| stages.parse(black_box(&input))));
+                group.bench_function("part_1", |b| b.iter(|| stages.part_1(black_box(parsed.as_ref()))));
+                group.bench_function("part_2", |b| b.iter(|| stages.part_2(black_box(parsed.as_ref()))));
+            },
+            None => {
+                group.bench_function("solve", |b| b.iter(|| solution.solve(black_box(&input))));
+            }
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
@@ -0,0 +1,6 @@
+use aoc_2022::d01::CalorieCounting;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&CalorieCounting, "inputs/2022/01/CalorieCounting.txt");
+}
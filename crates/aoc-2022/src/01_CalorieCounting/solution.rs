@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse calories: {0}")]
+    ParsingError(Excerpt),
+    #[error("Expected at least three elves, found {0}")]
+    NotEnoughElves(usize)
+}
+
+/// The calories carried by each elf, the most first.
+fn parse_input(input: &str) -> Result<Vec<u64>, Error> {
+    let mut loads: Vec<u64> = blocks(input)
+        .map(|block| block.lines().map(|line| line.trim().parse::<u64>().map_err(|_| Error::ParsingError(line.into()))).sum())
+        .collect::<Result<_, _>>()?;
+
+    loads.sort_by(|left, right| right.cmp(left));
+    Ok(loads)
+}
+
+pub struct CalorieCounting;
+
+impl Solver for CalorieCounting {
+    type Parsed = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, loads: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(loads.first().copied().ok_or(Error::NotEnoughElves(0))?.into())
+    }
+
+    fn part_2(&self, loads: &Self::Parsed) -> Result<Answer, SolutionError> {
+        match loads.len() {
+            len if len < 3 => Err(Error::NotEnoughElves(len).into()),
+            _ => Ok(loads[..3].iter().sum::<u64>().into())
+        }
+    }
+}
+
+impl Solution for CalorieCounting {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 1,
+            title: "Calorie Counting",
+            tags: &["sorting"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_input(EXAMPLE).unwrap(), vec![24000, 11000, 10000, 6000, 4000]);
+        assert!(matches!(parse_input("1000\nabc"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(CalorieCounting.solve(EXAMPLE).unwrap(), (24000.into(), 45000.into()));
+        assert!(CalorieCounting.solve("1000\n\n2000").is_err());
+    }
+
+    #[test]
+    fn test_stages() {
+        let stages = CalorieCounting.stages().unwrap();
+        let loads = stages.parse(EXAMPLE).unwrap();
+        assert_eq!(stages.part_2(loads.as_ref()).unwrap(), 45000.into());
+        assert!(stages.part_1(&"not the loads").is_err());
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d02::RockPaperScissors;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&RockPaperScissors, "inputs/2022/02/RockPaperScissors.txt");
+}
@@ -0,0 +1,161 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse round: {0}")]
+    ParsingError(Excerpt)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Outcome {
+    Loss,
+    Draw,
+    Win
+}
+
+impl Shape {
+    const ALL: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+
+    fn score(self) -> u64 {
+        self as u64 + 1
+    }
+
+    fn beats(self) -> Shape {
+        match self {
+            Shape::Rock => Shape::Scissors,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissors => Shape::Paper
+        }
+    }
+
+    fn against(self, opponent: Shape) -> Outcome {
+        if self == opponent { Outcome::Draw }
+        else if self.beats() == opponent { Outcome::Win }
+        else { Outcome::Loss }
+    }
+}
+
+impl Outcome {
+    fn score(self) -> u64 {
+        self as u64 * 3
+    }
+}
+
+/// The opponent's shape, and the second column that each part reads differently.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Round {
+    opponent: Shape,
+    column: usize
+}
+
+fn parse_round(line: &str) -> Result<Round, Error> {
+    match line.trim_end().as_bytes() {
+        [opponent @ b'A'..=b'C', b' ', column @ b'X'..=b'Z'] => {
+            Ok(Round { opponent: Shape::ALL[(opponent - b'A') as usize], column: (column - b'X') as usize })
+        },
+        _ => Err(Error::ParsingError(line.into()))
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Round>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_round).collect()
+}
+
+fn score(own: Shape, opponent: Shape) -> u64 {
+    own.score() + own.against(opponent).score()
+}
+
+/// The shape that gives `outcome` against `opponent`.
+fn shape_for(opponent: Shape, outcome: Outcome) -> Shape {
+    Shape::ALL.into_iter().find(|own| own.against(opponent) == outcome).unwrap()
+}
+
+pub struct RockPaperScissors;
+
+impl Solver for RockPaperScissors {
+    type Parsed = Vec<Round>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    /// The second column is the shape to play.
+    fn part_1(&self, rounds: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(rounds.iter().map(|round| score(Shape::ALL[round.column], round.opponent)).sum::<u64>().into())
+    }
+
+    /// The second column is how the round must end.
+    fn part_2(&self, rounds: &Self::Parsed) -> Result<Answer, SolutionError> {
+        let outcomes = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+        Ok(rounds.iter().map(|round| score(shape_for(round.opponent, outcomes[round.column]), round.opponent)).sum::<u64>().into())
+    }
+}
+
+impl Solution for RockPaperScissors {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 2,
+            title: "Rock Paper Scissors",
+            tags: &["simulation"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_round("B Z").unwrap(), Round { opponent: Shape::Paper, column: 2 });
+        for invalid in ["D X", "A W", "A  X", "abcdefg", "a x"] {
+            assert!(parse_round(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(score(Shape::Rock, Shape::Rock), 4);
+        assert_eq!(score(Shape::Rock, Shape::Paper), 1);
+        assert_eq!(score(Shape::Rock, Shape::Scissors), 7);
+        assert_eq!(score(Shape::Paper, Shape::Rock), 8);
+        assert_eq!(score(Shape::Scissors, Shape::Paper), 9);
+        assert_eq!(score(Shape::Scissors, Shape::Scissors), 6);
+    }
+
+    #[test]
+    fn test_shape_for() {
+        assert_eq!(shape_for(Shape::Rock, Outcome::Loss), Shape::Scissors);
+        assert_eq!(shape_for(Shape::Paper, Outcome::Draw), Shape::Paper);
+        assert_eq!(shape_for(Shape::Scissors, Outcome::Win), Shape::Rock);
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(RockPaperScissors.solve(EXAMPLE).unwrap(), (15.into(), 12.into()));
+    }
+}
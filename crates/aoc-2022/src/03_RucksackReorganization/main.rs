@@ -0,0 +1,6 @@
+use aoc_2022::d03::RucksackReorganization;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&RucksackReorganization, "inputs/2022/03/RucksackReorganization.txt");
+}
@@ -0,0 +1,131 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse rucksack: {0}")]
+    ParsingError(Excerpt),
+    #[error("The number of rucksacks isn't a multiple of three")]
+    IncompleteGroup
+}
+
+/// A set of items, as a bit per priority.
+type Items = u64;
+
+fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None
+    }
+}
+
+fn priorities(items: Items) -> u64 {
+    (1..=52).filter(|priority| items & (1 << priority) != 0).sum()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rucksack {
+    left: Items,
+    right: Items
+}
+
+impl Rucksack {
+    fn all(&self) -> Items {
+        self.left | self.right
+    }
+}
+
+fn parse_rucksack(line: &str) -> Result<Rucksack, Error> {
+    let make_error = || Error::ParsingError(line.into());
+    if line.is_empty() || !line.len().is_multiple_of(2) {
+        return Err(make_error());
+    }
+
+    let compartment = |items: &str| items.chars().try_fold(0, |set, item| Some(set | 1 << priority(item)?)).ok_or_else(make_error);
+    let (left, right) = line.split_at(line.len() / 2);
+    Ok(Rucksack { left: compartment(left)?, right: compartment(right)? })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Rucksack>, Error> {
+    input.lines().map(str::trim).filter(|line| !line.is_empty()).map(parse_rucksack).collect()
+}
+
+pub struct RucksackReorganization;
+
+impl Solver for RucksackReorganization {
+    type Parsed = Vec<Rucksack>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    /// The items in both compartments of each rucksack.
+    fn part_1(&self, rucksacks: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(rucksacks.iter().map(|rucksack| priorities(rucksack.left & rucksack.right)).sum::<u64>().into())
+    }
+
+    /// The badges, i.e. the items common to each group of three rucksacks.
+    fn part_2(&self, rucksacks: &Self::Parsed) -> Result<Answer, SolutionError> {
+        if rucksacks.len() % 3 != 0 {
+            return Err(Error::IncompleteGroup.into());
+        }
+
+        let badges = rucksacks.chunks(3).map(|group| priorities(group.iter().fold(Items::MAX, |common, rucksack| common & rucksack.all())));
+        Ok(badges.sum::<u64>().into())
+    }
+}
+
+impl Solution for RucksackReorganization {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 3,
+            title: "Rucksack Reorganization",
+            tags: &["sets", "bit-manipulation"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn test_priority() {
+        assert_eq!((priority('a'), priority('z'), priority('A'), priority('Z')), (Some(1), Some(26), Some(27), Some(52)));
+        assert_eq!(priority('1'), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let rucksack = parse_rucksack("abCa").unwrap();
+        assert_eq!(priorities(rucksack.left & rucksack.right), 1);
+        assert!(parse_rucksack("abc").is_err());
+        assert!(parse_rucksack("ab1c").is_err());
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(RucksackReorganization.solve(EXAMPLE).unwrap(), (157.into(), 70.into()));
+        assert!(RucksackReorganization.solve("abab\nabab").is_err());
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d04::CampCleanup;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&CampCleanup, "inputs/2022/04/CampCleanup.txt");
+}
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse pair of sections: {0}")]
+    ParsingError(Excerpt)
+}
+
+fn parse_pair(line: &str) -> Result<(Interval, Interval), Error> {
+    let make_error = || Error::ParsingError(line.into());
+    let parse_sections = |sections: &str| {
+        let (start, end) = sections.split_once('-')?;
+        Interval::new(start.parse().ok()?, end.parse().ok()?)
+    };
+
+    let (left, right) = line.trim().split_once(',').ok_or_else(make_error)?;
+    Ok((parse_sections(left).ok_or_else(make_error)?, parse_sections(right).ok_or_else(make_error)?))
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Interval, Interval)>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_pair).collect()
+}
+
+/// Whether one of the intervals holds the whole other one.
+fn nested(left: &Interval, right: &Interval) -> bool {
+    left.intersection(right).is_some_and(|common| common == *left || common == *right)
+}
+
+pub struct CampCleanup;
+
+impl Solver for CampCleanup {
+    type Parsed = Vec<(Interval, Interval)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, pairs: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(pairs.iter().filter(|(left, right)| nested(left, right)).count().into())
+    }
+
+    fn part_2(&self, pairs: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(pairs.iter().filter(|(left, right)| left.intersection(right).is_some()).count().into())
+    }
+}
+
+impl Solution for CampCleanup {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 4,
+            title: "Camp Cleanup",
+            tags: &["intervals"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_pair("2-4,6-8").unwrap(), (Interval::new(2, 4).unwrap(), Interval::new(6, 8).unwrap()));
+        assert!(parse_pair("4-2,6-8").is_err());
+        assert!(parse_pair("2-4;6-8").is_err());
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(CampCleanup.solve(EXAMPLE).unwrap(), (2.into(), 4.into()));
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d05::SupplyStacks;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&SupplyStacks, "inputs/2022/05/SupplyStacks.txt");
+}
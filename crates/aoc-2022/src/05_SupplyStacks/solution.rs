@@ -0,0 +1,166 @@
+use crate::prelude::*;
+use smallvec::SmallVec;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse line: {0}")]
+    ParsingError(Excerpt),
+    #[error("Expected the drawing of the stacks and the rearrangement procedure")]
+    MissingSection,
+    #[error("There is no stack {0}")]
+    OutOfBoundsStack(usize),
+    #[error("Stack {0} doesn't have enough crates to move")]
+    StackUnderflow(usize)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Instruction {
+    amount: usize,
+    from: usize,
+    to: usize
+}
+
+/// Stacks rarely grow past a few dozen crates, so they're kept inline
+type Stack = SmallVec<[char; 64]>;
+
+pub struct Procedure {
+    stacks: Vec<Stack>,
+    instructions: Vec<Instruction>
+}
+
+/// Crates are drawn bottom up, one `[X]` every four columns, over a line labelling the stacks.
+fn parse_stacks(drawing: &str) -> Result<Vec<Stack>, Error> {
+    let mut rows = drawing.lines().rev();
+    let labels = rows.next().ok_or(Error::MissingSection)?;
+    let mut stacks = vec![Stack::new(); labels.split_whitespace().count()];
+
+    for row in rows {
+        for (index, label) in row.chars().skip(1).step_by(4).enumerate() {
+            match label {
+                ' ' => continue,
+                label if label.is_alphanumeric() => stacks.get_mut(index).ok_or_else(|| Error::ParsingError(row.into()))?.push(label),
+                _ => return Err(Error::ParsingError(row.into()))
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction, Error> {
+    lazy_static! {
+        static ref INSTRUCTION_REGEX: Regex = Regex::new(r"^move (?<amount>\d+) from (?<from>\d+) to (?<to>\d+)$").unwrap();
+    }
+
+    let captures = INSTRUCTION_REGEX.captures(line.trim()).ok_or_else(|| Error::ParsingError(line.into()))?;
+    let number = |name: &str| captures[name].parse::<usize>().map_err(|_| Error::ParsingError(line.into()));
+    Ok(Instruction { amount: number("amount")?, from: number("from")?, to: number("to")? })
+}
+
+fn parse_input(input: &str) -> Result<Procedure, Error> {
+    let (drawing, procedure) = match split_blocks(input)[..] {
+        [drawing, procedure] => (drawing, procedure),
+        _ => return Err(Error::MissingSection)
+    };
+
+    let stacks = parse_stacks(drawing)?;
+    let instructions = procedure.lines().map(parse_instruction).collect::<Result<_, _>>()?;
+    Ok(Procedure { stacks, instructions })
+}
+
+/// Runs the procedure, with a crane that either moves crates one at a time or several at once.
+fn rearrange(procedure: &Procedure, many_at_once: bool) -> Result<Vec<Stack>, Error> {
+    let mut stacks = procedure.stacks.clone();
+
+    for instruction in &procedure.instructions {
+        let from = stacks.get_mut(instruction.from.wrapping_sub(1)).ok_or(Error::OutOfBoundsStack(instruction.from))?;
+        let remaining = from.len().checked_sub(instruction.amount).ok_or(Error::StackUnderflow(instruction.from))?;
+        let mut moved: Stack = from.drain(remaining..).collect();
+        if !many_at_once {
+            moved.reverse();
+        }
+
+        stacks.get_mut(instruction.to.wrapping_sub(1)).ok_or(Error::OutOfBoundsStack(instruction.to))?.extend(moved);
+    }
+
+    Ok(stacks)
+}
+
+fn topmost_crates(stacks: &[Stack]) -> String {
+    stacks.iter().map(|stack| stack.last().copied().unwrap_or(' ')).collect()
+}
+
+pub struct SupplyStacks;
+
+impl Solver for SupplyStacks {
+    type Parsed = Procedure;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, procedure: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(topmost_crates(&rearrange(procedure, false)?).into())
+    }
+
+    fn part_2(&self, procedure: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(topmost_crates(&rearrange(procedure, true)?).into())
+    }
+}
+
+impl Solution for SupplyStacks {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 5,
+            title: "Supply Stacks",
+            tags: &["parsing", "simulation"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn test_parse() {
+        let procedure = parse_input(EXAMPLE).unwrap();
+        assert_eq!(procedure.stacks, vec![Stack::from_slice(&['Z', 'N']), Stack::from_slice(&['M', 'C', 'D']), Stack::from_slice(&['P'])]);
+        assert_eq!(procedure.instructions[1], Instruction { amount: 3, from: 1, to: 3 });
+        assert!(matches!(parse_input(" 1 \n\nmove one from 1 to 2"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(SupplyStacks.solve(EXAMPLE).unwrap(), ("CMZ".into(), "MCD".into()));
+    }
+
+    #[test]
+    fn test_invalid_moves() {
+        assert!(SupplyStacks.solve(" 1 \n\nmove 1 from 1 to 2").is_err());
+        assert!(matches!(rearrange(&parse_input("[A]\n 1 \n\nmove 2 from 1 to 1").unwrap(), false), Err(Error::StackUnderflow(1))));
+        assert!(matches!(rearrange(&parse_input("[A]\n 1 \n\nmove 1 from 1 to 4").unwrap(), false), Err(Error::OutOfBoundsStack(4))));
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d06::TuningTrouble;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&TuningTrouble, "inputs/2022/06/TuningTrouble.txt");
+}
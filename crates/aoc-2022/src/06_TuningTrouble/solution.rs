@@ -0,0 +1,81 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("No {0} distinct characters in a row")]
+    MarkerNotFound(usize)
+}
+
+/// The number of characters read when the last `size` of them were all different.
+fn find_marker(datastream: &[u8], size: usize) -> Result<usize, Error> {
+    datastream
+        .windows(size)
+        .position(|window| window.iter().fold(0u128, |seen, byte| seen | 1 << (byte & 0x7f)).count_ones() as usize == size)
+        .map(|start| start + size)
+        .ok_or(Error::MarkerNotFound(size))
+}
+
+pub struct TuningTrouble;
+
+impl Solver for TuningTrouble {
+    type Parsed = Vec<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(input.trim().as_bytes().to_vec())
+    }
+
+    fn part_1(&self, datastream: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(find_marker(datastream, 4)?.into())
+    }
+
+    fn part_2(&self, datastream: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(find_marker(datastream, 14)?.into())
+    }
+}
+
+impl Solution for TuningTrouble {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 6,
+            title: "Tuning Trouble",
+            tags: &["sliding-window"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let examples = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsqg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26)
+        ];
+
+        for (datastream, start_of_packet, start_of_message) in examples {
+            assert_eq!(TuningTrouble.solve(datastream).unwrap(), (start_of_packet.into(), start_of_message.into()), "{datastream}");
+        }
+    }
+
+    #[test]
+    fn test_marker_at_the_end() {
+        assert_eq!(find_marker(b"aabcd", 4).unwrap(), 5);
+        assert!(matches!(find_marker(b"aabb", 4), Err(Error::MarkerNotFound(4))));
+    }
+}
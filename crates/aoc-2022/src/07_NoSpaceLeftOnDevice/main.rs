@@ -0,0 +1,6 @@
+use aoc_2022::d07::NoSpaceLeftOnDevice;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&NoSpaceLeftOnDevice, "inputs/2022/07/NoSpaceLeftOnDevice.txt");
+}
@@ -0,0 +1,148 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse line: {0}")]
+    ParsingError(Excerpt),
+    #[error("Can't leave the root directory")]
+    NoParentDirectory,
+    #[error("Even deleting everything doesn't free {0} bytes")]
+    NotEnoughSpace(u64)
+}
+
+const TOTAL_SPACE: u64 = 70_000_000;
+const NEEDED_SPACE: u64 = 30_000_000;
+const SMALL_DIRECTORY: u64 = 100_000;
+
+/// The total size of every directory in the terminal output, the root first. A file's size is
+/// added to each directory on the current path, and files seen again when a directory is
+/// listed twice are only counted once.
+fn parse_input(input: &str) -> Result<Vec<u64>, Error> {
+    let mut sizes = vec![0];
+    let mut children: Vec<HashMap<String, usize>> = vec![HashMap::default()];
+    let mut files: HashSet<(usize, String)> = HashSet::default();
+    let mut path = vec![0];
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let current = path[path.len() - 1];
+        match line.split_whitespace().collect_vec()[..] {
+            ["$", "cd", "/"] => path.truncate(1),
+            ["$", "cd", ".."] if path.len() == 1 => return Err(Error::NoParentDirectory),
+            ["$", "cd", ".."] => { path.pop(); },
+            ["$", "cd", name] => {
+                let next = sizes.len();
+                let child = *children[current].entry(name.to_string()).or_insert(next);
+                if child == next {
+                    sizes.push(0);
+                    children.push(HashMap::default());
+                }
+                path.push(child);
+            },
+            ["$", "ls"] | ["dir", _] => continue,
+            [size, name] => {
+                let size: u64 = size.parse().map_err(|_| Error::ParsingError(line.into()))?;
+                if files.insert((current, name.to_string())) {
+                    path.iter().for_each(|directory| sizes[*directory] += size);
+                }
+            },
+            _ => return Err(Error::ParsingError(line.into()))
+        }
+    }
+
+    Ok(sizes)
+}
+
+/// The smallest directory that frees enough space for the update once deleted.
+fn find_directory_to_delete(sizes: &[u64]) -> Result<u64, Error> {
+    let used = sizes[0];
+    let to_free = (used + NEEDED_SPACE).saturating_sub(TOTAL_SPACE);
+    sizes.iter().copied().filter(|size| *size >= to_free).min().ok_or(Error::NotEnoughSpace(to_free))
+}
+
+pub struct NoSpaceLeftOnDevice;
+
+impl Solver for NoSpaceLeftOnDevice {
+    type Parsed = Vec<u64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, sizes: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(sizes.iter().filter(|size| **size <= SMALL_DIRECTORY).sum::<u64>().into())
+    }
+
+    fn part_2(&self, sizes: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(find_directory_to_delete(sizes)?.into())
+    }
+}
+
+impl Solution for NoSpaceLeftOnDevice {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 7,
+            title: "No Space Left On Device",
+            tags: &["parsing", "tree"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_input(EXAMPLE).unwrap(), vec![48381165, 94853, 584, 24933642]);
+        assert!(matches!(parse_input("$ cd /\n$ cd .."), Err(Error::NoParentDirectory)));
+        assert!(matches!(parse_input("$ cd /\n$ ls\nbig b.txt"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_listed_twice() {
+        let twice = format!("{EXAMPLE}$ cd /\n$ ls\n14848514 b.txt\n");
+        assert_eq!(parse_input(&twice).unwrap(), parse_input(EXAMPLE).unwrap());
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(NoSpaceLeftOnDevice.solve(EXAMPLE).unwrap(), (95437.into(), 24933642.into()));
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d08::TreetopTreeHouse;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&TreetopTreeHouse, "inputs/2022/08/TreetopTreeHouse.txt");
+}
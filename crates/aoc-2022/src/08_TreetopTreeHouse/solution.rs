@@ -0,0 +1,110 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse tree heights: {0}")]
+    ParsingError(#[from] grid::Error)
+}
+
+fn parse_input(input: &str) -> Result<Grid<u8>, Error> {
+    Ok(Grid::parse_digits(input)?)
+}
+
+/// How many trees can be seen from `position` looking towards `direction`, and whether the view
+/// reaches the edge of the forest, in which case the tree itself can be seen from outside.
+fn look(forest: &Grid<u8>, position: Vec2, direction: Direction) -> (u64, bool) {
+    let height = forest[position];
+    let mut seen = 0;
+    let mut current = position + direction.offset();
+    while let Some(tree) = forest.get(current) {
+        seen += 1;
+        if *tree >= height {
+            return (seen, false);
+        }
+        current += direction.offset();
+    }
+
+    (seen, true)
+}
+
+fn count_visible_trees(forest: &Grid<u8>) -> usize {
+    forest.positions()
+        .filter(|position| Direction::ALL.iter().any(|direction| look(forest, *position, *direction).1))
+        .count()
+}
+
+fn find_best_scenic_score(forest: &Grid<u8>) -> u64 {
+    forest.positions()
+        .map(|position| Direction::ALL.iter().map(|direction| look(forest, position, *direction).0).product())
+        .max()
+        .unwrap_or_default()
+}
+
+pub struct TreetopTreeHouse;
+
+impl Solver for TreetopTreeHouse {
+    type Parsed = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, forest: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(count_visible_trees(forest).into())
+    }
+
+    fn part_2(&self, forest: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(find_best_scenic_score(forest).into())
+    }
+}
+
+impl Solution for TreetopTreeHouse {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 8,
+            title: "Treetop Tree House",
+            tags: &["grid"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn test_look() {
+        let forest = parse_input(EXAMPLE).unwrap();
+        let tree = Vec2::new(2, 3);
+        assert_eq!(look(&forest, tree, Direction::Up), (2, false));
+        assert_eq!(look(&forest, tree, Direction::Left), (2, true));
+        assert_eq!(look(&forest, tree, Direction::Down), (1, true));
+        assert_eq!(look(&forest, tree, Direction::Right), (2, false));
+        assert_eq!(look(&forest, Vec2::ZERO, Direction::Up), (0, true));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(TreetopTreeHouse.solve(EXAMPLE).unwrap(), (21.into(), 8.into()));
+        assert!(TreetopTreeHouse.solve("12\n3x").is_err());
+    }
+}
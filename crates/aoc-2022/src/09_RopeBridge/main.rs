@@ -0,0 +1,6 @@
+use aoc_2022::d09::RopeBridge;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&RopeBridge, "inputs/2022/09/RopeBridge.txt");
+}
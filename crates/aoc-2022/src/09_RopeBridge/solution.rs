@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse motion: {0}")]
+    ParsingError(Excerpt)
+}
+
+fn parse_motion(line: &str) -> Result<(Direction, usize), Error> {
+    let make_error = || Error::ParsingError(line.into());
+    let (direction, steps) = line.trim().split_once(' ').ok_or_else(make_error)?;
+    Ok((direction.parse().map_err(|_| make_error())?, steps.parse().map_err(|_| make_error())?))
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Direction, usize)>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_motion).collect()
+}
+
+/// Moves the head one step at a time, each knot following the one before it whenever they stop
+/// touching, and counts the positions the tail visits.
+fn count_tail_positions(motions: &[(Direction, usize)], knots: usize) -> usize {
+    let mut rope = vec![Vec2::ZERO; knots];
+    let mut visited: HashSet<Vec2> = HashSet::from_iter([Vec2::ZERO]);
+
+    for (direction, steps) in motions {
+        for _ in 0..*steps {
+            rope[0] += direction.offset();
+            for knot in 1..rope.len() {
+                if rope[knot - 1].chebyshev_distance(rope[knot]) > 1 {
+                    let pull = rope[knot - 1] - rope[knot];
+                    rope[knot] += Vec2::new(pull.x.signum(), pull.y.signum());
+                }
+            }
+
+            visited.insert(rope[rope.len() - 1]);
+        }
+    }
+
+    visited.len()
+}
+
+pub struct RopeBridge;
+
+impl Solver for RopeBridge {
+    type Parsed = Vec<(Direction, usize)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, motions: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(count_tail_positions(motions, 2).into())
+    }
+
+    fn part_2(&self, motions: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(count_tail_positions(motions, 10).into())
+    }
+}
+
+impl Solution for RopeBridge {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 9,
+            title: "Rope Bridge",
+            tags: &["simulation", "geometry"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_motion("U 4").unwrap(), (Direction::Up, 4));
+        assert!(matches!(parse_motion("X 4"), Err(Error::ParsingError(_))));
+        assert!(matches!(parse_motion("R"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(RopeBridge.solve(EXAMPLE).unwrap(), (13.into(), 1.into()));
+        assert_eq!(count_tail_positions(&parse_input(LARGER_EXAMPLE).unwrap(), 10), 36);
+    }
+}
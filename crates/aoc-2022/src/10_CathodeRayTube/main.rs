@@ -0,0 +1,6 @@
+use aoc_2022::d10::CathodeRayTube;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&CathodeRayTube, "inputs/2022/10/CathodeRayTube.txt");
+}
@@ -0,0 +1,145 @@
+use crate::prelude::*;
+use crate::{ vm::{ self, Decode, Effect, Instruction, Machine, Register, Registers } };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to decode program: {0}")]
+    DecodingError(#[from] vm::Error)
+}
+
+const X: Register = 0;
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum CpuInstruction {
+    Noop,
+    Addx(i64)
+}
+
+impl Decode for CpuInstruction {
+    fn decode(line: &str) -> Option<Self> {
+        match line.split_once(' ') {
+            Some(("addx", value)) => value.parse().ok().map(CpuInstruction::Addx),
+            None if line == "noop" => Some(CpuInstruction::Noop),
+            _ => None
+        }
+    }
+}
+
+impl Instruction for CpuInstruction {
+    fn execute(&self, registers: &mut Registers) -> Effect {
+        if let CpuInstruction::Addx(value) = self {
+            *registers.get_mut(X) += value;
+        }
+
+        Effect::Next
+    }
+
+    fn cycles(&self) -> usize {
+        match self {
+            CpuInstruction::Noop => 1,
+            CpuInstruction::Addx(_) => 2
+        }
+    }
+}
+
+fn make_cpu(program: Vec<CpuInstruction>) -> Machine<CpuInstruction> {
+    let mut cpu = Machine::new(program, 1);
+    cpu.registers_mut().set(X, 1);
+    cpu
+}
+
+fn sum_signal_strengths(program: Vec<CpuInstruction>) -> i64 {
+    let mut sum = 0;
+    make_cpu(program).run_traced(|event| {
+        if event.cycle % 40 == 20 && event.cycle <= 220 {
+            sum += event.cycle as i64 * event.registers.get(X);
+        }
+    });
+
+    sum
+}
+
+fn render_crt(program: Vec<CpuInstruction>) -> Vec<Vec<bool>> {
+    let mut pixels = vec![vec![false; CRT_WIDTH]; CRT_HEIGHT];
+    make_cpu(program).run_traced(|event| {
+        let position = event.cycle - 1;
+        let (row, column) = (position / CRT_WIDTH, position % CRT_WIDTH);
+        if row < CRT_HEIGHT {
+            pixels[row][column] = (event.registers.get(X) - column as i64).abs() <= 1;
+        }
+    });
+
+    pixels
+}
+
+pub struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 10,
+            title: "Cathode-Ray Tube",
+            tags: &["vm", "ocr"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let program: Vec<CpuInstruction> = vm::decode_program(input).map_err(Error::from)?;
+        let signal_strength = sum_signal_strengths(program.clone());
+        Ok((signal_strength.into(), Answer::Grid(render_crt(program))))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn decode(source: &str) -> Vec<CpuInstruction> {
+        vm::decode_program(source).unwrap()
+    }
+
+    #[test]
+    fn test_decode() {
+        assert!(matches!(CpuInstruction::decode("noop"), Some(CpuInstruction::Noop)));
+        assert!(matches!(CpuInstruction::decode("addx -5"), Some(CpuInstruction::Addx(-5))));
+        assert!(CpuInstruction::decode("addx").is_none());
+        assert!(CpuInstruction::decode("mulx 3").is_none());
+    }
+
+    #[test]
+    fn test_register_during_cycles() {
+        let mut values = vec![];
+        let mut cpu = make_cpu(decode("noop\naddx 3\naddx -5"));
+        cpu.run_traced(|event| values.push(event.registers.get(X)));
+
+        assert_eq!(values, vec![1, 1, 1, 4, 4]);
+        assert_eq!(cpu.registers().get(X), -1);
+    }
+
+    #[test]
+    fn test_signal_strength() {
+        // X is 3 from cycle 20 and 5 from cycle 60 onwards, and the cycles past 220 don't count
+        let mut source = vec!["noop"; 17];
+        source.push("addx 2");
+        source.extend(vec!["noop"; 38]);
+        source.push("addx 2");
+        source.extend(vec!["noop"; 300]);
+
+        assert_eq!(sum_signal_strengths(decode(&source.join("\n"))), 20 * 3 + 5 * (60 + 100 + 140 + 180 + 220));
+    }
+
+    #[test]
+    fn test_render_sprite() {
+        let pixels = render_crt(decode(&vec!["noop"; CRT_WIDTH * CRT_HEIGHT].join("\n")));
+        for row in pixels {
+            assert_eq!(row.iter().filter(|lit| **lit).count(), 3);
+            assert!(row[0] && row[1] && row[2]);
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d11::MonkeyInTheMiddle;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&MonkeyInTheMiddle, "inputs/2022/11/MonkeyInTheMiddle.txt");
+}
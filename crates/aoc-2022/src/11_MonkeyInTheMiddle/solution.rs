@@ -0,0 +1,244 @@
+use crate::prelude::*;
+use crate::math::lcm;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse monkey:\n{0}")]
+    ParsingError(Excerpt)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Operand {
+    Old,
+    Value(u64)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Operation {
+    Add(Operand),
+    Multiply(Operand)
+}
+
+impl Operation {
+    fn apply(&self, old: u64) -> u64 {
+        let value_of = |operand: &Operand| match operand {
+            Operand::Old => old,
+            Operand::Value(value) => *value
+        };
+
+        match self {
+            Operation::Add(operand) => old + value_of(operand),
+            Operation::Multiply(operand) => old * value_of(operand)
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    if_true: usize,
+    if_false: usize
+}
+
+#[derive(Clone, Copy)]
+enum WorryRelief {
+    DivideByThree,
+    /// Worry levels are only ever tested for divisibility, so they can be kept modulo
+    /// the LCM of all divisors without changing where items get thrown.
+    ModuloLcm(u64)
+}
+
+fn parse_monkey(block: &str) -> Result<Monkey, Error> {
+    lazy_static! {
+        static ref MONKEY_REGEX: Regex = Regex::new(concat!(
+            r"Monkey \d+:\s+",
+            r"Starting items: (?<items>[\d, ]*)\s+",
+            r"Operation: new = old (?<operator>[*+]) (?<operand>old|\d+)\s+",
+            r"Test: divisible by (?<divisor>\d+)\s+",
+            r"If true: throw to monkey (?<if_true>\d+)\s+",
+            r"If false: throw to monkey (?<if_false>\d+)"
+        )).unwrap();
+    }
+
+    let make_error = || Error::ParsingError(block.into());
+    let captures = MONKEY_REGEX.captures(block).ok_or_else(make_error)?;
+
+    let items = captures["items"]
+        .split(',')
+        .map(|item| item.trim().parse::<u64>())
+        .try_collect()
+        .map_err(|_| make_error())?;
+
+    let operand = match &captures["operand"] {
+        "old" => Operand::Old,
+        value => Operand::Value(value.parse().map_err(|_| make_error())?)
+    };
+
+    let operation = match &captures["operator"] {
+        "+" => Operation::Add(operand),
+        _ => Operation::Multiply(operand)
+    };
+
+    let parse_number = |name: &str| captures[name].parse::<u64>().map_err(|_| make_error());
+    let divisor = parse_number("divisor")?;
+    if divisor == 0 {
+        return Err(make_error());
+    }
+
+    Ok(Monkey {
+        items,
+        operation,
+        divisor,
+        if_true: parse_number("if_true")? as usize,
+        if_false: parse_number("if_false")? as usize
+    })
+}
+
+/// The monkeys, as long as they only throw to each other.
+fn parse_input(input: &str) -> Result<Vec<Monkey>, Error> {
+    let blocks = split_blocks(input);
+    let monkeys: Vec<Monkey> = blocks.iter().map(|block| parse_monkey(block)).try_collect()?;
+    let stray = monkeys.iter().position(|monkey| monkey.if_true.max(monkey.if_false) >= monkeys.len());
+    match stray {
+        Some(index) => Err(Error::ParsingError(blocks[index].into())),
+        None => Ok(monkeys)
+    }
+}
+
+fn count_inspections(monkeys: &[Monkey], rounds: usize, relief: WorryRelief) -> Vec<usize> {
+    let mut monkeys = monkeys.to_vec();
+    let mut inspections = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for idx in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[idx].items);
+            inspections[idx] += items.len();
+
+            for item in items {
+                let monkey = &monkeys[idx];
+                let worry = match relief {
+                    WorryRelief::DivideByThree => monkey.operation.apply(item) / 3,
+                    WorryRelief::ModuloLcm(modulo) => monkey.operation.apply(item) % modulo
+                };
+
+                let target = if worry % monkey.divisor == 0 { monkey.if_true } else { monkey.if_false };
+                monkeys[target].items.push(worry);
+            }
+        }
+    }
+
+    inspections
+}
+
+fn get_monkey_business(inspections: &[usize]) -> usize {
+    inspections.iter().sorted().rev().take(2).product()
+}
+
+pub struct MonkeyInTheMiddle;
+
+impl Solution for MonkeyInTheMiddle {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 11,
+            title: "Monkey in the Middle",
+            tags: &["simulation", "number-theory"],
+            runtime: RuntimeTier::Fast,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let monkeys = parse_input(input)?;
+        let divisors_lcm = monkeys.iter().map(|monkey| monkey.divisor).fold(1, lcm);
+
+        let solution_1 = get_monkey_business(&count_inspections(&monkeys, 20, WorryRelief::DivideByThree));
+        let solution_2 = get_monkey_business(&count_inspections(&monkeys, 10000, WorryRelief::ModuloLcm(divisors_lcm)));
+        Ok((solution_1.into(), solution_2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn test_parse_monkey() {
+        let monkeys = parse_input(EXAMPLE).unwrap();
+        assert_eq!(monkeys.len(), 4);
+        assert_eq!(monkeys[1].items, vec![54, 65, 75, 74]);
+        assert_eq!(monkeys[1].operation, Operation::Add(Operand::Value(6)));
+        assert_eq!(monkeys[2].operation, Operation::Multiply(Operand::Old));
+        assert_eq!(monkeys[2].divisor, 13);
+        assert_eq!(monkeys[2].if_true, 1);
+        assert_eq!(monkeys[2].if_false, 3);
+    }
+
+    #[test]
+    fn test_parse_invalid_monkey() {
+        assert!(parse_input("Monkey 0:\n  Starting items: 1\n  Operation: new = old - 2").is_err());
+
+        let monkey = |divisor: u64, target: usize| format!("\
+Monkey 0:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by {divisor}
+    If true: throw to monkey 0
+    If false: throw to monkey {target}
+");
+        assert!(parse_input(&monkey(2, 0)).is_ok());
+        assert!(parse_input(&monkey(2, 3)).is_err());
+        assert!(parse_input(&monkey(0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_with_relief() {
+        let monkeys = parse_input(EXAMPLE).unwrap();
+        let inspections = count_inspections(&monkeys, 20, WorryRelief::DivideByThree);
+        assert_eq!(inspections, vec![101, 95, 7, 105]);
+        assert_eq!(get_monkey_business(&inspections), 10605);
+    }
+
+    #[test]
+    fn test_without_relief() {
+        let monkeys = parse_input(EXAMPLE).unwrap();
+        let inspections = count_inspections(&monkeys, 10000, WorryRelief::ModuloLcm(96577));
+        assert_eq!(inspections, vec![52166, 47830, 1938, 52013]);
+        assert_eq!(get_monkey_business(&inspections), 2713310158);
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d12::HillClimbingAlgorithm;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&HillClimbingAlgorithm, "inputs/2022/12/HillClimbingAlgorithm.txt");
+}
@@ -0,0 +1,112 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse heightmap: {0}")]
+    ParsingError(#[from] grid::Error),
+    #[error("No path leads to the best signal")]
+    NoPath
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Heightmap {
+    elevations: Grid<u8>,
+    start: Vec2,
+    end: Vec2
+}
+
+fn parse_input(input: &str) -> Result<Heightmap, Error> {
+    let (elevations, markers) = Grid::parse_with_markers(input, &[('S', 'a'), ('E', 'z')], |ch| {
+        ch.is_ascii_lowercase().then(|| ch as u8 - b'a')
+    })?;
+
+    Ok(Heightmap { elevations, start: markers.require('S')?, end: markers.require('E')? })
+}
+
+/// Climbing at most one unit per step.
+fn climbable(from: &u8, to: &u8) -> bool {
+    *to <= from + 1
+}
+
+/// Number of steps from the start to the end.
+fn find_shortest_climb(heightmap: &Heightmap) -> Option<usize> {
+    heightmap.elevations.shortest_path(heightmap.start, heightmap.end, climbable).map(|path| path.len() - 1)
+}
+
+/// Number of steps from the closest lowest point to the end, searching from all of them at once.
+fn find_shortest_hike(heightmap: &Heightmap) -> Option<usize> {
+    let elevations = &heightmap.elevations;
+    let lowest = elevations.iter().filter(|(_, elevation)| **elevation == 0).map(|(position, _)| position);
+    elevations.shortest_path_from_any(lowest, heightmap.end, climbable).map(|path| path.len() - 1)
+}
+
+pub struct HillClimbingAlgorithm;
+
+impl Solution for HillClimbingAlgorithm {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 12,
+            title: "Hill Climbing Algorithm",
+            tags: &["grid", "bfs"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let heightmap = parse_input(input)?;
+
+        let solution_1 = find_shortest_climb(&heightmap).ok_or(Error::NoPath)?;
+        let solution_2 = find_shortest_hike(&heightmap).ok_or(Error::NoPath)?;
+
+        Ok((solution_1.into(), solution_2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+    #[test]
+    fn test_parse() {
+        let heightmap = parse_input(EXAMPLE).unwrap();
+        assert_eq!(heightmap.start, Vec2::new(0, 0));
+        assert_eq!(heightmap.end, Vec2::new(5, 2));
+        assert_eq!(heightmap.elevations[heightmap.start], 0);
+        assert_eq!(heightmap.elevations[heightmap.end], 25);
+        assert_eq!(heightmap.elevations[Vec2::new(2, 0)], 1);
+    }
+
+    #[test]
+    fn test_missing_marker() {
+        assert!(matches!(parse_input("Sab"), Err(Error::ParsingError(grid::Error::MissingMarker('E')))));
+    }
+
+    #[test]
+    fn test_from_start() {
+        let heightmap = parse_input(EXAMPLE).unwrap();
+        assert_eq!(find_shortest_climb(&heightmap), Some(31));
+    }
+
+    #[test]
+    fn test_from_any_lowest_point() {
+        let heightmap = parse_input(EXAMPLE).unwrap();
+        assert_eq!(find_shortest_hike(&heightmap), Some(29));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let heightmap = parse_input("SazE").unwrap();
+        assert_eq!(find_shortest_climb(&heightmap), None);
+        assert_eq!(find_shortest_hike(&heightmap), None);
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d13::DistressSignal;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&DistressSignal, "inputs/2022/13/DistressSignal.txt");
+}
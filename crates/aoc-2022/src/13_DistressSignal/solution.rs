@@ -0,0 +1,239 @@
+use crate::prelude::*;
+use std::{ cmp::Ordering, iter::Peekable, str::Chars };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse packet: {0}")]
+    ParsingError(Excerpt),
+    #[error("Expected a pair of packets, found:\n{0}")]
+    InvalidPair(Excerpt)
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Packet {
+    Integer(u32),
+    List(Vec<Packet>)
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Integer(left), Packet::Integer(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => left.cmp(right),
+            (Packet::Integer(left), Packet::List(right)) => [Packet::Integer(*left)].as_slice().cmp(right.as_slice()),
+            (Packet::List(left), Packet::Integer(right)) => left.as_slice().cmp([Packet::Integer(*right)].as_slice())
+        }
+    }
+}
+
+/// Equal as ordered, so an integer equals the list of just that integer, as `sort` and `dedup`
+/// expect of `Eq` and `Ord` together.
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Recursive descent over `packet := integer | '[' (packet (',' packet)*)? ']'`.
+struct PacketParser<'a> {
+    chars: Peekable<Chars<'a>>
+}
+
+impl<'a> PacketParser<'a> {
+    fn parse(line: &'a str) -> Result<Packet, Error> {
+        let mut parser = PacketParser { chars: line.trim().chars().peekable() };
+        let packet = parser.parse_packet().ok_or_else(|| Error::ParsingError(line.into()))?;
+
+        match parser.chars.next() {
+            None => Ok(packet),
+            Some(_) => Err(Error::ParsingError(line.into()))
+        }
+    }
+
+    fn parse_packet(&mut self) -> Option<Packet> {
+        match self.chars.peek()? {
+            '[' => self.parse_list(),
+            ch if ch.is_ascii_digit() => self.parse_integer(),
+            _ => None
+        }
+    }
+
+    fn parse_list(&mut self) -> Option<Packet> {
+        self.expect('[')?;
+
+        let mut items = vec![];
+        if self.chars.next_if_eq(&']').is_some() {
+            return Some(Packet::List(items));
+        }
+
+        loop {
+            items.push(self.parse_packet()?);
+            match self.chars.next()? {
+                ',' => continue,
+                ']' => return Some(Packet::List(items)),
+                _ => return None
+            }
+        }
+    }
+
+    fn parse_integer(&mut self) -> Option<Packet> {
+        let mut value: u32 = 0;
+        while let Some(digit) = self.chars.peek().and_then(|ch| ch.to_digit(10)) {
+            value = value.checked_mul(10)?.checked_add(digit)?;
+            self.chars.next();
+        }
+
+        Some(Packet::Integer(value))
+    }
+
+    fn expect(&mut self, expected: char) -> Option<()> {
+        self.chars.next_if_eq(&expected).map(|_| ())
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Packet, Packet)>, Error> {
+    blocks(input)
+        .map(|block| match block.lines().collect::<Vec<_>>().as_slice() {
+            [left, right] => Ok((PacketParser::parse(left)?, PacketParser::parse(right)?)),
+            _ => Err(Error::InvalidPair(block.into()))
+        })
+        .collect()
+}
+
+fn sum_ordered_pair_indices(pairs: &[(Packet, Packet)]) -> usize {
+    pairs
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| left < right)
+        .map(|(idx, _)| idx + 1)
+        .sum()
+}
+
+fn get_decoder_key(pairs: &[(Packet, Packet)]) -> usize {
+    let dividers = [2, 6].map(|value| Packet::List(vec![Packet::List(vec![Packet::Integer(value)])]));
+
+    // Sorting isn't needed: a divider's position is one plus the number of packets smaller than it
+    dividers
+        .iter()
+        .enumerate()
+        .map(|(idx, divider)| {
+            let smaller = pairs.iter().flat_map(|(left, right)| [left, right]).filter(|packet| *packet < divider).count();
+            smaller + idx + 1
+        })
+        .product()
+}
+
+pub struct DistressSignal;
+
+impl Solution for DistressSignal {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 13,
+            title: "Distress Signal",
+            tags: &["parsing", "ordering"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let pairs = parse_input(input)?;
+        Ok((sum_ordered_pair_indices(&pairs).into(), get_decoder_key(&pairs).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+    fn packet(line: &str) -> Packet {
+        PacketParser::parse(line).unwrap()
+    }
+
+    /// Packets that are equal can still differ in structure, which the parser has to get right.
+    fn assert_parses(line: &str, expected: Packet) {
+        assert_eq!(format!("{:?}", packet(line)), format!("{expected:?}"));
+    }
+
+    #[test]
+    fn test_parse() {
+        use Packet::{ Integer, List };
+
+        assert_parses("[]", List(vec![]));
+        assert_parses("[10,[2,[]]]", List(vec![Integer(10), List(vec![Integer(2), List(vec![])])]));
+        assert!(PacketParser::parse("[1,2").is_err());
+        assert!(PacketParser::parse("[1,,2]").is_err());
+        assert!(PacketParser::parse("[1]]").is_err());
+    }
+
+    #[test]
+    fn test_parse_nesting() {
+        use Packet::{ Integer, List };
+
+        assert_parses("[[[]]]", List(vec![List(vec![List(vec![])])]));
+        assert_parses("[[],[[]],[]]", List(vec![List(vec![]), List(vec![List(vec![])]), List(vec![])]));
+        assert_parses("[[1],[[2]],3]", List(vec![List(vec![Integer(1)]), List(vec![List(vec![Integer(2)])]), Integer(3)]));
+        assert_parses("7", Integer(7));
+
+        for invalid in ["", "[", "]", "[[]", "[1,]", "[,1]", "[[],]", "[1][2]", "[1 2]", "[99999999999]"] {
+            assert!(PacketParser::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_ordering() {
+        let pairs = parse_input(EXAMPLE).unwrap();
+        let ordered: Vec<bool> = pairs.iter().map(|(left, right)| left < right).collect();
+        assert_eq!(ordered, vec![true, true, false, true, false, true, false, false]);
+        assert_eq!(packet("[[1],4]").cmp(&packet("[1,4]")), Ordering::Equal);
+        assert_eq!(packet("[[1],4]"), packet("[1,4]"));
+        assert_eq!(packet("7"), packet("[[7]]"));
+        assert_ne!(packet("[1,4]"), packet("[1,[4,0]]"));
+    }
+
+    #[test]
+    fn test_sum_ordered_pair_indices() {
+        assert_eq!(sum_ordered_pair_indices(&parse_input(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_decoder_key() {
+        assert_eq!(get_decoder_key(&parse_input(EXAMPLE).unwrap()), 140);
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_2022::d14::RegolithReservoir;
+use aoc_core::solution::{ self, CancellationToken };
+
+fn main() {
+    let path = "inputs/2022/14/RegolithReservoir.txt";
+
+    if std::env::args().any(|arg| arg == "--visualize") {
+        let arguments = std::env::args().skip(1).filter(|arg| arg != "--visualize");
+        // Ctrl+C stops on the current frame instead of killing the process mid-frame
+        let cancellation = CancellationToken::default();
+        let on_interrupt = cancellation.clone();
+        ctrlc::set_handler(move || on_interrupt.cancel()).expect("Failed to handle Ctrl+C");
+
+        let animation = solution::read_input(&RegolithReservoir, path, arguments)
+            .and_then(|input| RegolithReservoir.visualize(&input, &cancellation));
+
+        if let Err(err) = animation {
+            println!("{err}");
+            std::process::exit(1);
+        }
+
+        return;
+    }
+
+    solution::run_from_file(&RegolithReservoir, path);
+}
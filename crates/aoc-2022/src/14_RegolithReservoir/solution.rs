@@ -0,0 +1,212 @@
+use crate::prelude::*;
+use crate::{ solution::CancellationToken, sparse_grid::SparseGrid, visualize::{ self, Simulation, Visualize } };
+use std::{ io::stdout, time::Duration };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse rock path: {0}")]
+    ParsingError(Excerpt)
+}
+
+const SAND_SOURCE: Vec2 = Vec2::new(500, 0);
+const FALL_DIRECTIONS: [Vec2; 3] = [Vec2::new(0, 1), Vec2::new(-1, 1), Vec2::new(1, 1)];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tile {
+    Rock,
+    Sand
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Bottom {
+    /// Sand falling below the lowest rock is lost forever.
+    Abyss,
+    /// An infinite floor lies two units below the lowest rock.
+    Floor
+}
+
+#[derive(Clone)]
+struct Reservoir {
+    cave: SparseGrid<Tile>,
+    lowest_rock: i64,
+    bottom: Bottom,
+    settled: usize
+}
+
+impl Reservoir {
+    fn new(rocks: &SparseGrid<Tile>, bottom: Bottom) -> Reservoir {
+        let mut cave = rocks.clone();
+        cave.include(SAND_SOURCE);
+
+        let lowest_rock = rocks.bounds().map(|(_, max)| max.y).unwrap_or(0);
+        Reservoir { cave, lowest_rock, bottom, settled: 0 }
+    }
+
+    /// Where the next unit of sand comes to rest, if it does at all.
+    fn drop_sand(&self) -> Option<Vec2> {
+        if self.cave.contains(SAND_SOURCE) {
+            return None;
+        }
+
+        let mut position = SAND_SOURCE;
+        loop {
+            if position.y > self.lowest_rock {
+                match self.bottom {
+                    Bottom::Abyss => return None,
+                    Bottom::Floor => return Some(position)
+                }
+            }
+
+            match FALL_DIRECTIONS.iter().map(|direction| position + *direction).find(|next| !self.cave.contains(*next)) {
+                Some(next) => position = next,
+                None => return Some(position)
+            }
+        }
+    }
+}
+
+impl Simulation for Reservoir {
+    fn step(&mut self) -> bool {
+        match self.drop_sand() {
+            Some(position) => {
+                self.cave.insert(position, Tile::Sand);
+                self.settled += 1;
+                true
+            },
+            None => false
+        }
+    }
+}
+
+impl Visualize for Reservoir {
+    fn render(&self) -> String {
+        self.cave.render(|position, tile| match tile {
+            Some(Tile::Rock) => '#',
+            Some(Tile::Sand) => 'o',
+            None if position == SAND_SOURCE => '+',
+            None => '.'
+        })
+    }
+}
+
+fn parse_point(point: &str) -> Option<Vec2> {
+    let (x, y) = point.trim().split_once(',')?;
+    Some(Vec2::new(x.parse().ok()?, y.parse().ok()?))
+}
+
+fn parse_input(input: &str) -> Result<SparseGrid<Tile>, Error> {
+    let mut rocks = SparseGrid::new();
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let points: Vec<Vec2> = line
+            .split("->")
+            .map(parse_point)
+            .collect::<Option<_>>()
+            .ok_or_else(|| Error::ParsingError(line.into()))?;
+
+        for segment in points.windows(2).map(|ends| Segment::new(ends[0], ends[1])) {
+            if !segment.is_axis_aligned() {
+                return Err(Error::ParsingError(line.into()));
+            }
+
+            for position in segment.points() {
+                rocks.insert(position, Tile::Rock);
+            }
+        }
+    }
+
+    Ok(rocks)
+}
+
+fn count_settled_sand(rocks: &SparseGrid<Tile>, bottom: Bottom) -> usize {
+    let mut reservoir = Reservoir::new(rocks, bottom);
+    reservoir.run();
+    reservoir.settled
+}
+
+pub struct RegolithReservoir;
+
+impl RegolithReservoir {
+    /// Animates the sand falling into the abyss on the terminal, until done or cancelled.
+    pub fn visualize(&self, input: &str, cancellation: &CancellationToken) -> Result<(), SolutionError> {
+        let rocks = parse_input(input)?;
+        visualize::animate(&mut Reservoir::new(&rocks, Bottom::Abyss), &mut stdout(), Duration::from_millis(20), cancellation)?;
+        Ok(())
+    }
+}
+
+impl Solution for RegolithReservoir {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 14,
+            title: "Regolith Reservoir",
+            tags: &["simulation", "sparse-grid"],
+            runtime: RuntimeTier::Fast,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let rocks = parse_input(input)?;
+        Ok((count_settled_sand(&rocks, Bottom::Abyss).into(), count_settled_sand(&rocks, Bottom::Floor).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+    #[test]
+    fn test_parse() {
+        let rocks = parse_input(EXAMPLE).unwrap();
+        assert_eq!(rocks.len(), 20);
+        assert_eq!(rocks.bounds(), Some((Vec2::new(494, 4), Vec2::new(503, 9))));
+        assert!(parse_input("498,4 -> 497,5").is_err());
+        assert!(parse_input("498,4 -> 498").is_err());
+    }
+
+    #[test]
+    fn test_abyss() {
+        assert_eq!(count_settled_sand(&parse_input(EXAMPLE).unwrap(), Bottom::Abyss), 24);
+    }
+
+    #[test]
+    fn test_floor() {
+        assert_eq!(count_settled_sand(&parse_input(EXAMPLE).unwrap(), Bottom::Floor), 93);
+    }
+
+    #[test]
+    fn test_states() {
+        let mut reservoir = Reservoir::new(&parse_input(EXAMPLE).unwrap(), Bottom::Abyss);
+        let settled: Vec<usize> = reservoir.states().map(|state| state.settled).collect();
+        assert_eq!(settled, (1..=24).collect::<Vec<_>>());
+        assert_eq!(reservoir.states().count(), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut reservoir = Reservoir::new(&parse_input(EXAMPLE).unwrap(), Bottom::Abyss);
+        reservoir.step();
+        reservoir.step();
+
+        let expected = "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+........#.
+.....oo.#.
+#########.
+";
+        assert_eq!(reservoir.render(), expected);
+    }
+}
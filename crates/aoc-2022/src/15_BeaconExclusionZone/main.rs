@@ -0,0 +1,6 @@
+use aoc_2022::d15::BeaconExclusionZone;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&BeaconExclusionZone, "inputs/2022/15/BeaconExclusionZone.txt");
+}
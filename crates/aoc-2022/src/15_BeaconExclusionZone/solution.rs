@@ -0,0 +1,180 @@
+use crate::prelude::*;
+use crate::geometry::row_coverage;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse sensor: {0}")]
+    ParsingError(Excerpt),
+    #[error("No uncovered position for the distress beacon")]
+    BeaconNotFound
+}
+
+const TARGET_ROW: i64 = 2000000;
+const SEARCH_LIMIT: i64 = 4000000;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Sensor {
+    range: Diamond,
+    beacon: Vec2
+}
+
+fn parse_sensor(line: &str) -> Result<Sensor, Error> {
+    let [sensor_x, sensor_y, beacon_x, beacon_y] = ints(line)[..] else {
+        return Err(Error::ParsingError(line.into()));
+    };
+
+    let beacon = Vec2::new(beacon_x, beacon_y);
+    Ok(Sensor { range: Diamond::reaching(Vec2::new(sensor_x, sensor_y), beacon), beacon })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Sensor>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_sensor).collect()
+}
+
+fn count_excluded_positions(sensors: &[Sensor], y: i64) -> u64 {
+    let coverage = row_coverage(sensors.iter().map(|sensor| &sensor.range), y);
+    let beacons_on_row = sensors
+        .iter()
+        .map(|sensor| sensor.beacon)
+        .filter(|beacon| beacon.y == y)
+        .collect::<HashSet<_>>()
+        .len();
+
+    coverage.len() - beacons_on_row as u64
+}
+
+/// The distress beacon is the only uncovered position, so it must lie just outside the
+/// perimeter of at least two sensors. Those perimeters are made of diagonal segments
+/// (`x + y = c` or `y - x = c`), so the candidates are the crossings of such lines. Unless it's
+/// on the edge of the search area, where a single perimeter is enough: then the rows are scanned.
+fn find_distress_beacon(sensors: &[Sensor], limit: i64) -> Option<Vec2> {
+    let ascending: HashSet<i64> = sensors
+        .iter()
+        .flat_map(|sensor| {
+            let (center, radius) = (sensor.range.center, sensor.range.radius as i64);
+            let offset = center.y - center.x;
+            [offset - radius - 1, offset + radius + 1]
+        })
+        .collect();
+
+    let descending: HashSet<i64> = sensors
+        .iter()
+        .flat_map(|sensor| {
+            let (center, radius) = (sensor.range.center, sensor.range.radius as i64);
+            let offset = center.y + center.x;
+            [offset - radius - 1, offset + radius + 1]
+        })
+        .collect();
+
+    let bounds = Interval::new(0, limit)?;
+    ascending
+        .iter()
+        .cartesian_product(descending.iter())
+        .filter(|(up, down)| (*up + *down) % 2 == 0)
+        .map(|(up, down)| Vec2::new((down - up) / 2, (up + down) / 2))
+        .filter(|candidate| bounds.contains(candidate.x) && bounds.contains(candidate.y))
+        .find(|candidate| sensors.iter().all(|sensor| !sensor.range.contains(*candidate)))
+        .or_else(|| scan_rows(sensors, bounds))
+}
+
+/// The first position within `bounds` on both axes that no sensor covers, row by row.
+fn scan_rows(sensors: &[Sensor], bounds: Interval) -> Option<Vec2> {
+    (bounds.start..=bounds.end).find_map(|y| {
+        let gaps = row_coverage(sensors.iter().map(|sensor| &sensor.range), y).gaps(bounds);
+        gaps.first().map(|gap| Vec2::new(gap.start, y))
+    })
+}
+
+fn get_tuning_frequency(beacon: Vec2) -> i64 {
+    beacon.x * 4000000 + beacon.y
+}
+
+pub struct BeaconExclusionZone;
+
+impl Solution for BeaconExclusionZone {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 15,
+            title: "Beacon Exclusion Zone",
+            tags: &["intervals", "geometry"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let sensors = parse_input(input)?;
+        let beacon = find_distress_beacon(&sensors, SEARCH_LIMIT).ok_or(Error::BeaconNotFound)?;
+        Ok((count_excluded_positions(&sensors, TARGET_ROW).into(), get_tuning_frequency(beacon).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+    #[test]
+    fn test_parse() {
+        let sensors = parse_input(EXAMPLE).unwrap();
+        assert_eq!(sensors.len(), 14);
+        assert_eq!(sensors[0].range.center, Vec2::new(2, 18));
+        assert_eq!(sensors[0].beacon, Vec2::new(-2, 15));
+        assert_eq!(sensors[0].range.radius, 7);
+        assert!(parse_sensor("Sensor at x=2: closest beacon is at x=-2, y=15").is_err());
+    }
+
+    #[test]
+    fn test_excluded_positions() {
+        assert_eq!(count_excluded_positions(&parse_input(EXAMPLE).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn test_distress_beacon() {
+        let beacon = find_distress_beacon(&parse_input(EXAMPLE).unwrap(), 20);
+        assert_eq!(beacon, Some(Vec2::new(14, 11)));
+        assert_eq!(get_tuning_frequency(beacon.unwrap()), 56000011);
+    }
+
+    #[test]
+    fn test_distress_beacon_on_edge() {
+        // Only the corner is out of range, and it lies on a single perimeter
+        let sensors = parse_input("Sensor at x=3, y=3: closest beacon is at x=3, y=-2\n").unwrap();
+        assert_eq!(find_distress_beacon(&sensors, 4), Some(Vec2::new(0, 0)));
+
+        let sensors = parse_input("Sensor at x=3, y=3: closest beacon is at x=3, y=-3\n").unwrap();
+        assert_eq!(find_distress_beacon(&sensors, 4), None);
+    }
+
+    #[test]
+    fn test_distress_beacon_by_rows() {
+        // Scanning every row for a gap in the coverage finds the same beacon, only slower
+        let sensors = parse_input(EXAMPLE).unwrap();
+        let bounds = Interval::new(0, 20).unwrap();
+        let uncovered: Vec<Vec2> = (0..=20)
+            .flat_map(|y| {
+                let gaps = row_coverage(sensors.iter().map(|sensor| &sensor.range), y).gaps(bounds);
+                gaps.into_iter().flat_map(move |gap| (gap.start..=gap.end).map(move |x| Vec2::new(x, y)))
+            })
+            .collect();
+
+        assert_eq!(uncovered, vec![Vec2::new(14, 11)]);
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d16::ProboscideaVolcanium;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&ProboscideaVolcanium, "inputs/2022/16/ProboscideaVolcanium.txt");
+}
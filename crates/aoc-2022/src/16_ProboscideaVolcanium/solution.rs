@@ -0,0 +1,235 @@
+use crate::prelude::*;
+use crate::{ cache, graph::bfs_distances, solution::{ CancellationToken, Cancelled, Progress } };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse valve: {0}")]
+    ParsingError(Excerpt),
+    #[error("Unknown valve: {0}")]
+    UnknownValve(Excerpt),
+    #[error("Too many valves with a positive flow rate: {0}")]
+    TooManyValves(usize)
+}
+
+const START: &str = "AA";
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>
+}
+
+/// The valves worth opening, with the travel time between each pair of them. Valves with no
+/// flow are only ever walked through, so they're folded into the distances.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Volcano {
+    flow_rates: Vec<u32>,
+    /// `distances[i][j]` is the travel time from valve `i` to valve `j`
+    distances: Vec<Vec<u32>>,
+    /// Travel time from the start to each valve
+    from_start: Vec<u32>
+}
+
+type ValveSet = u32;
+
+fn parse_valve(line: &str) -> Result<Valve, Error> {
+    lazy_static! {
+        static ref VALVE_REGEX: Regex = Regex::new(
+            r"^Valve (?<name>\w+) has flow rate=(?<flow_rate>\d+); tunnels? leads? to valves? (?<tunnels>[\w, ]+)$"
+        ).unwrap();
+    }
+
+    let captures = VALVE_REGEX.captures(line.trim()).ok_or_else(|| Error::ParsingError(line.into()))?;
+    Ok(Valve {
+        name: captures["name"].to_string(),
+        flow_rate: captures["flow_rate"].parse().map_err(|_| Error::ParsingError(line.into()))?,
+        tunnels: captures["tunnels"].split(", ").map(str::to_string).collect()
+    })
+}
+
+fn parse_input(input: &str) -> Result<Volcano, Error> {
+    let valves: HashMap<String, Valve> = input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_valve(line).map(|valve| (valve.name.clone(), valve)))
+        .collect::<Result<_, _>>()?;
+
+    if let Some(unknown) = valves.values().flat_map(|valve| &valve.tunnels).find(|tunnel| !valves.contains_key(*tunnel)) {
+        return Err(Error::UnknownValve(unknown.as_str().into()));
+    }
+
+    let mut useful: Vec<&Valve> = valves.values().filter(|valve| valve.flow_rate > 0).collect();
+    useful.sort_by(|left, right| left.name.cmp(&right.name));
+    // One bit is kept clear so that the set of all valves doesn't overflow
+    if useful.len() >= ValveSet::BITS as usize {
+        return Err(Error::TooManyValves(useful.len()));
+    }
+
+    let distances_from = |from: &str| {
+        let distances = bfs_distances(from.to_string(), |name: &String| valves[name].tunnels.clone());
+        useful.iter().map(|valve| distances.get(&valve.name).map_or(u32::MAX, |distance| *distance as u32)).collect::<Vec<_>>()
+    };
+
+    valves.get(START).ok_or_else(|| Error::UnknownValve(START.into()))?;
+    Ok(Volcano {
+        flow_rates: useful.iter().map(|valve| valve.flow_rate).collect(),
+        distances: useful.iter().map(|valve| distances_from(&valve.name)).collect(),
+        from_start: distances_from(START)
+    })
+}
+
+/// The most pressure that can be released by opening exactly the valves in each set, for every
+/// set that can be opened in time. `on_record` is told each time a set beats all the previous.
+fn find_best_releases(
+    volcano: &Volcano,
+    minutes: u32,
+    cancellation: &CancellationToken,
+    on_record: &mut impl FnMut(u32)
+) -> Result<HashMap<ValveSet, u32>, Cancelled> {
+    struct Search<'a, F> {
+        volcano: &'a Volcano,
+        cancellation: &'a CancellationToken,
+        best: HashMap<ValveSet, u32>,
+        record: u32,
+        on_record: &'a mut F
+    }
+
+    impl<F: FnMut(u32)> Search<'_, F> {
+        fn visit(&mut self, valve: usize, minutes_left: u32, opened: ValveSet, released: u32) {
+            if self.cancellation.is_cancelled() {
+                return;
+            }
+
+            let entry = self.best.entry(opened).or_default();
+            *entry = (*entry).max(released);
+            if released > self.record {
+                self.record = released;
+                (self.on_record)(released);
+            }
+
+            for next in (0..self.volcano.flow_rates.len()).filter(|next| opened & (1 << next) == 0) {
+                // One more minute to open the valve once there
+                let Some(minutes_left) = minutes_left.checked_sub(self.volcano.distances[valve][next].saturating_add(1))
+                else {
+                    continue;
+                };
+
+                let released = released + minutes_left * self.volcano.flow_rates[next];
+                self.visit(next, minutes_left, opened | (1 << next), released);
+            }
+        }
+    }
+
+    let mut search = Search { volcano, cancellation, best: HashMap::from_iter([(0, 0)]), record: 0, on_record };
+    for first in 0..volcano.flow_rates.len() {
+        if let Some(minutes_left) = minutes.checked_sub(volcano.from_start[first].saturating_add(1)) {
+            search.visit(first, minutes_left, 1 << first, minutes_left * volcano.flow_rates[first]);
+        }
+    }
+
+    cancellation.check()?;
+    Ok(search.best)
+}
+
+fn get_max_release(volcano: &Volcano, cancellation: &CancellationToken, on_record: &mut impl FnMut(u32)) -> Result<u32, Cancelled> {
+    Ok(find_best_releases(volcano, 30, cancellation, on_record)?.into_values().max().unwrap_or(0))
+}
+
+/// You and the elephant open disjoint sets of valves, so the answer is the best split of the
+/// valves into two sets, each scored by the best release using any subset of it. Until the
+/// splits are scored, the best you can do alone is a lower bound for `on_record`.
+fn get_max_release_with_elephant(volcano: &Volcano, cancellation: &CancellationToken, on_record: &mut impl FnMut(u32)) -> Result<u32, Cancelled> {
+    let best = find_best_releases(volcano, 26, cancellation, on_record)?;
+    let mut best_within = Memo::new(|memo: &mut Memo<ValveSet, u32>, valves: ValveSet| {
+        let own = best.get(&valves).copied().unwrap_or(0);
+        (0..ValveSet::BITS)
+            .filter(|bit| valves & (1 << bit) != 0)
+            .map(|bit| memo.get(valves & !(1 << bit)))
+            .fold(own, u32::max)
+    });
+
+    let all_valves: ValveSet = (1 << volcano.flow_rates.len()) - 1;
+    Ok(best.iter()
+        .map(|(mine, released)| released + best_within.get(all_valves & !mine))
+        .max()
+        .unwrap_or(0))
+}
+
+pub struct ProboscideaVolcanium;
+
+impl Solution for ProboscideaVolcanium {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 16,
+            title: "Proboscidea Volcanium",
+            tags: &["graph", "bitmask-dp", "memoization"],
+            runtime: RuntimeTier::Fast,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        self.solve_anytime(input, &Progress::default())
+    }
+
+    fn solve_anytime(&self, input: &str, progress: &Progress) -> Result<Answers, SolutionError> {
+        let volcano = cache::parsed(&self.metadata(), input, parse_input)?;
+        let cancellation = progress.cancellation();
+        let solution_1 = tracing::info_span!("part_1").in_scope(|| get_max_release(&volcano, cancellation, &mut |record| progress.improve(1, record)))?;
+        let solution_2 = tracing::info_span!("part_2")
+            .in_scope(|| get_max_release_with_elephant(&volcano, cancellation, &mut |record| progress.improve(2, record)))?;
+        Ok((solution_1.into(), solution_2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+
+    #[test]
+    fn test_parse() {
+        let volcano = parse_input(EXAMPLE).unwrap();
+        assert_eq!(volcano.flow_rates, vec![13, 2, 20, 3, 22, 21]);
+        assert_eq!(volcano.from_start, vec![1, 2, 1, 2, 5, 2]);
+        assert_eq!(volcano.distances[4][5], 7);
+        assert!(matches!(parse_input("Valve AA has flow rate=0; tunnel leads to valve BB"), Err(Error::UnknownValve(_))));
+        assert!(matches!(parse_input("Valve BB has flow rate=1; tunnel leads to valve BB"), Err(Error::UnknownValve(_))));
+
+        let valves: String = (0..ValveSet::BITS).map(|index| format!("Valve V{index} has flow rate=1; tunnel leads to valve V{index}\n")).collect();
+        assert!(matches!(parse_input(&valves), Err(Error::TooManyValves(32))));
+    }
+
+    #[test]
+    fn test_max_release() {
+        let mut records = vec![];
+        assert_eq!(get_max_release(&parse_input(EXAMPLE).unwrap(), &CancellationToken::default(), &mut |record| records.push(record)).unwrap(), 1651);
+        assert!(records.is_sorted() && records.last() == Some(&1651));
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let cancellation = CancellationToken::default();
+        cancellation.cancel();
+        assert!(get_max_release(&parse_input(EXAMPLE).unwrap(), &cancellation, &mut |_| ()).is_err());
+    }
+
+    #[test]
+    fn test_max_release_with_elephant() {
+        assert_eq!(get_max_release_with_elephant(&parse_input(EXAMPLE).unwrap(), &CancellationToken::default(), &mut |_| ()).unwrap(), 1707);
+    }
+}
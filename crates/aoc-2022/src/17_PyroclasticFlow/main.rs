@@ -0,0 +1,6 @@
+use aoc_2022::d17::PyroclasticFlow;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&PyroclasticFlow, "inputs/2022/17/PyroclasticFlow.txt");
+}
@@ -0,0 +1,272 @@
+use crate::prelude::*;
+use crate::cycles::find_cycle;
+use std::hash::{ Hash, Hasher };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Invalid jet '{0}'")]
+    InvalidJet(char),
+    #[error("No jets of gas")]
+    NoJets,
+    #[error("The tower didn't repeat itself within {MAX_ROCKS} rocks")]
+    NoCycle
+}
+
+const CHAMBER_WIDTH: u32 = 7;
+const RIGHT_WALL: u8 = 1 << (CHAMBER_WIDTH - 1);
+
+/// How far below the top of the tower rocks are still tracked. A tower that never seals off, like
+/// one that only ever grows on the left, would otherwise never repeat itself.
+const SURFACE_DEPTH: usize = 64;
+
+/// How many rocks to drop looking for the tower to repeat itself before giving up.
+const MAX_ROCKS: usize = 100_000;
+
+// The rows of each rock from the bottom up, where bit `x` is column `x`, as they appear: two
+// units away from the left wall
+const ROCKS: [&[u8]; 5] = [
+    &[0b0111100],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0010000, 0b0010000],
+    &[0b0000100, 0b0000100, 0b0000100, 0b0000100],
+    &[0b0001100, 0b0001100]
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Jet {
+    Left,
+    Right
+}
+
+/// The top of the tower, from the lowest row a falling rock can still reach, with the rock and
+/// the jet coming next. Towers equal but for their `base` grow the same way from then on.
+#[derive(Clone, Debug)]
+struct Tower {
+    rows: Vec<u8>,
+    base: u64,
+    rock: usize,
+    jet: usize
+}
+
+impl PartialEq for Tower {
+    fn eq(&self, other: &Self) -> bool {
+        (&self.rows, self.rock, self.jet) == (&other.rows, other.rock, other.jet)
+    }
+}
+
+impl Eq for Tower {}
+
+impl Hash for Tower {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (&self.rows, self.rock, self.jet).hash(state);
+    }
+}
+
+impl Tower {
+    fn new() -> Tower {
+        Tower { rows: vec![], base: 0, rock: 0, jet: 0 }
+    }
+
+    fn height(&self) -> u64 {
+        self.base + self.rows.len() as u64
+    }
+
+    /// Whether `rock` with its bottom row at `y` overlaps nothing. Below the rows lies either the
+    /// floor or rock sealed off, so it's as solid.
+    fn fits(&self, rock: &[u8], y: usize) -> bool {
+        rock.iter().enumerate().all(|(index, row)| self.rows.get(y + index).is_none_or(|cells| cells & row == 0))
+    }
+
+    /// The tower once the next rock comes to rest.
+    fn drop_rock(&self, jets: &[Jet]) -> Tower {
+        let mut tower = self.clone();
+        let mut rock = ROCKS[tower.rock].to_vec();
+        let mut y = tower.rows.len() + 3;
+        loop {
+            let pushed: Option<Vec<u8>> = match jets[tower.jet] {
+                Jet::Left => rock.iter().map(|row| (row & 1 == 0).then(|| row >> 1)).collect(),
+                Jet::Right => rock.iter().map(|row| (row & RIGHT_WALL == 0).then(|| row << 1)).collect()
+            };
+            tower.jet = (tower.jet + 1) % jets.len();
+            if let Some(pushed) = pushed.filter(|pushed| tower.fits(pushed, y)) {
+                rock = pushed;
+            }
+
+            match y > 0 && tower.fits(&rock, y - 1) {
+                true => y -= 1,
+                false => break
+            }
+        }
+
+        for (index, row) in rock.iter().enumerate() {
+            match tower.rows.get_mut(y + index) {
+                Some(cells) => *cells |= row,
+                None => tower.rows.push(*row)
+            }
+        }
+
+        tower.rock = (tower.rock + 1) % ROCKS.len();
+        tower.seal();
+        tower
+    }
+
+    /// Drops the rows that no rock can reach anymore. Rocks only move down and sideways, so
+    /// those are below the first row, from the top, where they can't get past anywhere. Rows
+    /// deeper than `SURFACE_DEPTH` count as out of reach too, no rock falls that far.
+    fn seal(&mut self) {
+        let full = (1 << CHAMBER_WIDTH) - 1;
+        let (mut reachable, mut lowest) = (full, self.rows.len());
+        for y in (0..self.rows.len()).rev() {
+            let free = !self.rows[y] & full;
+            reachable &= free;
+            loop {
+                let spread = (reachable | reachable << 1 | reachable >> 1) & free;
+                if spread == reachable {
+                    break;
+                }
+
+                reachable = spread;
+            }
+
+            if reachable == 0 {
+                break;
+            }
+
+            lowest = y;
+        }
+
+        let lowest = lowest.max(self.rows.len().saturating_sub(SURFACE_DEPTH));
+        self.rows.drain(..lowest);
+        self.base += lowest as u64;
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Jet>, Error> {
+    let jets: Vec<Jet> = input
+        .trim()
+        .chars()
+        .map(|ch| match ch {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(Error::InvalidJet(ch))
+        })
+        .collect::<Result<_, _>>()?;
+
+    match jets.is_empty() {
+        true => Err(Error::NoJets),
+        false => Ok(jets)
+    }
+}
+
+/// The height of the tower after `rocks` rocks. Once the tower repeats itself, every round of the
+/// cycle adds as much height, so the rounds left are skipped. Fewer rocks than `MAX_ROCKS` are
+/// simply dropped, whether the tower repeats itself or not.
+fn tower_height(jets: &[Jet], rocks: usize) -> Result<u64, Error> {
+    let mut heights = vec![0];
+    let cycle = find_cycle(Tower::new(), |tower| {
+        if heights.len() > rocks.min(MAX_ROCKS) {
+            return None;
+        }
+
+        let next = tower.drop_rock(jets);
+        heights.push(next.height());
+        Some(next)
+    });
+
+    if rocks < heights.len() {
+        return Ok(heights[rocks]);
+    }
+
+    let cycle = cycle.ok_or(Error::NoCycle)?;
+    let step = cycle.equivalent_step(rocks);
+    let rounds = ((rocks - step) / cycle.length) as u64;
+    Ok(heights[step] + rounds * (heights[cycle.start + cycle.length] - heights[cycle.start]))
+}
+
+pub struct PyroclasticFlow;
+
+impl Solver for PyroclasticFlow {
+    type Parsed = Vec<Jet>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, jets: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(tower_height(jets, 2022)?.into())
+    }
+
+    fn part_2(&self, jets: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(tower_height(jets, 1_000_000_000_000)?.into())
+    }
+}
+
+impl Solution for PyroclasticFlow {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 17,
+            title: "Pyroclastic Flow",
+            tags: &["simulation", "cycles"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_input("<>\n").unwrap(), vec![Jet::Left, Jet::Right]);
+        assert!(matches!(parse_input("<x>"), Err(Error::InvalidJet('x'))));
+        assert!(matches!(parse_input("\n"), Err(Error::NoJets)));
+    }
+
+    #[test]
+    fn test_first_rocks() {
+        let jets = parse_input(EXAMPLE).unwrap();
+        let heights: Vec<u64> = std::iter::successors(Some(Tower::new()), |tower| Some(tower.drop_rock(&jets)))
+            .skip(1)
+            .take(10)
+            .map(|tower| tower.height())
+            .collect();
+        assert_eq!(heights, vec![1, 4, 6, 7, 9, 10, 13, 15, 17, 17]);
+    }
+
+    #[test]
+    fn test_seal() {
+        // The full row and everything under it are out of reach
+        let mut tower = Tower { rows: vec![0b0000001, 0b1111111, 0b0000100, 0b0001000], base: 5, rock: 0, jet: 0 };
+        tower.seal();
+        assert_eq!((tower.rows, tower.base), (vec![0b0000100, 0b0001000], 7));
+    }
+
+    #[test]
+    fn test_tower_height() {
+        let jets = parse_input(EXAMPLE).unwrap();
+        assert_eq!(tower_height(&jets, 2022).unwrap(), 3068);
+        assert_eq!(tower_height(&jets, 1_000_000_000_000).unwrap(), 1514285714288);
+    }
+
+    #[test]
+    fn test_unsealed_tower() {
+        // Pushed against the left wall, the rocks never fill the right columns to seal the tower off
+        let jets = parse_input("<\n").unwrap();
+        assert_eq!(tower_height(&jets, 5).unwrap(), 11);
+        assert!(tower_height(&jets, 1_000_000_000_000).is_ok());
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2022::d18::BoilingBoulders;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&BoilingBoulders, "inputs/2022/18/BoilingBoulders.txt");
+}
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+use crate::voxels::{ exterior_surface_area, surface_area };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse cube: {0}")]
+    ParsingError(Excerpt)
+}
+
+fn parse_cube(line: &str) -> Result<Vec3, Error> {
+    let [x, y, z] = ints(line)[..] else {
+        return Err(Error::ParsingError(line.into()));
+    };
+
+    Ok(Vec3::new(x, y, z))
+}
+
+fn parse_input(input: &str) -> Result<HashSet<Vec3>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_cube).collect()
+}
+
+pub struct BoilingBoulders;
+
+impl Solver for BoilingBoulders {
+    type Parsed = HashSet<Vec3>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, droplet: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(surface_area(droplet).into())
+    }
+
+    /// The faces facing air pockets trapped inside the droplet don't count.
+    fn part_2(&self, droplet: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(exterior_surface_area(droplet).into())
+    }
+}
+
+impl Solution for BoilingBoulders {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2022,
+            day: 18,
+            title: "Boiling Boulders",
+            tags: &["voxels", "flood-fill"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+";
+
+    #[test]
+    fn test_parse() {
+        let droplet = parse_input(EXAMPLE).unwrap();
+        assert_eq!(droplet.len(), 13);
+        assert!(droplet.contains(&Vec3::new(2, 2, 6)));
+        assert!(parse_cube("1,2").is_err());
+    }
+
+    #[test]
+    fn test_surface_area() {
+        assert_eq!(surface_area(&parse_input("1,1,1\n2,1,1\n").unwrap()), 10);
+        assert_eq!(surface_area(&parse_input(EXAMPLE).unwrap()), 64);
+    }
+
+    #[test]
+    fn test_exterior_surface_area() {
+        assert_eq!(exterior_surface_area(&parse_input(EXAMPLE).unwrap()), 58);
+    }
+}
@@ -0,0 +1,63 @@
+//! The solutions of 2022, a module per day.
+
+use aoc_core::solution::Register;
+// The days use the shared code as if it were part of this crate, e.g. `crate::prelude`
+use aoc_core::*;
+
+#[path = "01_CalorieCounting/solution.rs"]
+pub mod d01;
+#[path = "02_RockPaperScissors/solution.rs"]
+pub mod d02;
+#[path = "03_RucksackReorganization/solution.rs"]
+pub mod d03;
+#[path = "04_CampCleanup/solution.rs"]
+pub mod d04;
+#[path = "05_SupplyStacks/solution.rs"]
+pub mod d05;
+#[path = "06_TuningTrouble/solution.rs"]
+pub mod d06;
+#[path = "07_NoSpaceLeftOnDevice/solution.rs"]
+pub mod d07;
+#[path = "08_TreetopTreeHouse/solution.rs"]
+pub mod d08;
+#[path = "09_RopeBridge/solution.rs"]
+pub mod d09;
+#[path = "10_CathodeRayTube/solution.rs"]
+pub mod d10;
+#[path = "11_MonkeyInTheMiddle/solution.rs"]
+pub mod d11;
+#[path = "12_HillClimbingAlgorithm/solution.rs"]
+pub mod d12;
+#[path = "13_DistressSignal/solution.rs"]
+pub mod d13;
+#[path = "14_RegolithReservoir/solution.rs"]
+pub mod d14;
+#[path = "15_BeaconExclusionZone/solution.rs"]
+pub mod d15;
+#[path = "16_ProboscideaVolcanium/solution.rs"]
+pub mod d16;
+#[path = "17_PyroclasticFlow/solution.rs"]
+pub mod d17;
+#[path = "18_BoilingBoulders/solution.rs"]
+pub mod d18;
+
+pub fn register(registry: &mut impl Register) {
+    registry.register(Box::new(d01::CalorieCounting));
+    registry.register(Box::new(d02::RockPaperScissors));
+    registry.register(Box::new(d03::RucksackReorganization));
+    registry.register(Box::new(d04::CampCleanup));
+    registry.register(Box::new(d05::SupplyStacks));
+    registry.register(Box::new(d06::TuningTrouble));
+    registry.register(Box::new(d07::NoSpaceLeftOnDevice));
+    registry.register(Box::new(d08::TreetopTreeHouse));
+    registry.register(Box::new(d09::RopeBridge));
+    registry.register(Box::new(d10::CathodeRayTube));
+    registry.register(Box::new(d11::MonkeyInTheMiddle));
+    registry.register(Box::new(d12::HillClimbingAlgorithm));
+    registry.register(Box::new(d13::DistressSignal));
+    registry.register(Box::new(d14::RegolithReservoir));
+    registry.register(Box::new(d15::BeaconExclusionZone));
+    registry.register(Box::new(d16::ProboscideaVolcanium));
+    registry.register(Box::new(d17::PyroclasticFlow));
+    registry.register(Box::new(d18::BoilingBoulders));
+}
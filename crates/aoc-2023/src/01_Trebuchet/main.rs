@@ -0,0 +1,6 @@
+use aoc_2023::d01::Trebuchet;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&Trebuchet, "inputs/2023/01/input.txt");
+}
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+const SPELLED_DIGITS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// The digit starting at `index` of `line`, if any. Spelled out digits can overlap, as in
+/// `eighthree`, so each position is checked on its own.
+fn digit_at(line: &str, index: usize, spelled: bool) -> Option<u32> {
+    let rest = &line[index..];
+    rest.chars().next()?.to_digit(10).or_else(|| match spelled {
+        true => SPELLED_DIGITS.iter().position(|digit| rest.starts_with(digit)).map(|position| position as u32 + 1),
+        false => None
+    })
+}
+
+/// The first and last digits of `line`, or `None` for a line without any.
+fn first_and_last_digits(line: &str, spelled: bool) -> Option<(u32, u32)> {
+    let mut digits = (0..line.len()).filter(|index| line.is_char_boundary(*index)).filter_map(|index| digit_at(line, index, spelled));
+    let first = digits.next()?;
+    Some((first, digits.next_back().unwrap_or(first)))
+}
+
+fn sum_calibration_values(lines: &[String], spelled: bool) -> u32 {
+    lines.iter()
+        .filter_map(|line| first_and_last_digits(line, spelled))
+        .map(|(first, last)| first * 10 + last)
+        .sum()
+}
+
+pub struct Trebuchet;
+
+impl Solver for Trebuchet {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(input.lines().map(|line| line.trim().to_string()).collect())
+    }
+
+    fn part_1(&self, lines: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(sum_calibration_values(lines, false).into())
+    }
+
+    fn part_2(&self, lines: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(sum_calibration_values(lines, true).into())
+    }
+}
+
+impl Solution for Trebuchet {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 1,
+            title: "Trebuchet?!",
+            tags: &["strings"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn test_digits_only() {
+        assert_eq!(first_and_last_digits("abcdefg", false), None);
+        assert_eq!(first_and_last_digits("12", false), Some((1, 2)));
+        assert_eq!(first_and_last_digits("abc1defg2hilmn", false), Some((1, 2)));
+        assert_eq!(first_and_last_digits("abcde1fghi", false), Some((1, 1)));
+        assert_eq!(first_and_last_digits("onetwo", false), None);
+    }
+
+    #[test]
+    fn test_spelled_digits() {
+        assert_eq!(first_and_last_digits("onetwo", true), Some((1, 2)));
+        assert_eq!(first_and_last_digits("eighthree", true), Some((8, 3)));
+        assert_eq!(first_and_last_digits("one2", true), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Trebuchet.solve("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet").unwrap().0, 142.into());
+        assert_eq!(Trebuchet.solve(EXAMPLE).unwrap().1, 281.into());
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2023::d02::CubeConundrum;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&CubeConundrum, "inputs/2023/02/input.txt");
+}
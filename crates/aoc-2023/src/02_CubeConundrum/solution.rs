@@ -0,0 +1,136 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse game: {0}")]
+    ParsingError(Excerpt)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct CubeSet {
+    red: u32,
+    green: u32,
+    blue: u32
+}
+
+impl CubeSet {
+    /// The smallest set containing both sets.
+    fn union(&self, other: &CubeSet) -> CubeSet {
+        CubeSet { red: self.red.max(other.red), green: self.green.max(other.green), blue: self.blue.max(other.blue) }
+    }
+
+    fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+}
+
+pub struct Game {
+    id: u32,
+    sets: Vec<CubeSet>
+}
+
+const BAG: CubeSet = CubeSet { red: 12, green: 13, blue: 14 };
+
+fn parse_game(line: &str) -> Result<Game, Error> {
+    lazy_static! {
+        static ref GAME_REGEX: Regex = Regex::new(r"^Game (?<id>\d+): (?<sets>.*)$").unwrap();
+        static ref CUBES_REGEX: Regex = Regex::new(r"^(?<count>\d+) (?<color>red|green|blue)$").unwrap();
+    }
+
+    let make_error = || Error::ParsingError(line.into());
+    let captures = GAME_REGEX.captures(line.trim()).ok_or_else(make_error)?;
+    let id = captures["id"].parse().map_err(|_| make_error())?;
+
+    let mut sets = vec![];
+    for set_text in captures["sets"].split(';').filter(|set| !set.trim().is_empty()) {
+        let mut set = CubeSet::default();
+        for cubes in set_text.split(',') {
+            let cubes = CUBES_REGEX.captures(cubes.trim()).ok_or_else(make_error)?;
+            let count = cubes["count"].parse().map_err(|_| make_error())?;
+            match &cubes["color"] {
+                "red" => set.red = count,
+                "green" => set.green = count,
+                _ => set.blue = count
+            }
+        }
+        sets.push(set);
+    }
+
+    Ok(Game { id, sets })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_game).collect()
+}
+
+pub struct CubeConundrum;
+
+impl Solver for CubeConundrum {
+    type Parsed = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, games: &Self::Parsed) -> Result<Answer, SolutionError> {
+        let possible = |game: &&Game| game.sets.iter().all(|set| set.union(&BAG) == BAG);
+        Ok(games.iter().filter(possible).map(|game| game.id).sum::<u32>().into())
+    }
+
+    fn part_2(&self, games: &Self::Parsed) -> Result<Answer, SolutionError> {
+        let minimal_set = |game: &Game| game.sets.iter().fold(CubeSet::default(), |minimal, set| minimal.union(set));
+        Ok(games.iter().map(|game| minimal_set(game).power()).sum::<u32>().into())
+    }
+}
+
+impl Solution for CubeConundrum {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 2,
+            title: "Cube Conundrum",
+            tags: &["parsing"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+    #[test]
+    fn test_parse() {
+        let game = parse_game("Game 123: 1 red, 2 green, 3 blue").unwrap();
+        assert_eq!(game.id, 123);
+        assert_eq!(game.sets, vec![CubeSet { red: 1, green: 2, blue: 3 }]);
+
+        let game = parse_game("Game 1: 1 red; 2 green; 3 blue").unwrap();
+        assert_eq!(game.sets.len(), 3);
+        assert_eq!((game.sets[0].red, game.sets[1].green, game.sets[2].blue), (1, 2, 3));
+
+        assert!(matches!(parse_game("Game 1: 1 purple"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(CubeConundrum.solve(EXAMPLE).unwrap(), (8.into(), 2286.into()));
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2023::d03::GearRatios;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&GearRatios, "inputs/2023/03/input.txt");
+}
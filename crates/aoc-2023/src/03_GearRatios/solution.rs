@@ -0,0 +1,170 @@
+use crate::prelude::*;
+use arrayvec::ArrayVec;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse engine schematic: {0}")]
+    ParsingError(#[from] grid::Error)
+}
+
+/// A number next to at least one symbol.
+#[derive(Debug)]
+struct Part {
+    number: u64,
+    symbols: HashSet<Vec2>
+}
+
+pub struct Schematic {
+    glyphs: Grid<char>,
+    parts: Vec<Part>
+}
+
+fn is_symbol(glyph: char) -> bool {
+    glyph != '.' && !glyph.is_ascii_digit()
+}
+
+/// Called for every digit, so the at most 8 positions are kept on the stack
+fn symbols_around(glyphs: &Grid<char>, position: Vec2) -> ArrayVec<Vec2, 8> {
+    (-1..=1)
+        .cartesian_product(-1..=1)
+        .map(|(x, y)| position + Vec2::new(x, y))
+        .filter(|around| glyphs.get(*around).is_some_and(|glyph| is_symbol(*glyph)))
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Schematic, Error> {
+    let glyphs = Grid::parse(input, Some)?;
+    let mut parts = vec![];
+    let mut number: Option<u64> = None;
+    let mut symbols = HashSet::default();
+
+    for (position, glyph) in glyphs.iter() {
+        // Numbers end at the first non-digit or at the end of their row
+        if position.x == 0 || !glyph.is_ascii_digit() {
+            if let Some(number) = number.take().filter(|_| !symbols.is_empty()) {
+                parts.push(Part { number, symbols: std::mem::take(&mut symbols) });
+            }
+            symbols.clear();
+        }
+
+        if let Some(digit) = glyph.to_digit(10) {
+            number = Some(number.unwrap_or_default() * 10 + digit as u64);
+            symbols.extend(symbols_around(&glyphs, position));
+        }
+    }
+
+    if let Some(number) = number.filter(|_| !symbols.is_empty()) {
+        parts.push(Part { number, symbols });
+    }
+
+    Ok(Schematic { glyphs, parts })
+}
+
+/// Gears are `*` symbols next to exactly two parts, their ratio is the product of the two.
+fn sum_gear_ratios(schematic: &Schematic) -> u64 {
+    let mut gears: HashMap<Vec2, Vec<u64>> = HashMap::default();
+    for part in &schematic.parts {
+        for symbol in part.symbols.iter().filter(|symbol| schematic.glyphs[**symbol] == '*') {
+            gears.entry(*symbol).or_default().push(part.number);
+        }
+    }
+
+    gears.values().filter(|parts| parts.len() == 2).map(|parts| parts[0] * parts[1]).sum()
+}
+
+pub struct GearRatios;
+
+impl Solver for GearRatios {
+    type Parsed = Schematic;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, schematic: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(schematic.parts.iter().map(|part| part.number).sum::<u64>().into())
+    }
+
+    fn part_2(&self, schematic: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(sum_gear_ratios(schematic).into())
+    }
+}
+
+impl Solution for GearRatios {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 3,
+            title: "Gear Ratios",
+            tags: &["grid", "parsing"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    fn part_numbers(input: &str) -> Vec<u64> {
+        parse_input(input).unwrap().parts.iter().map(|part| part.number).collect()
+    }
+
+    #[test]
+    fn test_single_part() {
+        assert_eq!(part_numbers("..123..\n...#..."), vec![123]);
+        assert_eq!(part_numbers("*1.2"), vec![1]);
+        assert_eq!(part_numbers("1*"), vec![1]);
+    }
+
+    #[test]
+    fn test_multiple_parts() {
+        assert_eq!(part_numbers(".12.34.\n...#..."), vec![12, 34]);
+        assert_eq!(part_numbers(".12.34.\n.#....$"), vec![12, 34]);
+        assert_eq!(part_numbers(".12$34."), vec![12, 34]);
+        assert_eq!(part_numbers("..12..\n.34#.."), vec![12, 34]);
+    }
+
+    #[test]
+    fn test_number_boundaries() {
+        assert_eq!(part_numbers("*123.456"), vec![123]);
+        assert_eq!(part_numbers("*123"), vec![123]);
+        // A number at the end of a row doesn't continue on the next one
+        assert_eq!(part_numbers("..12\n34..\n#..."), vec![34]);
+    }
+
+    #[test]
+    fn test_part_near_symbols() {
+        let schematic = parse_input("*.#\n.1.\n%.$").unwrap();
+        assert_eq!(schematic.parts.len(), 1);
+        let expected = [(0, 0), (2, 0), (0, 2), (2, 2)].map(|(x, y)| Vec2::new(x, y));
+        assert_eq!(schematic.parts[0].symbols, HashSet::from_iter(expected));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(GearRatios.solve(EXAMPLE).unwrap(), (4361.into(), 467835.into()));
+    }
+}
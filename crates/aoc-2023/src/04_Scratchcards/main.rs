@@ -0,0 +1,6 @@
+use aoc_2023::d04::Scratchcards;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&Scratchcards, "inputs/2023/04/input.txt");
+}
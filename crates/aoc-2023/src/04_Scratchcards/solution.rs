@@ -0,0 +1,106 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse card: {0}")]
+    ParsingError(Excerpt)
+}
+
+/// How many of the numbers on the card are winning numbers.
+fn parse_matches(line: &str) -> Result<usize, Error> {
+    let make_error = || Error::ParsingError(line.into());
+    let (_, numbers) = line.split_once(':').ok_or_else(make_error)?;
+    let (winning, yours) = numbers.split_once('|').ok_or_else(make_error)?;
+
+    let parse_numbers = |numbers: &str| -> Result<HashSet<u32>, Error> {
+        numbers.split_whitespace().map(|number| number.parse().map_err(|_| make_error())).collect()
+    };
+
+    Ok(parse_numbers(winning)?.intersection(&parse_numbers(yours)?).count())
+}
+
+fn parse_input(input: &str) -> Result<Vec<usize>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_matches).collect()
+}
+
+/// Each card wins one copy of as many following cards as it has matches, for every copy of it
+/// already won.
+fn count_cards(matches: &[usize]) -> u64 {
+    let mut copies = vec![1u64; matches.len()];
+    for (card, matches) in matches.iter().enumerate() {
+        let won = card + 1..(card + 1 + matches).min(copies.len());
+        for next in won {
+            copies[next] += copies[card];
+        }
+    }
+
+    copies.iter().sum()
+}
+
+pub struct Scratchcards;
+
+impl Solver for Scratchcards {
+    type Parsed = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, matches: &Self::Parsed) -> Result<Answer, SolutionError> {
+        let points = |matches: usize| match matches {
+            0 => 0,
+            matches => 1u64 << (matches - 1)
+        };
+        Ok(matches.iter().map(|matches| points(*matches)).sum::<u64>().into())
+    }
+
+    fn part_2(&self, matches: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(count_cards(matches).into())
+    }
+}
+
+impl Solution for Scratchcards {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 4,
+            title: "Scratchcards",
+            tags: &["parsing", "dynamic-programming"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_input(EXAMPLE).unwrap(), vec![4, 2, 2, 1, 0, 0]);
+        assert!(matches!(parse_matches("Card 1: 41 48"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(Scratchcards.solve(EXAMPLE).unwrap(), (13.into(), 30.into()));
+    }
+}
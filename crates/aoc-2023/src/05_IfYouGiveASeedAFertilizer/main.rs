@@ -0,0 +1,6 @@
+use aoc_2023::d05::IfYouGiveASeedAFertilizer;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&IfYouGiveASeedAFertilizer, "inputs/2023/05/input.txt");
+}
@@ -0,0 +1,151 @@
+use crate::prelude::*;
+use crate::parsers::{ self, nom::{ bytes::complete::tag, character::complete::alpha1, Parser }, StrParser };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse almanac: {0}")]
+    ParsingError(#[from] parsers::Error),
+    #[error("The seeds aren't listed as pairs of start and length")]
+    UnpairedSeeds,
+    #[error("The almanac has no seeds")]
+    NoSeeds
+}
+
+/// The seeds, and the maps from seeds to locations in the order they're applied.
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<IntervalMap>
+}
+
+/// A line of a map, moving `length` numbers from `source` to `destination`.
+fn mapping<'a>() -> impl StrParser<'a, (Interval, i64)> {
+    parsers::numbers().map_opt(|numbers| match numbers[..] {
+        [destination, source, length] if length > 0 => Some((Interval { start: source, end: source + length - 1 }, destination - source)),
+        _ => None
+    })
+}
+
+/// Maps are listed in the order they chain, from seed to soil down to location, so they're
+/// applied in that order and their names are only checked for shape.
+fn parse_input(input: &str) -> Result<Almanac, Error> {
+    let header = (alpha1, tag("-to-"), alpha1, tag(" map:"));
+    let map = parsers::section(header, mapping()).map(|(_, mappings)| IntervalMap::from_iter(mappings));
+    let almanac = (parsers::labeled("seeds:", parsers::numbers()), parsers::blank_lines(), parsers::sections(map));
+
+    let (seeds, _, maps) = parsers::parse(almanac, input)?;
+    Ok(Almanac { seeds, maps })
+}
+
+fn find_lowest_location(almanac: &Almanac, seeds: IntervalSet) -> Result<i64, Error> {
+    almanac.maps
+        .iter()
+        .fold(seeds, |set, map| map.apply(&set))
+        .min()
+        .ok_or(Error::NoSeeds)
+}
+
+pub struct IfYouGiveASeedAFertilizer;
+
+impl Solver for IfYouGiveASeedAFertilizer {
+    type Parsed = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, almanac: &Self::Parsed) -> Result<Answer, SolutionError> {
+        let locations = almanac.seeds.iter().map(|seed| almanac.maps.iter().fold(*seed, |number, map| map.get(number)));
+        Ok(locations.min().ok_or(Error::NoSeeds)?.into())
+    }
+
+    /// Seeds come in ranges too large to go through one by one, so whole intervals are mapped.
+    fn part_2(&self, almanac: &Self::Parsed) -> Result<Answer, SolutionError> {
+        if !almanac.seeds.len().is_multiple_of(2) {
+            return Err(Error::UnpairedSeeds.into());
+        }
+
+        let seeds = almanac.seeds.chunks(2).filter_map(|pair| Interval::new(pair[0], pair[0] + pair[1] - 1)).collect();
+        Ok(find_lowest_location(almanac, seeds)?.into())
+    }
+}
+
+impl Solution for IfYouGiveASeedAFertilizer {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 5,
+            title: "If You Give A Seed A Fertilizer",
+            tags: &["intervals", "parsing"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    fn interval(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    #[test]
+    fn test_parse() {
+        let almanac = parse_input("seeds: 1 2 3 4\n\na-to-b map:\n1 2 3\n4 5 6\n\nb-to-c map:\n7 8 9").unwrap();
+        assert_eq!(almanac.seeds, vec![1, 2, 3, 4]);
+        assert_eq!(almanac.maps.len(), 2);
+        assert_eq!(almanac.maps[0], IntervalMap::from_iter([(interval(2, 4), -1), (interval(5, 10), -1)]));
+        assert!(matches!(parse_input("seeds: 1\n\na-to-b map:\n1 2"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(IfYouGiveASeedAFertilizer.solve(EXAMPLE).unwrap(), (35.into(), 46.into()));
+        assert!(IfYouGiveASeedAFertilizer.solve("seeds: 1 2 3").is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_2023::d06::WaitForIt;
+use aoc_core::solution::{ self, Solution };
+
+fn main() {
+    let path = "inputs/2023/06/input.txt";
+
+    // e.g. `--strategy=brute_force` to compare against the closed form
+    match std::env::args().find_map(|arg| arg.strip_prefix("--strategy=").map(str::to_string)) {
+        Some(strategy) => {
+            let arguments = std::env::args().skip(1).filter(|arg| !arg.starts_with("--strategy="));
+            match solution::read_input(&WaitForIt, path, arguments).and_then(|input| WaitForIt.solve_with_strategy(&input, &strategy)) {
+                Ok((solution_1, solution_2)) => {
+                    println!("Solution 1: {solution_1}");
+                    println!("Solution 2: {solution_2}");
+                },
+                Err(err) => {
+                    println!("{err}");
+                    std::process::exit(1);
+                }
+            }
+        },
+        None => solution::run_from_file(&WaitForIt, path)
+    }
+}
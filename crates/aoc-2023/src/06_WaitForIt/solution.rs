@@ -0,0 +1,181 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse line: {0}")]
+    ParsingError(Excerpt),
+    #[error("Expected a Time and a Distance line")]
+    MissingLine,
+    #[error("Unknown strategy: {0}")]
+    UnknownStrategy(String),
+    #[error("The product of the ways to win overflows")]
+    Overflow
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Race {
+    time: u64,
+    record: u64
+}
+
+impl Race {
+    /// Distances can take up to twice the bits of the time, so they're compared as `u128`.
+    fn beats_record(&self, hold: u64) -> bool {
+        hold <= self.time && hold as u128 * (self.time - hold) as u128 > self.record as u128
+    }
+}
+
+type Strategy = fn(&Race) -> u64;
+
+/// Every way of counting the winning holds. They must all agree, which the tests check.
+const STRATEGIES: [(&str, Strategy); 2] = [
+    ("brute_force", count_ways_to_win_brute_force),
+    ("quadratic", count_ways_to_win_quadratic)
+];
+
+fn count_ways_to_win_brute_force(race: &Race) -> u64 {
+    (0..=race.time).filter(|hold| race.beats_record(*hold)).count() as u64
+}
+
+/// Winning holds `h` satisfy `h * (time - h) > record`, i.e. they lie strictly between the
+/// roots of `h^2 - time * h + record`. The roots are estimated with an integer square root
+/// and then nudged, so no floating point rounding can shift the count.
+fn count_ways_to_win_quadratic(race: &Race) -> u64 {
+    let Some(discriminant) = (race.time as u128 * race.time as u128).checked_sub(4 * race.record as u128)
+    else {
+        return 0;
+    };
+
+    // The discriminant is at most time^2, so its root fits back in a u64
+    let mut lowest = (race.time - discriminant.isqrt() as u64) / 2;
+    while lowest > 0 && race.beats_record(lowest - 1) {
+        lowest -= 1;
+    }
+    while lowest <= race.time / 2 && !race.beats_record(lowest) {
+        lowest += 1;
+    }
+
+    // Winning holds are symmetric around time / 2. Holding 0 never wins, so this can't overflow
+    if race.beats_record(lowest) { race.time - 2 * lowest + 1 }
+    else { 0 }
+}
+
+fn parse_line<'a>(line: Option<&'a str>, label: &str) -> Result<Vec<&'a str>, Error> {
+    let line = line.ok_or(Error::MissingLine)?;
+    let values = line.strip_prefix(label).ok_or_else(|| Error::ParsingError(line.into()))?;
+    Ok(values.split_whitespace().collect())
+}
+
+fn parse_number(digits: &str) -> Result<u64, Error> {
+    digits.parse().map_err(|_| Error::ParsingError(digits.into()))
+}
+
+/// Returns both readings of the sheet: one race per column, and a single race with the
+/// columns' digits joined together.
+fn parse_input(input: &str) -> Result<(Vec<Race>, Race), Error> {
+    let mut lines = input.lines();
+    let times = parse_line(lines.next(), "Time:")?;
+    let records = parse_line(lines.next(), "Distance:")?;
+
+    let races = times
+        .iter()
+        .zip(records.iter())
+        .map(|(time, record)| Ok(Race { time: parse_number(time)?, record: parse_number(record)? }))
+        .collect::<Result<_, Error>>()?;
+
+    let single_race = Race { time: parse_number(&times.concat())?, record: parse_number(&records.concat())? };
+    Ok((races, single_race))
+}
+
+fn find_strategy(name: &str) -> Result<Strategy, Error> {
+    STRATEGIES
+        .iter()
+        .find(|(strategy_name, _)| *strategy_name == name)
+        .map(|(_, strategy)| *strategy)
+        .ok_or_else(|| Error::UnknownStrategy(name.to_string()))
+}
+
+pub struct WaitForIt;
+
+impl Solution for WaitForIt {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 6,
+            title: "Wait For It",
+            tags: &["math"],
+            runtime: RuntimeTier::Instant,
+            strategies: &["quadratic", "brute_force"]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        self.solve_with_strategy(input, "quadratic")
+    }
+
+    fn solve_with_strategy(&self, input: &str, strategy_name: &str) -> Result<Answers, SolutionError> {
+        let strategy = find_strategy(strategy_name)?;
+        let (races, single_race) = parse_input(input)?;
+        let solution_1 = races.iter().map(strategy).try_fold(1, u64::checked_mul).ok_or(Error::Overflow)?;
+        let solution_2 = strategy(&single_race);
+        Ok((solution_1.into(), solution_2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn test_parse() {
+        let (races, single_race) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(races, vec![Race { time: 7, record: 9 }, Race { time: 15, record: 40 }, Race { time: 30, record: 200 }]);
+        assert_eq!(single_race, Race { time: 71530, record: 940200 });
+        assert!(matches!(parse_input("Time: 7"), Err(Error::MissingLine)));
+    }
+
+    #[test]
+    fn test_example() {
+        let (races, single_race) = parse_input(EXAMPLE).unwrap();
+        for (name, strategy) in STRATEGIES {
+            assert_eq!(races.iter().map(strategy).collect::<Vec<_>>(), vec![4, 8, 9], "Strategy {name}");
+            assert_eq!(strategy(&single_race), 71503, "Strategy {name}");
+        }
+    }
+
+    #[test]
+    fn test_long_races() {
+        // Distances past u64::MAX, which only fit in a u128
+        assert_eq!(count_ways_to_win_quadratic(&Race { time: 99999999999, record: 1 }), 99999999998);
+        assert_eq!(count_ways_to_win_quadratic(&Race { time: u64::MAX, record: u64::MAX }), u64::MAX - 3);
+        assert_eq!(count_ways_to_win_quadratic(&Race { time: 1 << 33, record: u64::MAX }), 1);
+        assert_eq!(count_ways_to_win_quadratic(&Race { time: 1 << 32, record: u64::MAX }), 0);
+        assert!(Race { time: u64::MAX, record: u64::MAX }.beats_record(2));
+        assert_eq!(WaitForIt.solve("Time: 99999999999\nDistance: 1\n").unwrap(), (99999999998u64.into(), 99999999998u64.into()));
+    }
+
+    #[test]
+    fn test_find_strategy() {
+        assert!(find_strategy("brute_force").is_ok());
+        assert!(matches!(find_strategy("guess"), Err(Error::UnknownStrategy(_))));
+    }
+
+    #[test]
+    fn test_strategies_agree() {
+        for time in 0..60 {
+            for record in 0..=(time * time / 4 + 1) {
+                let race = Race { time, record };
+                let (_, reference) = STRATEGIES[0];
+                for (name, strategy) in &STRATEGIES[1..] {
+                    assert_eq!(strategy(&race), reference(&race), "Strategy {name} on {race:?}");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2023::d07::CamelCards;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&CamelCards, "inputs/2023/07/input.txt");
+}
@@ -0,0 +1,235 @@
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse hand: {0}")]
+    ParsingError(Excerpt)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Rules {
+    Standard,
+    /// `J` cards are jokers: they act as whatever card makes the strongest hand,
+    /// but are the weakest card when breaking ties.
+    Jokers
+}
+
+/// A card's strength, from 2 to 14 (Ace). Jokers have strength 1.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Card(u8);
+
+const JOKER: Card = Card(1);
+
+impl Card {
+    fn parse(label: char, rules: Rules) -> Option<Card> {
+        let strength = match label {
+            '2'..='9' => label.to_digit(10)? as u8,
+            'T' => 10,
+            'J' if rules == Rules::Jokers => return Some(JOKER),
+            'J' => 11,
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            _ => return None
+        };
+
+        Some(Card(strength))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind
+}
+
+impl HandType {
+    fn classify(cards: &[Card; 5]) -> HandType {
+        let counter: Counter<Card> = cards.iter().copied().filter(|card| *card != JOKER).collect();
+        let mut counts: Vec<usize> = counter.most_common().into_iter().map(|(_, count)| count).collect();
+
+        // Jokers always join the largest group
+        let jokers = 5 - counter.total();
+        match counts.first_mut() {
+            Some(largest) => *largest += jokers,
+            None => counts.push(jokers)
+        }
+
+        match counts.as_slice() {
+            [5] => HandType::FiveOfAKind,
+            [4, ..] => HandType::FourOfAKind,
+            [3, 2] => HandType::FullHouse,
+            [3, ..] => HandType::ThreeOfAKind,
+            [2, 2, ..] => HandType::TwoPair,
+            [2, ..] => HandType::OnePair,
+            _ => HandType::HighCard
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Hand {
+    cards: [Card; 5],
+    hand_type: HandType,
+    bid: u64
+}
+
+impl Hand {
+    fn parse(line: &str, rules: Rules) -> Result<Hand, Error> {
+        let make_error = || Error::ParsingError(line.into());
+        let (labels, bid) = line.split_once(' ').ok_or_else(make_error)?;
+
+        let cards: Vec<Card> = labels.chars().map(|label| Card::parse(label, rules)).collect::<Option<_>>().ok_or_else(make_error)?;
+        let cards: [Card; 5] = cards.try_into().map_err(|_| make_error())?;
+        let bid = bid.trim().parse().map_err(|_| make_error())?;
+
+        Ok(Hand { cards, hand_type: HandType::classify(&cards), bid })
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hand_type
+            .cmp(&other.hand_type)
+            .then_with(|| self.cards.cmp(&other.cards))
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_input(input: &str, rules: Rules) -> Result<Vec<Hand>, Error> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Hand::parse(line, rules))
+        .collect()
+}
+
+fn get_total_winnings(mut hands: Vec<Hand>) -> u64 {
+    hands.sort();
+    hands.iter().zip(1..).map(|(hand, rank)| hand.bid * rank).sum()
+}
+
+pub struct CamelCards;
+
+impl Solution for CamelCards {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 7,
+            title: "Camel Cards",
+            tags: &["sorting", "counting"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let solution_1 = get_total_winnings(parse_input(input, Rules::Standard)?);
+        let solution_2 = get_total_winnings(parse_input(input, Rules::Jokers)?);
+        Ok((solution_1.into(), solution_2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    fn hand(labels: &str, rules: Rules) -> Hand {
+        Hand::parse(&format!("{labels} 0"), rules).unwrap()
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Hand::parse("32T3K", Rules::Standard).is_err());
+        assert!(Hand::parse("32T3 765", Rules::Standard).is_err());
+        assert!(Hand::parse("32T3KK 765", Rules::Standard).is_err());
+        assert!(Hand::parse("32X3K 765", Rules::Standard).is_err());
+        assert!(Hand::parse("32T3K bid", Rules::Standard).is_err());
+    }
+
+    #[test]
+    fn test_classify_standard() {
+        let cases = [
+            ("AAAAA", HandType::FiveOfAKind),
+            ("AA8AA", HandType::FourOfAKind),
+            ("23332", HandType::FullHouse),
+            ("TTT98", HandType::ThreeOfAKind),
+            ("23432", HandType::TwoPair),
+            ("A23A4", HandType::OnePair),
+            ("23456", HandType::HighCard),
+            ("JJJJJ", HandType::FiveOfAKind)
+        ];
+
+        for (labels, expected) in cases {
+            assert_eq!(hand(labels, Rules::Standard).hand_type, expected, "Hand {labels}");
+        }
+    }
+
+    #[test]
+    fn test_classify_jokers() {
+        let cases = [
+            ("JJJJJ", HandType::FiveOfAKind),
+            ("JJJJ2", HandType::FiveOfAKind),
+            ("QJJQ2", HandType::FourOfAKind),
+            ("T55J5", HandType::FourOfAKind),
+            ("2233J", HandType::FullHouse),
+            ("2J34J", HandType::ThreeOfAKind),
+            ("2345J", HandType::OnePair),
+            ("23456", HandType::HighCard)
+        ];
+
+        for (labels, expected) in cases {
+            assert_eq!(hand(labels, Rules::Jokers).hand_type, expected, "Hand {labels}");
+        }
+    }
+
+    #[test]
+    fn test_tie_breaking() {
+        // Same type: the first differing card decides, not the best card overall
+        assert!(hand("33332", Rules::Standard) > hand("2AAAA", Rules::Standard));
+        assert!(hand("77888", Rules::Standard) > hand("77788", Rules::Standard));
+        assert!(hand("KK677", Rules::Standard) > hand("KTJJT", Rules::Standard));
+
+        // Type always wins over card strength
+        assert!(hand("22345", Rules::Standard) > hand("AKQJT", Rules::Standard));
+
+        // Jokers are weaker than 2 when breaking ties, even though they upgrade the type
+        assert!(hand("JKKK2", Rules::Jokers) < hand("QQQQ2", Rules::Jokers));
+        assert!(hand("J2222", Rules::Jokers) < hand("22222", Rules::Jokers));
+        assert!(hand("JJJJJ", Rules::Jokers) < hand("22222", Rules::Jokers));
+
+        // Under standard rules J sits between T and Q
+        assert!(hand("JKKK2", Rules::Standard) > hand("TKKK2", Rules::Standard));
+        assert!(hand("JKKK2", Rules::Standard) < hand("QKKK2", Rules::Standard));
+
+        assert_eq!(hand("AKQT9", Rules::Standard).cmp(&hand("AKQT9", Rules::Standard)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_total_winnings() {
+        assert_eq!(get_total_winnings(parse_input(EXAMPLE, Rules::Standard).unwrap()), 6440);
+        assert_eq!(get_total_winnings(parse_input(EXAMPLE, Rules::Jokers).unwrap()), 5905);
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2023::d08::HauntedWasteland;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&HauntedWasteland, "inputs/2023/08/input.txt");
+}
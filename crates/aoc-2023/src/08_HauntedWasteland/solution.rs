@@ -0,0 +1,304 @@
+use crate::prelude::*;
+use crate::{ cache, math::align };
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse line: {0}")]
+    ParsingError(Excerpt),
+    #[error("Unknown node: {0}")]
+    UnknownNode(Excerpt),
+    #[error("{1} can't be reached from {0}")]
+    Unreachable(String, String),
+    #[error("The ghosts never stand on Z nodes at the same time")]
+    NeverAligned
+}
+
+type NodeId = usize;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Turn {
+    Left,
+    Right
+}
+
+/// Node names are interned, so walking the network only deals with indices.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Network {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    links: Vec<[NodeId; 2]>
+}
+
+impl Network {
+    fn new() -> Network {
+        Network { names: vec![], ids: HashMap::default(), links: vec![] }
+    }
+
+    fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.links.push([id, id]);
+        id
+    }
+
+    fn id(&self, name: &str) -> Result<NodeId, Error> {
+        self.ids.get(name).copied().ok_or_else(|| Error::UnknownNode(name.into()))
+    }
+
+    fn next(&self, node: NodeId, turn: Turn) -> NodeId {
+        match turn {
+            Turn::Left => self.links[node][0],
+            Turn::Right => self.links[node][1]
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Map {
+    turns: Vec<Turn>,
+    network: Network
+}
+
+/// The times at which a walk stands on a goal node. The walk is eventually periodic, since its
+/// state (node, position in the turns) is finite: after `cycle_start` steps it repeats every
+/// `cycle_length` steps.
+struct GoalTimes {
+    before_cycle: Vec<u64>,
+    in_cycle: Vec<u64>,
+    cycle_start: u64,
+    cycle_length: u64
+}
+
+impl GoalTimes {
+    fn contains(&self, time: u64) -> bool {
+        if time < self.cycle_start {
+            return self.before_cycle.contains(&time);
+        }
+
+        let first_lap_time = self.cycle_start + (time - self.cycle_start) % self.cycle_length;
+        self.in_cycle.contains(&first_lap_time)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Map, Error> {
+    lazy_static! {
+        static ref NODE_REGEX: Regex = Regex::new(r"^(?<node>\w+) = \((?<left>\w+), (?<right>\w+)\)$").unwrap();
+    }
+
+    let mut lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+    let turns_line = lines.next().unwrap_or_default();
+    let turns = turns_line
+        .chars()
+        .map(|ch| match ch {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            _ => Err(Error::ParsingError(turns_line.into()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if turns.is_empty() {
+        return Err(Error::ParsingError(turns_line.into()));
+    }
+
+    let mut network = Network::new();
+    for line in lines {
+        let captures = NODE_REGEX.captures(line).ok_or_else(|| Error::ParsingError(line.into()))?;
+        let node = network.intern(&captures["node"]);
+        let left = network.intern(&captures["left"]);
+        let right = network.intern(&captures["right"]);
+        network.links[node] = [left, right];
+    }
+
+    Ok(Map { turns, network })
+}
+
+fn count_steps(map: &Map, from: &str, to: &str) -> Result<u64, Error> {
+    let (mut node, goal) = (map.network.id(from)?, map.network.id(to)?);
+
+    // A walk that doesn't reach the goal within one lap of every state never will
+    let max_steps = map.network.names.len() * map.turns.len();
+    for (steps, turn) in map.turns.iter().cycle().take(max_steps).enumerate() {
+        if node == goal {
+            return Ok(steps as u64);
+        }
+
+        node = map.network.next(node, *turn);
+    }
+
+    Err(Error::Unreachable(from.to_string(), to.to_string()))
+}
+
+fn find_goal_times(map: &Map, start: NodeId, is_goal: impl Fn(NodeId) -> bool) -> GoalTimes {
+    let mut first_seen: HashMap<(NodeId, usize), u64> = HashMap::default();
+    let mut goal_times = vec![];
+    let mut node = start;
+
+    for time in 0.. {
+        let turn_idx = time as usize % map.turns.len();
+        if let Some(cycle_start) = first_seen.insert((node, turn_idx), time) {
+            let (before_cycle, in_cycle) = goal_times.into_iter().partition(|goal_time| *goal_time < cycle_start);
+            return GoalTimes { before_cycle, in_cycle, cycle_start, cycle_length: time - cycle_start };
+        }
+
+        if is_goal(node) {
+            goal_times.push(time);
+        }
+
+        node = map.network.next(node, map.turns[turn_idx]);
+    }
+
+    unreachable!()
+}
+
+/// First time at which every walk stands on a goal. Goals seen before a walk settles into its
+/// cycle are checked directly; within the cycles, every combination of goal offsets is aligned,
+/// so the usual "single goal exactly one cycle in" shape is not assumed.
+fn find_first_alignment(walks: &[GoalTimes]) -> Option<u64> {
+    let all_cycles_start = walks.iter().map(|walk| walk.cycle_start).max()?;
+
+    let early = (0..all_cycles_start).find(|time| walks.iter().all(|walk| walk.contains(*time)));
+    if early.is_some() {
+        return early;
+    }
+
+    // Each goal recurs every lap from its first time once every walk is in its cycle
+    let first_after_start = |goal_time: u64, length: u64| all_cycles_start + (goal_time % length + length - all_cycles_start % length) % length;
+    walks
+        .iter()
+        .map(|walk| walk.in_cycle.iter().map(move |goal_time| (first_after_start(*goal_time, walk.cycle_length), walk.cycle_length)))
+        .multi_cartesian_product()
+        .filter_map(|goals| Some(align(goals)?.0))
+        .min()
+}
+
+fn count_ghost_steps(map: &Map) -> Result<u64, Error> {
+    let is_goal = |node: NodeId| map.network.names[node].ends_with('Z');
+    let walks: Vec<GoalTimes> = (0..map.network.names.len())
+        .filter(|node| map.network.names[*node].ends_with('A'))
+        .map(|start| find_goal_times(map, start, is_goal))
+        .collect();
+
+    find_first_alignment(&walks).ok_or(Error::NeverAligned)
+}
+
+pub struct HauntedWasteland;
+
+impl Solver for HauntedWasteland {
+    type Parsed = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(cache::parsed(&self.metadata(), input, parse_input)?)
+    }
+
+    fn part_1(&self, map: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(count_steps(map, "AAA", "ZZZ")?.into())
+    }
+
+    fn part_2(&self, map: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(count_ghost_steps(map)?.into())
+    }
+}
+
+impl Solution for HauntedWasteland {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 8,
+            title: "Haunted Wasteland",
+            tags: &["graph", "number-theory"],
+            runtime: RuntimeTier::Fast,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE_1: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_2: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn test_parse() {
+        let map = parse_input(EXAMPLE_1).unwrap();
+        assert_eq!(map.turns, vec![Turn::Left, Turn::Left, Turn::Right]);
+        assert_eq!(map.network.names, vec!["AAA", "BBB", "ZZZ"]);
+        assert_eq!(map.network.links, vec![[1, 1], [0, 2], [2, 2]]);
+        assert!(parse_input("LXR\n\nAAA = (AAA, AAA)").is_err());
+        assert!(parse_input("LR\n\nAAA = AAA, AAA").is_err());
+    }
+
+    #[test]
+    fn test_count_steps() {
+        assert_eq!(count_steps(&parse_input(EXAMPLE_1).unwrap(), "AAA", "ZZZ").unwrap(), 6);
+        assert!(matches!(count_steps(&parse_input(EXAMPLE_2).unwrap(), "AAA", "ZZZ"), Err(Error::UnknownNode(_))));
+    }
+
+    #[test]
+    fn test_goal_times() {
+        let map = parse_input(EXAMPLE_2).unwrap();
+        let walk = find_goal_times(&map, map.network.id("22A").unwrap(), |node| map.network.names[node] == "22Z");
+        assert_eq!((walk.cycle_start, walk.cycle_length), (1, 6));
+        assert_eq!(walk.in_cycle, vec![3, 6]);
+        assert!(walk.contains(9) && walk.contains(12) && !walk.contains(10));
+    }
+
+    #[test]
+    fn test_ghost_steps() {
+        assert_eq!(count_ghost_steps(&parse_input(EXAMPLE_2).unwrap()).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_alignment_with_offsets() {
+        // Goals at 2 + 5k and 4 + 7k first coincide at 32, which a plain LCM would miss
+        let walks = [
+            GoalTimes { before_cycle: vec![], in_cycle: vec![2], cycle_start: 0, cycle_length: 5 },
+            GoalTimes { before_cycle: vec![1], in_cycle: vec![4], cycle_start: 3, cycle_length: 7 }
+        ];
+
+        assert_eq!(find_first_alignment(&walks), Some(32));
+    }
+
+    #[test]
+    fn test_alignment_before_cycles() {
+        let walks = [
+            GoalTimes { before_cycle: vec![1], in_cycle: vec![10], cycle_start: 5, cycle_length: 7 },
+            GoalTimes { before_cycle: vec![], in_cycle: vec![1], cycle_start: 0, cycle_length: 2 }
+        ];
+
+        assert_eq!(find_first_alignment(&walks), Some(1));
+    }
+}
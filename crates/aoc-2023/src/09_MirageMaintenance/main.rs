@@ -0,0 +1,6 @@
+use aoc_2023::d09::MirageMaintenance;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&MirageMaintenance, "inputs/2023/09/input.txt");
+}
@@ -0,0 +1,98 @@
+use crate::prelude::*;
+use crate::math::extrapolate;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse history: {0}")]
+    ParsingError(Excerpt),
+    #[error("Extrapolation overflowed or had no values")]
+    ExtrapolationError
+}
+
+fn parse_history(line: &str) -> Result<Vec<i64>, Error> {
+    line.split_whitespace()
+        .map(|value| value.parse::<i64>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| Error::ParsingError(line.into()))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_history).collect()
+}
+
+fn sum_extrapolated(histories: &[Vec<i64>], position: impl Fn(&[i64]) -> i64) -> Result<i64, Error> {
+    histories
+        .iter()
+        .map(|history| extrapolate(history, position(history)).ok_or(Error::ExtrapolationError))
+        .sum()
+}
+
+pub struct MirageMaintenance;
+
+impl Solution for MirageMaintenance {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 9,
+            title: "Mirage Maintenance",
+            tags: &["math"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let histories = parse_input(input)?;
+        let solution_1 = sum_extrapolated(&histories, |history| history.len() as i64)?;
+        let solution_2 = sum_extrapolated(&histories, |_| -1)?;
+        Ok((solution_1.into(), solution_2.into()))
+    }
+
+    /// Histories are independent of each other, so they can be extrapolated while being read.
+    fn solve_lines(&self, lines: &mut dyn Iterator<Item = String>) -> Result<Answers, SolutionError> {
+        let (mut solution_1, mut solution_2) = (0i64, 0i64);
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let history = parse_history(&line)?;
+            solution_1 += extrapolate(&history, history.len() as i64).ok_or(Error::ExtrapolationError)?;
+            solution_2 += extrapolate(&history, -1).ok_or(Error::ExtrapolationError)?;
+        }
+
+        Ok((solution_1.into(), solution_2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn test_parse() {
+        let histories = parse_input(EXAMPLE).unwrap();
+        assert_eq!(histories[2], vec![10, 13, 16, 21, 30, 45]);
+        assert!(parse_input("1 2 x").is_err());
+    }
+
+    #[test]
+    fn test_forward() {
+        let histories = parse_input(EXAMPLE).unwrap();
+        assert_eq!(sum_extrapolated(&histories, |history| history.len() as i64).unwrap(), 114);
+    }
+
+    #[test]
+    fn test_backward() {
+        let histories = parse_input(EXAMPLE).unwrap();
+        assert_eq!(sum_extrapolated(&histories, |_| -1).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_solve_lines() {
+        let mut lines = EXAMPLE.lines().map(str::to_string);
+        assert_eq!(MirageMaintenance.solve_lines(&mut lines).unwrap(), MirageMaintenance.solve(EXAMPLE).unwrap());
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2023::d10::PipeMaze;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&PipeMaze, "inputs/2023/10/input.txt");
+}
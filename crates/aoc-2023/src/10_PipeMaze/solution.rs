@@ -0,0 +1,188 @@
+use crate::prelude::*;
+use crate::geometry::interior_points;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse maze: {0}")]
+    ParsingError(#[from] grid::Error),
+    #[error("The maze has no starting tile")]
+    MissingStart,
+    #[error("The starting tile isn't part of a closed loop")]
+    OpenLoop
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Tile {
+    Pipe(Direction, Direction),
+    Ground,
+    Start
+}
+
+impl Tile {
+    fn parse(ch: char) -> Option<Tile> {
+        use Direction::*;
+
+        let tile = match ch {
+            '|' => Tile::Pipe(Up, Down),
+            '-' => Tile::Pipe(Left, Right),
+            'L' => Tile::Pipe(Up, Right),
+            'J' => Tile::Pipe(Up, Left),
+            '7' => Tile::Pipe(Down, Left),
+            'F' => Tile::Pipe(Down, Right),
+            '.' => Tile::Ground,
+            'S' => Tile::Start,
+            _ => return None
+        };
+
+        Some(tile)
+    }
+
+    /// Where a pipe leads when entered by moving in `heading`, if it can be entered that way.
+    fn exit(&self, heading: Direction) -> Option<Direction> {
+        let Tile::Pipe(first, second) = *self
+        else {
+            return None;
+        };
+
+        let entry = heading.opposite();
+        if entry == first { Some(second) }
+        else if entry == second { Some(first) }
+        else { None }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<Tile>, Error> {
+    Ok(Grid::parse(input, Tile::parse)?)
+}
+
+/// Follows the loop through the start tile, returning the position of every tile on it.
+fn find_loop(maze: &Grid<Tile>) -> Result<Vec<Vec2>, Error> {
+    let start = maze.find(|tile| *tile == Tile::Start).ok_or(Error::MissingStart)?;
+
+    // Whatever pipe hides under the start, following any of its two connections leads back
+    Direction::ALL
+        .iter()
+        .find_map(|heading| follow_loop(maze, start, *heading))
+        .ok_or(Error::OpenLoop)
+}
+
+fn follow_loop(maze: &Grid<Tile>, start: Vec2, mut heading: Direction) -> Option<Vec<Vec2>> {
+    let mut tiles = vec![start];
+    let mut position = start + heading.offset();
+
+    while position != start {
+        tiles.push(position);
+        heading = maze.get(position)?.exit(heading)?;
+        position += heading.offset();
+    }
+
+    Some(tiles)
+}
+
+pub struct PipeMaze;
+
+impl Solution for PipeMaze {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 10,
+            title: "Pipe Maze",
+            tags: &["grid", "geometry"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let pipe_loop = find_loop(&parse_input(input)?)?;
+        Ok(((pipe_loop.len() / 2).into(), interior_points(&pipe_loop).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SIMPLE_LOOP: &str = "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+";
+
+    const COMPLEX_LOOP: &str = "\
+7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ
+";
+
+    const ENCLOSED: &str = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+
+    const LARGER_ENCLOSED: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+    const JUNK_PIPES: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn test_parse() {
+        let maze = parse_input(SIMPLE_LOOP).unwrap();
+        assert_eq!(maze[Vec2::new(1, 1)], Tile::Start);
+        assert_eq!(maze[Vec2::new(4, 0)], Tile::Pipe(Direction::Down, Direction::Left));
+        assert!(parse_input("S-X").is_err());
+    }
+
+    #[test]
+    fn test_farthest_point() {
+        assert_eq!(find_loop(&parse_input(SIMPLE_LOOP).unwrap()).unwrap().len() / 2, 4);
+        assert_eq!(find_loop(&parse_input(COMPLEX_LOOP).unwrap()).unwrap().len() / 2, 8);
+    }
+
+    #[test]
+    fn test_enclosed_tiles() {
+        for (maze, expected) in [(ENCLOSED, 4), (LARGER_ENCLOSED, 8), (JUNK_PIPES, 10)] {
+            let pipe_loop = find_loop(&parse_input(maze).unwrap()).unwrap();
+            assert_eq!(interior_points(&pipe_loop), expected);
+        }
+    }
+
+    #[test]
+    fn test_broken_loops() {
+        assert!(matches!(find_loop(&parse_input("...\n.-.\n...").unwrap()), Err(Error::MissingStart)));
+        assert!(matches!(find_loop(&parse_input("S-7\n|.|\nL-.").unwrap()), Err(Error::OpenLoop)));
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2023::d12::HotSprings;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&HotSprings, "inputs/2023/12/input.txt");
+}
@@ -0,0 +1,167 @@
+use crate::prelude::*;
+use rayon::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse record: {0}")]
+    ParsingError(Excerpt)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<usize>
+}
+
+impl Record {
+    fn unfold(&self, copies: usize) -> Record {
+        let springs = vec![self.springs.clone(); copies].join(&Spring::Unknown);
+        Record { springs, groups: self.groups.repeat(copies) }
+    }
+
+    fn count_arrangements(&self) -> u64 {
+        let (springs, groups) = (&self.springs, &self.groups);
+
+        // Arrangements of springs[position..] that match groups[group..]
+        let mut memo = Memo::new(|memo: &mut Memo<(usize, usize), u64>, (position, group): (usize, usize)| {
+            let Some(spring) = springs.get(position)
+            else {
+                return (group == groups.len()) as u64;
+            };
+
+            let mut count = 0;
+            if *spring != Spring::Damaged {
+                count += memo.get((position + 1, group));
+            }
+
+            if *spring != Spring::Operational {
+                if let Some(size) = groups.get(group) {
+                    let end = position + size;
+                    let fits = end <= springs.len() && springs[position..end].iter().all(|spring| *spring != Spring::Operational);
+                    let separated = springs.get(end) != Some(&Spring::Damaged);
+                    if fits && separated {
+                        count += memo.get((end + 1, group + 1));
+                    }
+                }
+            }
+
+            count
+        });
+
+        memo.get((0, 0))
+    }
+}
+
+fn parse_record(line: &str) -> Result<Record, Error> {
+    let make_error = || Error::ParsingError(line.into());
+    let (springs, groups) = line.split_once(' ').ok_or_else(make_error)?;
+
+    let springs = springs
+        .chars()
+        .map(|ch| match ch {
+            '.' => Some(Spring::Operational),
+            '#' => Some(Spring::Damaged),
+            '?' => Some(Spring::Unknown),
+            _ => None
+        })
+        .collect::<Option<_>>()
+        .ok_or_else(make_error)?;
+
+    let groups = groups.split(',').map(|size| size.trim().parse()).try_collect().map_err(|_| make_error())?;
+    Ok(Record { springs, groups })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Record>, Error> {
+    input.lines().filter(|line| !line.trim().is_empty()).map(parse_record).collect()
+}
+
+/// Records are independent of each other, so they can be counted in parallel.
+fn sum_arrangements(records: &[Record], copies: usize, parallel: bool) -> u64 {
+    let count = |record: &Record| record.unfold(copies).count_arrangements();
+    match parallel {
+        true => records.par_iter().map(count).sum(),
+        false => records.iter().map(count).sum()
+    }
+}
+
+pub struct HotSprings;
+
+impl Solution for HotSprings {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 12,
+            title: "Hot Springs",
+            tags: &["dynamic-programming", "memoization"],
+            runtime: RuntimeTier::Fast,
+            strategies: &[solution::SERIAL, solution::PARALLEL]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        self.solve_with_strategy(input, solution::SERIAL)
+    }
+
+    fn solve_with_strategy(&self, input: &str, strategy: &str) -> Result<Answers, SolutionError> {
+        let parallel = match strategy {
+            solution::PARALLEL => true,
+            solution::SERIAL => false,
+            _ => return Err(format!("Unknown strategy: {strategy}").into())
+        };
+
+        let records = parse_input(input)?;
+        Ok((sum_arrangements(&records, 1, parallel).into(), sum_arrangements(&records, 5, parallel).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn test_parse() {
+        let record = parse_record("#.? 1,1").unwrap();
+        assert_eq!(record.springs, vec![Spring::Damaged, Spring::Operational, Spring::Unknown]);
+        assert_eq!(record.groups, vec![1, 1]);
+        assert!(parse_record("#.x 1").is_err());
+        assert!(parse_record("#.? 1,a").is_err());
+    }
+
+    #[test]
+    fn test_unfold() {
+        assert_eq!(parse_record(".# 1").unwrap().unfold(5), parse_record(".#?.#?.#?.#?.# 1,1,1,1,1").unwrap());
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let counts: Vec<u64> = parse_input(EXAMPLE).unwrap().iter().map(Record::count_arrangements).collect();
+        assert_eq!(counts, vec![1, 4, 1, 1, 4, 10]);
+    }
+
+    #[test]
+    fn test_unfolded_arrangements() {
+        let records = parse_input(EXAMPLE).unwrap();
+        let counts: Vec<u64> = records.iter().map(|record| record.unfold(5).count_arrangements()).collect();
+        assert_eq!(counts, vec![1, 16384, 1, 16, 2500, 506250]);
+        assert_eq!(sum_arrangements(&records, 5, false), 525152);
+        assert_eq!(sum_arrangements(&records, 5, true), 525152);
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2023::d17::ClumsyCrucible;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&ClumsyCrucible, "inputs/2023/17/input.txt");
+}
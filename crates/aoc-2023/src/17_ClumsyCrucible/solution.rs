@@ -0,0 +1,146 @@
+use crate::prelude::*;
+use crate::{ graph::dijkstra, solution::{ CancellationToken, Progress } };
+use arrayvec::ArrayVec;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse city map: {0}")]
+    ParsingError(#[from] grid::Error),
+    #[error("The crucible can't reach the factory")]
+    NoPath
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Crucible {
+    min_run: u8,
+    max_run: u8
+}
+
+const CRUCIBLE: Crucible = Crucible { min_run: 1, max_run: 3 };
+const ULTRA_CRUCIBLE: Crucible = Crucible { min_run: 4, max_run: 10 };
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+struct State {
+    position: Vec2,
+    direction: Direction,
+    /// Blocks moved in a straight line in `direction`
+    run: u8
+}
+
+fn parse_input(input: &str) -> Result<Grid<u64>, Error> {
+    Ok(Grid::parse(input, |ch| ch.to_digit(10).map(u64::from))?)
+}
+
+/// Once `cancellation` is cancelled states stop having successors, so the search winds down
+/// without finding a path.
+fn find_least_heat_loss(city: &Grid<u64>, crucible: Crucible, cancellation: &CancellationToken) -> Option<u64> {
+    let factory = Vec2::new(city.width() as i64 - 1, city.height() as i64 - 1);
+    let starts = [Direction::Right, Direction::Down].map(|direction| State { position: Vec2::ZERO, direction, run: 0 });
+
+    // At most straight ahead and both turns, so neither list needs the heap
+    let successors = |state: &State| {
+        let mut directions = ArrayVec::<Direction, 3>::new();
+        if cancellation.is_cancelled() {
+            return ArrayVec::new();
+        }
+
+        if state.run < crucible.max_run {
+            directions.push(state.direction);
+        }
+        if state.run >= crucible.min_run {
+            directions.extend([state.direction.turn_left(), state.direction.turn_right()]);
+        }
+
+        directions
+            .into_iter()
+            .filter_map(|direction| {
+                let position = state.position + direction.offset();
+                let run = if direction == state.direction { state.run + 1 } else { 1 };
+                city.get(position).map(|heat_loss| (State { position, direction, run }, *heat_loss))
+            })
+            .collect::<ArrayVec<_, 3>>()
+    };
+
+    let is_goal = |state: &State| state.position == factory && state.run >= crucible.min_run;
+    dijkstra(starts, successors, is_goal).map(|(heat_loss, _)| heat_loss)
+}
+
+pub struct ClumsyCrucible;
+
+impl Solution for ClumsyCrucible {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2023,
+            day: 17,
+            title: "Clumsy Crucible",
+            tags: &["grid", "dijkstra"],
+            runtime: RuntimeTier::Slow,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        self.solve_anytime(input, &Progress::default())
+    }
+
+    /// Each part is a long search of its own, so the first answer is worth keeping early.
+    fn solve_anytime(&self, input: &str, progress: &Progress) -> Result<Answers, SolutionError> {
+        let city = parse_input(input)?;
+        let cancellation = progress.cancellation();
+        let solution_1 = tracing::info_span!("part_1").in_scope(|| find_least_heat_loss(&city, CRUCIBLE, cancellation));
+        cancellation.check()?;
+        let solution_1 = solution_1.ok_or(Error::NoPath)?;
+        progress.improve(1, solution_1);
+
+        let solution_2 = tracing::info_span!("part_2").in_scope(|| find_least_heat_loss(&city, ULTRA_CRUCIBLE, cancellation));
+        cancellation.check()?;
+        let solution_2 = solution_2.ok_or(Error::NoPath)?;
+        Ok((solution_1.into(), solution_2.into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533
+";
+
+    const ULTRA_EXAMPLE: &str = "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991
+";
+
+    #[test]
+    fn test_crucible() {
+        assert_eq!(find_least_heat_loss(&parse_input(EXAMPLE).unwrap(), CRUCIBLE, &CancellationToken::default()), Some(102));
+    }
+
+    #[test]
+    fn test_ultra_crucible() {
+        assert_eq!(find_least_heat_loss(&parse_input(EXAMPLE).unwrap(), ULTRA_CRUCIBLE, &CancellationToken::default()), Some(94));
+        assert_eq!(find_least_heat_loss(&parse_input(ULTRA_EXAMPLE).unwrap(), ULTRA_CRUCIBLE, &CancellationToken::default()), Some(71));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // The ultra crucible can't stop after moving less than four blocks
+        assert_eq!(find_least_heat_loss(&parse_input("12\n34").unwrap(), ULTRA_CRUCIBLE, &CancellationToken::default()), None);
+    }
+}
@@ -0,0 +1,45 @@
+//! The solutions of 2023, a module per day.
+
+use aoc_core::solution::Register;
+// The days use the shared code as if it were part of this crate, e.g. `crate::prelude`
+use aoc_core::*;
+
+#[path = "01_Trebuchet/solution.rs"]
+pub mod d01;
+#[path = "02_CubeConundrum/solution.rs"]
+pub mod d02;
+#[path = "03_GearRatios/solution.rs"]
+pub mod d03;
+#[path = "04_Scratchcards/solution.rs"]
+pub mod d04;
+#[path = "05_IfYouGiveASeedAFertilizer/solution.rs"]
+pub mod d05;
+#[path = "06_WaitForIt/solution.rs"]
+pub mod d06;
+#[path = "07_CamelCards/solution.rs"]
+pub mod d07;
+#[path = "08_HauntedWasteland/solution.rs"]
+pub mod d08;
+#[path = "09_MirageMaintenance/solution.rs"]
+pub mod d09;
+#[path = "10_PipeMaze/solution.rs"]
+pub mod d10;
+#[path = "12_HotSprings/solution.rs"]
+pub mod d12;
+#[path = "17_ClumsyCrucible/solution.rs"]
+pub mod d17;
+
+pub fn register(registry: &mut impl Register) {
+    registry.register(Box::new(d01::Trebuchet));
+    registry.register(Box::new(d02::CubeConundrum));
+    registry.register(Box::new(d03::GearRatios));
+    registry.register(Box::new(d04::Scratchcards));
+    registry.register(Box::new(d05::IfYouGiveASeedAFertilizer));
+    registry.register(Box::new(d06::WaitForIt));
+    registry.register(Box::new(d07::CamelCards));
+    registry.register(Box::new(d08::HauntedWasteland));
+    registry.register(Box::new(d09::MirageMaintenance));
+    registry.register(Box::new(d10::PipeMaze));
+    registry.register(Box::new(d12::HotSprings));
+    registry.register(Box::new(d17::ClumsyCrucible));
+}
@@ -0,0 +1,6 @@
+use aoc_2024::d01::HistorianHysteria;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&HistorianHysteria, "inputs/2024/01/input.txt");
+}
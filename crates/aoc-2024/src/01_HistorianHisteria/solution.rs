@@ -0,0 +1,89 @@
+use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse location ids: {0}")]
+    ParsingError(Excerpt)
+}
+
+fn parse_pair(line: &str) -> Result<(i64, i64), Error> {
+    let make_error = || Error::ParsingError(line.into());
+    let (left, right) = line.split_whitespace().collect_tuple().ok_or_else(make_error)?;
+    Ok((left.parse().map_err(|_| make_error())?, right.parse().map_err(|_| make_error())?))
+}
+
+/// The two lists of location ids, each sorted.
+fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<i64>), Error> {
+    let pairs: Vec<_> = input.lines().filter(|line| !line.trim().is_empty()).map(parse_pair).collect::<Result<_, _>>()?;
+    let (mut left, mut right): (Vec<i64>, Vec<i64>) = pairs.into_iter().unzip();
+
+    left.sort_unstable();
+    right.sort_unstable();
+    Ok((left, right))
+}
+
+pub struct HistorianHysteria;
+
+impl Solver for HistorianHysteria {
+    type Parsed = (Vec<i64>, Vec<i64>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, (left, right): &Self::Parsed) -> Result<Answer, SolutionError> {
+        Ok(left.iter().zip(right).map(|(left, right)| left.abs_diff(*right)).sum::<u64>().into())
+    }
+
+    fn part_2(&self, (left, right): &Self::Parsed) -> Result<Answer, SolutionError> {
+        let occurrences: Counter<i64> = right.iter().copied().collect();
+        Ok(left.iter().map(|id| id * occurrences.count(id) as i64).sum::<i64>().into())
+    }
+}
+
+impl Solution for HistorianHysteria {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2024,
+            day: 1,
+            title: "Historian Hysteria",
+            tags: &["sorting", "counting"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_input(EXAMPLE).unwrap(), (vec![1, 2, 3, 3, 3, 4], vec![3, 3, 3, 4, 5, 9]));
+        assert!(matches!(parse_input("1   2   3"), Err(Error::ParsingError(_))));
+        assert!(matches!(parse_input("1   x"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(HistorianHysteria.solve(EXAMPLE).unwrap(), (11.into(), 31.into()));
+    }
+}
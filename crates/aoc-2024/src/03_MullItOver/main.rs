@@ -0,0 +1,6 @@
+use aoc_2024::d03::MullItOver;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&MullItOver, "inputs/2024/03/input.txt");
+}
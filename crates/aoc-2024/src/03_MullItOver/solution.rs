@@ -0,0 +1,102 @@
+use crate::prelude::*;
+use crate::scanner::Scanner;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont
+}
+
+fn scan_mul(scanner: &mut Scanner) -> Option<Instruction> {
+    scanner.eat("mul(").then_some(())?;
+    let left = scanner.number(3)?;
+    scanner.eat(",").then_some(())?;
+    let right = scanner.number(3)?;
+    scanner.eat(")").then_some(Instruction::Mul(left, right))
+}
+
+/// Extracts the well-formed instructions from the corrupted memory, skipping everything else.
+fn scan_instructions(memory: &str) -> Vec<Instruction> {
+    let mut scanner = Scanner::new(memory);
+    let mut instructions = vec![];
+
+    while !scanner.is_at_end() {
+        if let Some(mul) = scanner.attempt(scan_mul) {
+            instructions.push(mul);
+        }
+        else if scanner.eat("do()") {
+            instructions.push(Instruction::Do);
+        }
+        else if scanner.eat("don't()") {
+            instructions.push(Instruction::Dont);
+        }
+        else {
+            scanner.next_char();
+        }
+    }
+
+    instructions
+}
+
+fn sum_products(instructions: &[Instruction], conditionals: bool) -> u64 {
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(left, right) if enabled => sum += left * right,
+            Instruction::Do if conditionals => enabled = true,
+            Instruction::Dont if conditionals => enabled = false,
+            _ => ()
+        }
+    }
+
+    sum
+}
+
+pub struct MullItOver;
+
+impl Solution for MullItOver {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2024,
+            day: 3,
+            title: "Mull It Over",
+            tags: &["parsing"],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        let instructions = scan_instructions(input);
+        Ok((sum_products(&instructions, false).into(), sum_products(&instructions, true).into()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        let instructions = scan_instructions("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
+        assert_eq!(instructions, vec![Instruction::Mul(2, 4), Instruction::Mul(5, 5), Instruction::Mul(11, 8), Instruction::Mul(8, 5)]);
+        assert_eq!(sum_products(&instructions, false), 161);
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(scan_instructions("mul(4*mul(6,9!?(12,34)mul ( 2 , 4 )mul(1234,5)"), vec![]);
+        assert_eq!(scan_instructions("mulmul(1,2)mul(3,4"), vec![Instruction::Mul(1, 2)]);
+    }
+
+    #[test]
+    fn test_conditionals() {
+        let instructions = scan_instructions("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))");
+        assert_eq!(sum_products(&instructions, false), 161);
+        assert_eq!(sum_products(&instructions, true), 48);
+    }
+}
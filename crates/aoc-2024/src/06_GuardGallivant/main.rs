@@ -0,0 +1,6 @@
+use aoc_2024::d06::GuardGallivant;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&GuardGallivant, "inputs/2024/06/input.txt");
+}
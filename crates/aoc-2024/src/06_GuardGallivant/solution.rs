@@ -0,0 +1,144 @@
+use crate::prelude::*;
+use crate::cycles::find_cycle;
+use rayon::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse lab map: {0}")]
+    ParsingError(#[from] grid::Error),
+    #[error("The lab map has no guard")]
+    MissingGuard
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Lab {
+    obstructions: Grid<bool>,
+    guard: Walker
+}
+
+fn parse_input(input: &str) -> Result<Lab, Error> {
+    let (obstructions, markers) = Grid::parse_with_markers(input, &[('^', '.')], |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    })?;
+
+    let position = markers.get('^').ok_or(Error::MissingGuard)?;
+    Ok(Lab { obstructions, guard: Walker::new(position, Direction::Up) })
+}
+
+/// Where the guard goes next: a step forward, or a right turn if that step is obstructed.
+/// `None` once the guard leaves the lab.
+fn step(obstructions: &Grid<bool>, extra_obstruction: Option<Vec2>, guard: &Walker) -> Option<Walker> {
+    let ahead = guard.ahead();
+    let blocked = *obstructions.get(ahead)? || extra_obstruction == Some(ahead);
+
+    if blocked { Some(guard.turn_right()) }
+    else { Some(guard.step(1)) }
+}
+
+fn find_visited_positions(lab: &Lab) -> HashSet<Vec2> {
+    std::iter::successors(Some(lab.guard), |guard| step(&lab.obstructions, None, guard))
+        .map(|guard| guard.position)
+        .collect()
+}
+
+/// A new obstruction can only change the patrol if it's on the original path, the `visited`
+/// positions, so those are the only candidates. Each one is checked independently, so they can
+/// be checked in parallel.
+fn count_looping_obstructions(lab: &Lab, visited: HashSet<Vec2>, parallel: bool) -> usize {
+    let candidates: Vec<Vec2> = visited.into_iter().filter(|position| *position != lab.guard.position).collect();
+
+    let loops = |obstruction: &Vec2| find_cycle(lab.guard, |guard| step(&lab.obstructions, Some(*obstruction), guard)).is_some();
+    match parallel {
+        true => candidates.par_iter().filter(|obstruction| loops(obstruction)).count(),
+        false => candidates.iter().filter(|obstruction| loops(obstruction)).count()
+    }
+}
+
+/// Part 2 places obstructions along the path found by part 1.
+struct Patrol {
+    parallel: bool
+}
+
+impl Parts for Patrol {
+    type Shared = (Lab, HashSet<Vec2>);
+
+    fn part_1(&self, input: &str) -> Result<(Answer, Self::Shared), SolutionError> {
+        let lab = parse_input(input)?;
+        let visited = find_visited_positions(&lab);
+        Ok((visited.len().into(), (lab, visited)))
+    }
+
+    fn part_2(&self, _input: &str, (lab, visited): Self::Shared) -> Result<Answer, SolutionError> {
+        Ok(count_looping_obstructions(&lab, visited, self.parallel).into())
+    }
+}
+
+pub struct GuardGallivant;
+
+impl Solution for GuardGallivant {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: 2024,
+            day: 6,
+            title: "Guard Gallivant",
+            tags: &["grid", "simulation", "cycle-detection", "parallel"],
+            runtime: RuntimeTier::Fast,
+            strategies: &[solution::PARALLEL, solution::SERIAL]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        self.solve_with_strategy(input, solution::PARALLEL)
+    }
+
+    fn solve_with_strategy(&self, input: &str, strategy: &str) -> Result<Answers, SolutionError> {
+        let parallel = match strategy {
+            solution::PARALLEL => true,
+            solution::SERIAL => false,
+            _ => return Err(format!("Unknown strategy: {strategy}").into())
+        };
+
+        solve_parts(&Patrol { parallel }, input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn test_parse() {
+        let lab = parse_input(EXAMPLE).unwrap();
+        assert_eq!(lab.guard.position, Vec2::new(4, 6));
+        assert!(lab.obstructions[Vec2::new(4, 0)]);
+        assert!(!lab.obstructions[lab.guard.position]);
+        assert!(matches!(parse_input("..#\n..."), Err(Error::MissingGuard)));
+    }
+
+    #[test]
+    fn test_visited_positions() {
+        assert_eq!(find_visited_positions(&parse_input(EXAMPLE).unwrap()).len(), 41);
+    }
+
+    #[test]
+    fn test_looping_obstructions() {
+        let lab = parse_input(EXAMPLE).unwrap();
+        assert_eq!(count_looping_obstructions(&lab, find_visited_positions(&lab), true), 6);
+        assert_eq!(count_looping_obstructions(&lab, find_visited_positions(&lab), false), 6);
+    }
+}
@@ -0,0 +1,18 @@
+//! The solutions of 2024, a module per day.
+
+use aoc_core::solution::Register;
+// The days use the shared code as if it were part of this crate, e.g. `crate::prelude`
+use aoc_core::*;
+
+#[path = "01_HistorianHisteria/solution.rs"]
+pub mod d01;
+#[path = "03_MullItOver/solution.rs"]
+pub mod d03;
+#[path = "06_GuardGallivant/solution.rs"]
+pub mod d06;
+
+pub fn register(registry: &mut impl Register) {
+    registry.register(Box::new(d01::HistorianHysteria));
+    registry.register(Box::new(d03::MullItOver));
+    registry.register(Box::new(d06::GuardGallivant));
+}
@@ -0,0 +1,168 @@
+use crate::ocr;
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle. Most puzzles want a number, but some
+/// (e.g. letters drawn on a screen) want a string.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// Pixels drawn on a screen, one `Vec` per row with lit pixels set. Displayed as the
+    /// letters they spell, or as the picture itself on the lines below when OCR can't read it.
+    Grid(Vec<Vec<bool>>)
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Grid(pixels) => match ocr::read_letters(pixels) {
+                Ok(letters) => write!(f, "{letters}"),
+                Err(_) => pixels.iter().try_for_each(|row| {
+                    let row: String = row.iter().map(|lit| if *lit { '#' } else { '.' }).collect();
+                    write!(f, "\n{row}")
+                })
+            }
+        }
+    }
+}
+
+/// Answers are equal when they're displayed the same, since that's how they're stored and read
+/// back: a grid equals the text of the letters it spells.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Number(left), Answer::Number(right)) => left == right,
+            (Answer::Text(left), Answer::Text(right)) => left == right,
+            _ => self.to_string() == other.to_string()
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Reads back a displayed answer: anything that looks like a number becomes one.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(text.parse().map(Answer::Number).unwrap_or_else(|_| Answer::Text(text.to_string())))
+    }
+}
+
+/// Numbers are serialized as numbers, anything else as the displayed text.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Answer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer
+    {
+        match self {
+            Answer::Number(number) => serializer.serialize_i128(*number),
+            other => serializer.collect_str(other)
+        }
+    }
+}
+
+// Untagged enums can't be deserialized into `i128`, so numbers are dispatched by hand
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Answer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de>
+    {
+        struct AnswerVisitor;
+
+        impl serde::de::Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("an integer or a string")
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Answer, E> {
+                Ok(Answer::Number(value as i128))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Answer, E> {
+                Ok(Answer::Number(value as i128))
+            }
+
+            fn visit_i128<E>(self, value: i128) -> Result<Answer, E> {
+                Ok(Answer::Number(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Answer, E> {
+                Ok(Answer::Text(value.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn raster(rows: &[&str]) -> Vec<Vec<bool>> {
+        rows.iter().map(|row| row.chars().map(|ch| ch == '#').collect()).collect()
+    }
+
+    #[test]
+    fn test_display_grid() {
+        let letters = raster(&["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."]);
+        assert_eq!(Answer::Grid(letters).to_string(), "H");
+
+        let unreadable = raster(&["#.", ".#"]);
+        assert_eq!(Answer::Grid(unreadable).to_string(), "\n#.\n.#");
+    }
+
+    #[test]
+    fn test_eq() {
+        let letters = Answer::Grid(raster(&["#..#.", "#..#.", "####.", "#..#.", "#..#.", "#..#."]));
+        assert_eq!(letters, "H".parse().unwrap());
+        assert_ne!(letters, Answer::from("E"));
+
+        let unreadable = Answer::Grid(raster(&["#.", ".#"]));
+        assert_eq!(unreadable, unreadable.to_string().parse().unwrap());
+        assert_eq!(Answer::from(42), Answer::from("42"));
+        assert_ne!(Answer::from(42), Answer::from(43));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let answers = vec![Answer::from(13140), Answer::from("EHZFZHCZ")];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r#"[13140,"EHZFZHCZ"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+}
@@ -0,0 +1,526 @@
+//! Caches on disk, keyed by a hash of the puzzle input, to skip work when re-running an
+//! unchanged input. Everything lives under `.cache/` and can be deleted at any time.
+
+use crate::solution::Metadata;
+use std::sync::atomic::{ AtomicBool, Ordering };
+#[cfg(feature = "fs")]
+use {
+    crate::{ answer::Answer, date::PuzzleDate, solution::Answers },
+    std::{ collections::HashMap, io, path::{ Path, PathBuf }, time::{ Duration, SystemTime, UNIX_EPOCH } }
+};
+
+static PARSED_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns the parsed input cache on or off for the whole process. It's off by default, since a
+/// stale entry survives changes to a parser that don't change the shape of its output.
+pub fn enable_parsed(enabled: bool) {
+    PARSED_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// FNV-1a, which unlike `DefaultHasher` is guaranteed to give the same hash across builds.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3))
+}
+
+/// Parses `input` with `parse`, or loads the result of a previous parse of the same input.
+/// Without the `serde` and `fs` features, or with the cache disabled, this just parses.
+#[cfg(all(feature = "serde", feature = "fs"))]
+pub fn parsed<T, E>(metadata: &Metadata, input: &str, parse: impl FnOnce(&str) -> Result<T, E>) -> Result<T, E>
+where T: serde::Serialize + serde::de::DeserializeOwned
+{
+    let _span = tracing::info_span!("parse").entered();
+    if !PARSED_ENABLED.load(Ordering::Relaxed) {
+        return parse(input);
+    }
+
+    let directory = std::path::Path::new(".cache/parsed");
+    let path = directory.join(format!("{}-{:02}-{:016x}.json", metadata.year, metadata.day, input_hash(input)));
+    if let Some(parsed) = std::fs::read(&path).ok().and_then(|json| serde_json::from_slice(&json).ok()) {
+        return Ok(parsed);
+    }
+
+    let parsed = parse(input)?;
+    // Failing to write the cache only costs the next run some time
+    if let Ok(json) = serde_json::to_vec(&parsed) {
+        let _ = std::fs::create_dir_all(directory).and_then(|_| std::fs::write(&path, json));
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(not(all(feature = "serde", feature = "fs")))]
+pub fn parsed<T, E>(_metadata: &Metadata, input: &str, parse: impl FnOnce(&str) -> Result<T, E>) -> Result<T, E> {
+    let _span = tracing::info_span!("parse").entered();
+    parse(input)
+}
+
+/// A puzzle input, identified by its date and hash.
+#[cfg(feature = "fs")]
+type InputKey = (PuzzleDate, u64);
+
+#[cfg(feature = "fs")]
+fn input_key(metadata: &Metadata, input: &str) -> InputKey {
+    (metadata.date(), input_hash(input))
+}
+
+/// `answer` as it's read back from a cache file, e.g. a grid as the letters it spells.
+#[cfg(feature = "fs")]
+fn as_stored(answer: &Answer) -> Answer {
+    answer.to_string().parse().unwrap()
+}
+
+/// The answers computed for each input, and those known to be wrong, kept in a text file with
+/// one tab-separated `year day input-hash kind answer` entry per line.
+#[cfg(feature = "fs")]
+pub struct AnswerCache {
+    path: PathBuf,
+    solved: HashMap<InputKey, Answers>,
+    rejected: HashMap<(InputKey, u8), Vec<Answer>>
+}
+
+#[cfg(feature = "fs")]
+impl AnswerCache {
+    pub const DEFAULT_PATH: &str = ".cache/answers.tsv";
+
+    /// Loads the cache at `path`, which is empty if the file doesn't exist yet. Lines that
+    /// can't be understood are skipped, as if they weren't cached.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<AnswerCache> {
+        let path = path.as_ref().to_path_buf();
+        let contents = read_or_empty(&path)?;
+
+        let mut parts: HashMap<InputKey, [Option<Answer>; 2]> = HashMap::new();
+        let mut rejected: HashMap<(InputKey, u8), Vec<Answer>> = HashMap::new();
+        for fields in contents.lines().map(|line| line.split('\t').collect::<Vec<_>>()) {
+            let [year, day, hash, kind, answer] = fields[..] else { continue };
+            let (Ok(year), Ok(day), Ok(hash)) = (year.parse(), day.parse(), u64::from_str_radix(hash, 16)) else { continue };
+            let Ok(date) = PuzzleDate::new(year, day) else { continue };
+            let answer: Answer = unescape(answer).parse().unwrap();
+
+            match kind {
+                "1" | "2" => parts.entry((date, hash)).or_default()[usize::from(kind == "2")] = Some(answer),
+                "rejected-1" => rejected.entry(((date, hash), 1)).or_default().push(answer),
+                "rejected-2" => rejected.entry(((date, hash), 2)).or_default().push(answer),
+                _ => continue
+            }
+        }
+
+        let solved = parts
+            .into_iter()
+            .filter_map(|(key, parts)| match parts {
+                [Some(solution_1), Some(solution_2)] => Some((key, (solution_1, solution_2))),
+                _ => None
+            })
+            .collect();
+
+        Ok(AnswerCache { path, solved, rejected })
+    }
+
+    pub fn answers(&self, metadata: &Metadata, input: &str) -> Option<&Answers> {
+        self.solved.get(&input_key(metadata, input))
+    }
+
+    pub fn store(&mut self, metadata: &Metadata, input: &str, answers: &Answers) {
+        self.solved.insert(input_key(metadata, input), answers.clone());
+    }
+
+    /// Remembers that `answer` is wrong for `part` (1 or 2) of this input, as `aoc verify` finds.
+    pub fn reject(&mut self, metadata: &Metadata, input: &str, part: u8, answer: &Answer) {
+        let answer = as_stored(answer);
+        let rejected = self.rejected.entry((input_key(metadata, input), part)).or_default();
+        if !rejected.contains(&answer) {
+            rejected.push(answer);
+        }
+    }
+
+    /// Whether `answer` was already found wrong for `part` of this input, to warn when a change
+    /// brings it back.
+    pub fn is_rejected(&self, metadata: &Metadata, input: &str, part: u8, answer: &Answer) -> bool {
+        let answer = as_stored(answer);
+        self.rejected.get(&(input_key(metadata, input), part)).is_some_and(|rejected| rejected.contains(&answer))
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let line = |(date, hash): &InputKey, kind: &str, answer: &Answer| {
+            format!("{}\t{}\t{hash:016x}\t{kind}\t{}\n", date.year(), date.day(), escape(&answer.to_string()))
+        };
+
+        let lines: Vec<String> = self
+            .solved
+            .iter()
+            .flat_map(|(key, (solution_1, solution_2))| [line(key, "1", solution_1), line(key, "2", solution_2)])
+            .chain(self.rejected.iter().flat_map(|((key, part), answers)| {
+                answers.iter().map(move |answer| line(key, &format!("rejected-{part}"), answer))
+            }))
+            .collect();
+        write_sorted(&self.path, lines)
+    }
+}
+
+/// A hash of the sources a day's answers depend on: every `.rs` file in its folder under
+/// `crates/aoc-<year>/src`, and those of the shared library code in `crates/aoc-core/src`.
+#[cfg(feature = "fs")]
+pub fn source_hash(metadata: &Metadata) -> io::Result<u64> {
+    let core_directory = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src"));
+    let year_directory = core_directory.join(format!("../../aoc-{}/src", metadata.year));
+    let prefix = format!("{:02}_", metadata.day);
+    let day_directory = std::fs::read_dir(year_directory)?
+        .filter_map(Result::ok)
+        .find(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+        .ok_or(io::ErrorKind::NotFound)?
+        .path();
+
+    let mut contents = String::new();
+    for directory in [day_directory.as_path(), core_directory] {
+        for source in rust_sources(directory)? {
+            contents += &source.to_string_lossy();
+            contents += &std::fs::read_to_string(source)?;
+        }
+    }
+
+    Ok(input_hash(&contents))
+}
+
+/// The `.rs` files directly in `directory`, sorted so that they're always hashed in the same order.
+#[cfg(feature = "fs")]
+fn rust_sources(directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut sources = vec![];
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            sources.push(path);
+        }
+    }
+
+    sources.sort();
+    Ok(sources)
+}
+
+/// The source hash of each day when its answers were last verified, so unchanged days can be
+/// skipped. One tab-separated `year day source-hash` entry per line.
+#[cfg(feature = "fs")]
+pub struct SourceHashes {
+    path: PathBuf,
+    hashes: HashMap<PuzzleDate, u64>
+}
+
+#[cfg(feature = "fs")]
+impl SourceHashes {
+    pub const DEFAULT_PATH: &str = ".cache/sources.tsv";
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<SourceHashes> {
+        let path = path.as_ref().to_path_buf();
+        let contents = read_or_empty(&path)?;
+
+        let hashes = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                let (year, day, hash) = (fields.next()?, fields.next()?, fields.next()?);
+                Some((PuzzleDate::new(year.parse().ok()?, day.parse().ok()?).ok()?, u64::from_str_radix(hash, 16).ok()?))
+            })
+            .collect();
+
+        Ok(SourceHashes { path, hashes })
+    }
+
+    /// Whether the sources of the day are the same as when `record` was last called for it.
+    pub fn is_unchanged(&self, metadata: &Metadata) -> bool {
+        let recorded = self.hashes.get(&metadata.date());
+        recorded.is_some_and(|recorded| source_hash(metadata).is_ok_and(|hash| hash == *recorded))
+    }
+
+    pub fn record(&mut self, metadata: &Metadata) {
+        match source_hash(metadata) {
+            Ok(hash) => self.hashes.insert(metadata.date(), hash),
+            Err(_) => self.hashes.remove(&metadata.date())
+        };
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let lines: Vec<String> = self.hashes.iter().map(|(date, hash)| format!("{}\t{}\t{hash:016x}\n", date.year(), date.day())).collect();
+        write_sorted(&self.path, lines)
+    }
+}
+
+/// How long each day took the last time it was solved, for `aoc stats`. Same format as
+/// `aoc bench --tsv`, one tab-separated `year day nanoseconds` entry per line.
+#[cfg(feature = "fs")]
+pub struct Timings {
+    path: PathBuf,
+    timings: HashMap<PuzzleDate, Duration>
+}
+
+#[cfg(feature = "fs")]
+impl Timings {
+    pub const DEFAULT_PATH: &str = ".cache/timings.tsv";
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Timings> {
+        let path = path.as_ref().to_path_buf();
+        let timings = timings_from_tsv(&read_or_empty(&path)?);
+        Ok(Timings { path, timings })
+    }
+
+    pub fn get(&self, date: PuzzleDate) -> Option<Duration> {
+        self.timings.get(&date).copied()
+    }
+
+    pub fn record(&mut self, metadata: &Metadata, elapsed: Duration) {
+        self.timings.insert(metadata.date(), elapsed);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        write_sorted(&self.path, vec![timings_to_tsv(&self.timings)])
+    }
+}
+
+/// One `year day nanoseconds` line per date, which is also what `aoc bench --tsv` prints.
+#[cfg(feature = "fs")]
+pub fn timings_to_tsv(times: &HashMap<PuzzleDate, Duration>) -> String {
+    let mut lines: Vec<_> = times.iter().map(|(date, time)| format!("{}\t{}\t{}\n", date.year(), date.day(), time.as_nanos())).collect();
+    lines.sort();
+    lines.concat()
+}
+
+/// Reads back the output of `timings_to_tsv`, skipping lines it can't understand.
+#[cfg(feature = "fs")]
+pub fn timings_from_tsv(tsv: &str) -> HashMap<PuzzleDate, Duration> {
+    tsv.lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let (year, day, nanos) = (fields.next()?.parse().ok()?, fields.next()?.parse().ok()?, fields.next()?.parse().ok()?);
+            Some((PuzzleDate::new(year, day).ok()?, Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+/// The stars earned on adventofcode.com each year, as last synced by `aoc stats --sync-stars`.
+/// One tab-separated `year stars` entry per line.
+#[cfg(feature = "fs")]
+pub struct Stars {
+    path: PathBuf,
+    stars: HashMap<u16, u8>
+}
+
+#[cfg(feature = "fs")]
+impl Stars {
+    pub const DEFAULT_PATH: &str = ".cache/stars.tsv";
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Stars> {
+        let path = path.as_ref().to_path_buf();
+        let stars = read_or_empty(&path)?
+            .lines()
+            .filter_map(|line| {
+                let (year, stars) = line.split_once('\t')?;
+                Some((year.parse().ok()?, stars.parse().ok()?))
+            })
+            .collect();
+
+        Ok(Stars { path, stars })
+    }
+
+    pub fn get(&self, year: u16) -> Option<u8> {
+        self.stars.get(&year).copied()
+    }
+
+    pub fn set(&mut self, year: u16, stars: u8) {
+        self.stars.insert(year, stars);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        write_sorted(&self.path, self.stars.iter().map(|(year, stars)| format!("{year}\t{stars}\n")).collect())
+    }
+}
+
+/// A downloaded input: when it was downloaded, and its hash at the time.
+#[cfg(feature = "fs")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Download {
+    pub at: SystemTime,
+    pub hash: u64
+}
+
+/// The inputs downloaded so far, so that `aoc fetch` can tell the inputs already on disk from
+/// those that went missing or were edited. One tab-separated `year day unix-seconds input-hash`
+/// entry per line.
+#[cfg(feature = "fs")]
+pub struct Downloads {
+    path: PathBuf,
+    downloads: HashMap<PuzzleDate, Download>
+}
+
+#[cfg(feature = "fs")]
+impl Downloads {
+    pub const DEFAULT_PATH: &str = ".cache/downloads.tsv";
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Downloads> {
+        let path = path.as_ref().to_path_buf();
+        let downloads = read_or_empty(&path)?
+            .lines()
+            .filter_map(|line| {
+                let [year, day, seconds, hash] = line.split('\t').collect::<Vec<_>>()[..] else { return None };
+                let date = PuzzleDate::new(year.parse().ok()?, day.parse().ok()?).ok()?;
+                let at = UNIX_EPOCH + Duration::from_secs(seconds.parse().ok()?);
+                Some((date, Download { at, hash: u64::from_str_radix(hash, 16).ok()? }))
+            })
+            .collect();
+
+        Ok(Downloads { path, downloads })
+    }
+
+    pub fn get(&self, date: PuzzleDate) -> Option<Download> {
+        self.downloads.get(&date).copied()
+    }
+
+    /// Remembers that `input` was just downloaded for the puzzle.
+    pub fn record(&mut self, date: PuzzleDate, input: &str) {
+        self.downloads.insert(date, Download { at: SystemTime::now(), hash: input_hash(input) });
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let line = |(date, download): (&PuzzleDate, &Download)| {
+            let seconds = download.at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+            format!("{}\t{}\t{seconds}\t{:016x}\n", date.year(), date.day(), download.hash)
+        };
+
+        write_sorted(&self.path, self.downloads.iter().map(line).collect())
+    }
+}
+
+/// The contents of a cache file, which is empty until first saved.
+#[cfg(feature = "fs")]
+pub fn read_or_empty(path: &Path) -> io::Result<String> {
+    match std::fs::read_to_string(path) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+        contents => contents
+    }
+}
+
+/// Writes the lines sorted, so that saving the same entries always gives the same file.
+#[cfg(feature = "fs")]
+pub fn write_sorted(path: &Path, mut lines: Vec<String>) -> io::Result<()> {
+    lines.sort();
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+
+    std::fs::write(path, lines.concat())
+}
+
+/// Answers drawn on a screen can span lines, so separators are escaped.
+#[cfg(feature = "fs")]
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n")
+}
+
+/// Undoes `escape`.
+#[cfg(feature = "fs")]
+pub fn unescape(answer: &str) -> String {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some('t') => unescaped.push('\t'),
+                Some('n') => unescaped.push('\n'),
+                Some(other) => unescaped.push(other),
+                None => break
+            },
+            ch => unescaped.push(ch)
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::RuntimeTier;
+
+    const METADATA: Metadata = Metadata { year: 2022, day: 10, title: "Test", tags: &[], runtime: RuntimeTier::Instant, strategies: &[] };
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), 0xcbf29ce484222325);
+        assert_eq!(input_hash("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(input_hash("ab"), input_hash("ba"));
+    }
+
+    #[test]
+    fn test_source_hashes() {
+        let path = std::env::temp_dir().join(format!("aoc_source_hashes_{}.tsv", std::process::id()));
+        let with_sources = METADATA;
+        let mut hashes = SourceHashes::open(&path).unwrap();
+        assert!(!hashes.is_unchanged(&with_sources));
+
+        let without_sources = Metadata { day: 25, ..METADATA };
+        hashes.record(&with_sources);
+        hashes.record(&without_sources);
+        hashes.save().unwrap();
+
+        let hashes = SourceHashes::open(&path).unwrap();
+        assert!(hashes.is_unchanged(&with_sources));
+        assert!(!hashes.is_unchanged(&without_sources));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_timings_tsv_round_trip() {
+        let date = |year, day| PuzzleDate::new(year, day).unwrap();
+        let times = HashMap::from([(date(2022, 16), Duration::from_micros(1500)), (date(2023, 6), Duration::from_nanos(42))]);
+        assert_eq!(timings_to_tsv(&times), "2022\t16\t1500000\n2023\t6\t42\n");
+        assert_eq!(timings_from_tsv(&timings_to_tsv(&times)), times);
+    }
+
+    #[test]
+    fn test_escape() {
+        for answer in ["ABC", "#..#\n#..#", "a\\tb\t\\"] {
+            assert_eq!(unescape(&escape(answer)), answer);
+            assert!(!escape(answer).contains(['\t', '\n']));
+        }
+    }
+
+    #[test]
+    fn test_downloads() {
+        let path = std::env::temp_dir().join(format!("aoc_downloads_{}.tsv", std::process::id()));
+        let mut downloads = Downloads::open(&path).unwrap();
+        assert_eq!(downloads.get(METADATA.date()), None);
+
+        downloads.record(METADATA.date(), "input");
+        downloads.save().unwrap();
+
+        let download = Downloads::open(&path).unwrap().get(METADATA.date()).unwrap();
+        assert_eq!(download.hash, input_hash("input"));
+        assert!(SystemTime::now().duration_since(download.at).unwrap() < Duration::from_secs(60));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_answer_cache() {
+        let path = std::env::temp_dir().join(format!("aoc_answer_cache_{}.tsv", std::process::id()));
+        let mut cache = AnswerCache::open(&path).unwrap();
+        assert_eq!(cache.answers(&METADATA, "input"), None);
+
+        cache.store(&METADATA, "input", &(13140.into(), "#..#\n#..#".into()));
+        cache.reject(&METADATA, "input", 2, &"EHZ".into());
+        cache.save().unwrap();
+
+        let cache = AnswerCache::open(&path).unwrap();
+        assert_eq!(cache.answers(&METADATA, "input"), Some(&(13140.into(), "#..#\n#..#".into())));
+        assert_eq!(cache.answers(&METADATA, "other input"), None);
+        assert!(cache.is_rejected(&METADATA, "input", 2, &"EHZ".into()));
+        assert!(!cache.is_rejected(&METADATA, "input", 1, &"EHZ".into()));
+        std::fs::remove_file(&path).unwrap();
+
+        // A rejected grid is recognised both as a grid and as the letters it spells
+        let letters = Answer::Grid(["#..#", "#..#", "####", "#..#", "#..#", "#..#"].iter().map(|row| row.chars().map(|ch| ch == '#').collect()).collect());
+        let mut cache = AnswerCache::open(&path).unwrap();
+        cache.reject(&METADATA, "input", 2, &letters);
+        assert!(cache.is_rejected(&METADATA, "input", 2, &"H".into()));
+        cache.save().unwrap();
+
+        let cache = AnswerCache::open(&path).unwrap();
+        assert!(cache.is_rejected(&METADATA, "input", 2, &letters));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
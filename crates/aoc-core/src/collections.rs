@@ -0,0 +1,25 @@
+//! Hash collections for the solutions. The std ones seed their hasher randomly and use SipHash,
+//! which is needlessly slow for the small integer and string keys puzzles hash, and makes
+//! anything that depends on iteration order change from run to run. These use FxHash instead,
+//! so they're both faster and deterministic.
+//!
+//! They're built with `default()` or `collect()` rather than `new()` and `from()`, which only
+//! exist for the default hasher.
+
+pub use hashbrown::{ hash_map, hash_set };
+pub use rustc_hash::FxBuildHasher;
+
+pub type HashMap<K, V> = hashbrown::HashMap<K, V, FxBuildHasher>;
+pub type HashSet<T> = hashbrown::HashSet<T, FxBuildHasher>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_iteration_order_is_reproducible() {
+        let order = || (0..1000).collect::<HashSet<u32>>().into_iter().collect::<Vec<_>>();
+        assert_eq!(order(), order());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::collections::{ hash_map, HashMap };
+use std::{ hash::Hash, ops::{ AddAssign, SubAssign } };
+
+/// A multiset that counts how many times each value was added.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counter<T>
+where T: Eq + Hash
+{
+    counts: HashMap<T, usize>
+}
+
+impl<T> Counter<T>
+where T: Eq + Hash
+{
+    pub fn new() -> Counter<T> {
+        Counter { counts: HashMap::default() }
+    }
+
+    pub fn add(&mut self, value: T) {
+        self.add_many(value, 1);
+    }
+
+    pub fn add_many(&mut self, value: T, count: usize) {
+        *self.counts.entry(value).or_default() += count;
+    }
+
+    /// How many times `value` was added. Values never added count zero.
+    pub fn count(&self, value: &T) -> usize {
+        self.counts.get(value).copied().unwrap_or(0)
+    }
+
+    /// Number of distinct values.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Total number of values added.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, T, usize> {
+        self.counts.iter()
+    }
+
+    /// All values with their counts, the most frequent first. Ties come in the order of the
+    /// hash map, which is the same from one run to the next.
+    pub fn most_common(&self) -> Vec<(&T, usize)> {
+        let mut counts: Vec<_> = self.counts.iter().map(|(value, count)| (value, *count)).collect();
+        counts.sort_by(|(_, left), (_, right)| right.cmp(left));
+        counts
+    }
+
+    /// Adds every value `other` counted, as many times. Also `+=`, while `-=` takes them away.
+    /// There's no `+` as it would hide `add`.
+    pub fn merge(&mut self, other: Counter<T>) {
+        for (value, count) in other.counts {
+            self.add_many(value, count);
+        }
+    }
+}
+
+impl<T> AddAssign for Counter<T>
+where T: Eq + Hash
+{
+    fn add_assign(&mut self, other: Counter<T>) {
+        self.merge(other);
+    }
+}
+
+/// Takes away the counts of `other`. Counts never go below zero, and values left with none are
+/// dropped, as if never added.
+impl<T> SubAssign for Counter<T>
+where T: Eq + Hash
+{
+    fn sub_assign(&mut self, other: Counter<T>) {
+        for (value, count) in other.counts {
+            if let hash_map::Entry::Occupied(mut entry) = self.counts.entry(value) {
+                match *entry.get() > count {
+                    true => *entry.get_mut() -= count,
+                    false => {
+                        entry.remove();
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> Default for Counter<T>
+where T: Eq + Hash
+{
+    fn default() -> Self {
+        Counter::new()
+    }
+}
+
+impl<T> FromIterator<T> for Counter<T>
+where T: Eq + Hash
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        for value in iter {
+            counter.add(value);
+        }
+
+        counter
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count() {
+        let counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(counter.count(&'a'), 5);
+        assert_eq!(counter.count(&'z'), 0);
+        assert_eq!(counter.len(), 5);
+        assert_eq!(counter.total(), 11);
+    }
+
+    #[test]
+    fn test_most_common() {
+        let mut counter: Counter<&str> = ["x", "y", "y"].into_iter().collect();
+        counter.add_many("z", 3);
+        let counts: Vec<usize> = counter.most_common().into_iter().map(|(_, count)| count).collect();
+        assert_eq!(counts, vec![3, 2, 1]);
+        assert_eq!(counter.most_common()[0], (&"z", 3));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let letters = |word: &str| word.chars().collect::<Counter<char>>();
+        let mut counter = letters("abb");
+        counter += letters("bc");
+        assert_eq!(counter, letters("abbbc"));
+        counter -= letters("bcdd");
+        assert_eq!(counter, letters("abb"));
+        counter -= letters("abbb");
+        assert!(counter.is_empty());
+
+        counter.merge(letters("ab"));
+        counter.add('a');
+        assert_eq!((counter.count(&'a'), counter.count(&'b'), counter.len()), (2, 1, 2));
+    }
+}
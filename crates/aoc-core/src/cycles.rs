@@ -0,0 +1,99 @@
+use crate::HashMap;
+use std::hash::Hash;
+
+/// The shape of an eventually periodic sequence of states: after `start` steps, the states
+/// repeat every `length` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize
+}
+
+impl Cycle {
+    /// The first step with the same state as after `n` steps: `n` itself before the cycle, its
+    /// position within the first round of the cycle after.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Iterates `step` from `initial` until a state repeats, returning the cycle it entered.
+/// Returns `None` if `step` ends the sequence first by returning `None`.
+pub fn find_cycle<S>(initial: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle>
+where S: Clone + Eq + Hash
+{
+    let mut first_seen: HashMap<S, usize> = HashMap::default();
+    let mut state = initial;
+
+    for index in 0.. {
+        if let Some(start) = first_seen.insert(state.clone(), index) {
+            return Some(Cycle { start, length: index - start });
+        }
+
+        state = step(&state)?;
+    }
+
+    unreachable!()
+}
+
+/// The state after `n` steps of `step` from `initial`. Once a state repeats the rest of the way
+/// is skipped, so `n` can be far more steps than could be simulated, e.g. a billion spin cycles.
+/// Returns `None` if `step` ends the sequence before.
+pub fn nth_state<S>(initial: S, n: usize, mut step: impl FnMut(&S) -> Option<S>) -> Option<S>
+where S: Clone + Eq + Hash
+{
+    let mut first_seen: HashMap<S, usize> = HashMap::default();
+    let mut states = vec![];
+    let mut state = initial;
+
+    for index in 0..n {
+        if let Some(start) = first_seen.insert(state.clone(), index) {
+            let cycle = Cycle { start, length: index - start };
+            return Some(states.swap_remove(cycle.equivalent_step(n)));
+        }
+
+        states.push(state.clone());
+        state = step(&state)?;
+    }
+
+    Some(state)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_cycle() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+        let step = |state: &u32| Some(if *state == 4 { 2 } else { state + 1 });
+        assert_eq!(find_cycle(0, step), Some(Cycle { start: 2, length: 3 }));
+    }
+
+    #[test]
+    fn test_pure_cycle() {
+        assert_eq!(find_cycle(0, |state: &u32| Some((state + 1) % 5)), Some(Cycle { start: 0, length: 5 }));
+    }
+
+    #[test]
+    fn test_terminating_sequence() {
+        assert_eq!(find_cycle(0, |state: &u32| (*state < 10).then_some(state + 1)), None);
+        assert_eq!(nth_state(0, 20, |state: &u32| (*state < 10).then_some(state + 1)), None);
+        assert_eq!(nth_state(0, 5, |state: &u32| (*state < 10).then_some(state + 1)), Some(5));
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let step = |state: &u32| Some(if *state == 4 { 2 } else { state + 1 });
+        let cycle = Cycle { start: 2, length: 3 };
+        assert_eq!(cycle.equivalent_step(1), 1);
+        assert_eq!(cycle.equivalent_step(7), 4);
+
+        let simulated: Vec<u32> = (0..20).map(|n| nth_state(0, n, step).unwrap()).collect();
+        assert_eq!(simulated, vec![0, 1, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4]);
+        assert_eq!(nth_state(0, 1_000_000_000, step), Some(4));
+    }
+}
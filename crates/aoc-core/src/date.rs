@@ -0,0 +1,129 @@
+//! The date of a puzzle, checked to be one Advent of Code actually had.
+
+use std::fmt::{ self, Display };
+use std::str::FromStr;
+use std::time::{ SystemTime, UNIX_EPOCH };
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("There is no Advent of Code {0}, the first one was in {first}", first = PuzzleDate::FIRST_YEAR)]
+    InvalidYear(u16),
+    #[error("There is no day {0}, puzzles go from day 1 to 25")]
+    InvalidDay(u8),
+    #[error("Failed to parse date '{0}', expected e.g. 2023-05 or 2023/5")]
+    ParsingError(String)
+}
+
+/// A day of Advent of Code, from 2015 day 1 up to the current year. Dates are ordered by year,
+/// then by day.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PuzzleDate {
+    year: u16,
+    day: u8
+}
+
+impl PuzzleDate {
+    pub const FIRST_YEAR: u16 = 2015;
+    pub const LAST_DAY: u8 = 25;
+
+    pub fn new(year: u16, day: u8) -> Result<PuzzleDate, Error> {
+        PuzzleDate::check_year(year)?;
+        match day {
+            1..=PuzzleDate::LAST_DAY => Ok(PuzzleDate { year, day }),
+            _ => Err(Error::InvalidDay(day))
+        }
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Every day of `year`, in order.
+    pub fn days_of(year: u16) -> Result<impl Iterator<Item = PuzzleDate>, Error> {
+        PuzzleDate::check_year(year)?;
+        Ok((1..=PuzzleDate::LAST_DAY).map(move |day| PuzzleDate { year, day }))
+    }
+
+    /// The year of the latest event, which is the current year: puzzles of the current year
+    /// that aren't out yet are still valid dates, so they can be prepared for.
+    pub fn latest_year() -> u16 {
+        let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let mut days = elapsed.as_secs() / (24 * 60 * 60);
+        let mut year = 1970;
+        loop {
+            let length = if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) { 366 } else { 365 };
+            if days < length {
+                return year;
+            }
+
+            days -= length;
+            year += 1;
+        }
+    }
+
+    fn check_year(year: u16) -> Result<(), Error> {
+        match (PuzzleDate::FIRST_YEAR..=PuzzleDate::latest_year()).contains(&year) {
+            true => Ok(()),
+            false => Err(Error::InvalidYear(year))
+        }
+    }
+}
+
+impl Display for PuzzleDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+/// Parses `2023-05`, `2023/5` and the like.
+impl FromStr for PuzzleDate {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let make_error = || Error::ParsingError(text.to_string());
+        let (year, day) = text.trim().split_once(['-', '/']).ok_or_else(make_error)?;
+        PuzzleDate::new(year.parse().map_err(|_| make_error())?, day.parse().map_err(|_| make_error())?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(PuzzleDate::new(2023, 5).map(|date| (date.year(), date.day())), Ok((2023, 5)));
+        assert_eq!(PuzzleDate::new(2014, 1), Err(Error::InvalidYear(2014)));
+        assert_eq!(PuzzleDate::new(PuzzleDate::latest_year() + 1, 1), Err(Error::InvalidYear(PuzzleDate::latest_year() + 1)));
+        assert_eq!(PuzzleDate::new(2023, 0), Err(Error::InvalidDay(0)));
+        assert_eq!(PuzzleDate::new(2023, 26), Err(Error::InvalidDay(26)));
+        assert!(PuzzleDate::latest_year() >= 2024);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("2023-05".parse(), PuzzleDate::new(2023, 5));
+        assert_eq!("2023/5".parse(), PuzzleDate::new(2023, 5));
+        assert_eq!("2022-12".parse::<PuzzleDate>().unwrap().to_string(), "2022 day 12");
+        assert_eq!("2023".parse::<PuzzleDate>(), Err(Error::ParsingError("2023".to_string())));
+        assert_eq!("2023-x".parse::<PuzzleDate>(), Err(Error::ParsingError("2023-x".to_string())));
+        assert_eq!("2023-30".parse::<PuzzleDate>(), Err(Error::InvalidDay(30)));
+    }
+
+    #[test]
+    fn test_order_and_days() {
+        assert!(PuzzleDate::new(2022, 25).unwrap() < PuzzleDate::new(2023, 1).unwrap());
+        assert!(PuzzleDate::new(2023, 2).unwrap() < PuzzleDate::new(2023, 10).unwrap());
+
+        let days: Vec<_> = PuzzleDate::days_of(2023).unwrap().collect();
+        assert_eq!(days.len(), 25);
+        assert_eq!((days[0].day(), days[24].day()), (1, 25));
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(PuzzleDate::days_of(2010).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Fetching puzzles, inputs, progress and private leaderboards from adventofcode.com. Most are
+//! personal, so requests are authenticated with the session cookie of a logged-in browser, read
+//! from `AOC_SESSION`.
+
+use crate::date::PuzzleDate;
+use std::collections::{ BTreeMap, HashMap };
+use std::time::Duration;
+
+const USER_AGENT: &str = "github.com/alessiocali/AdventOfCode-Rust";
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Set AOC_SESSION to the session cookie of adventofcode.com to download from it")]
+    MissingSession,
+    #[error("adventofcode.com didn't accept AOC_SESSION, the session probably expired")]
+    ExpiredSession,
+    #[error("The puzzle of {0} isn't unlocked yet")]
+    Locked(PuzzleDate),
+    #[error("adventofcode.com answered with a web page instead of an input")]
+    NotAnInput,
+    #[error("Leaderboard {0} doesn't exist, or you aren't a member")]
+    UnknownLeaderboard(u64),
+    #[error("Failed to parse the leaderboard: {0}")]
+    ParsingError(#[from] serde_json::Error),
+    #[error("Request to adventofcode.com failed: {0}")]
+    HttpError(Box<ureq::Error>),
+    #[error("Failed to read the response of adventofcode.com: {0}")]
+    IoError(#[from] std::io::Error)
+}
+
+/// The session cookie from the environment.
+pub fn session() -> Result<String, Error> {
+    std::env::var("AOC_SESSION").ok().filter(|session| !session.trim().is_empty()).ok_or(Error::MissingSession)
+}
+
+/// Downloads the input of the given puzzle. This blocks, and the site asks not to be hammered,
+/// so callers should fetch inputs once and keep them on disk.
+pub fn input(date: PuzzleDate, session: &str) -> Result<String, Error> {
+    let input = match get(&format!("https://adventofcode.com/{}/day/{}/input", date.year(), date.day()), session) {
+        Err(Error::HttpError(err)) if matches!(*err, ureq::Error::Status(400, _)) => return Err(Error::ExpiredSession),
+        Err(Error::HttpError(err)) if matches!(*err, ureq::Error::Status(404, _)) => return Err(Error::Locked(date)),
+        input => input?
+    };
+
+    check_input(date, &input)?;
+    Ok(input)
+}
+
+/// Fails for the pages the site serves in place of an input, which mustn't be saved as one:
+/// the login page when the session expired, or the notice for puzzles not out yet.
+pub fn check_input(date: PuzzleDate, input: &str) -> Result<(), Error> {
+    let start = input.trim_start();
+    if start.starts_with("Please don't repeatedly request this endpoint before it unlocks") {
+        Err(Error::Locked(date))
+    }
+    else if start.starts_with("Puzzle inputs differ by user") {
+        Err(Error::ExpiredSession)
+    }
+    else if start.starts_with('<') {
+        Err(Error::NotAnInput)
+    }
+    else {
+        Ok(())
+    }
+}
+
+/// Downloads the page of the given puzzle. Part 2 only shows once part 1 is solved, by whoever
+/// `session` belongs to. The page is public otherwise, so `session` can be empty.
+pub fn puzzle(date: PuzzleDate, session: &str) -> Result<String, Error> {
+    match get(&format!("https://adventofcode.com/{}/day/{}", date.year(), date.day()), session) {
+        Err(Error::HttpError(err)) if matches!(*err, ureq::Error::Status(404, _)) => Err(Error::Locked(date)),
+        page => page
+    }
+}
+
+/// The stars earned in the given year, counted from the calendar on its page.
+pub fn stars(year: u16, session: &str) -> Result<u8, Error> {
+    Ok(count_stars(&get(&format!("https://adventofcode.com/{year}"), session)?))
+}
+
+/// How often the site asks leaderboards to be fetched at most.
+pub const LEADERBOARD_REFRESH: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, in the JSON format of the site.
+#[derive(serde::Deserialize, Debug)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Member {
+    pub id: u64,
+    /// Anonymous users have none
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// When the latest star was earned, in seconds since the Unix epoch. 0 without stars
+    pub last_star_ts: u64,
+    /// The parts solved each day, by day and part
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, serde::de::IgnoredAny>>
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Leaderboard, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+}
+
+impl Member {
+    /// The name shown for the member on the site.
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// How many parts of `day` the member solved.
+    pub fn stars_of(&self, day: u8) -> usize {
+        self.completion_day_level.get(&day).map_or(0, BTreeMap::len)
+    }
+}
+
+/// Downloads the JSON of private leaderboard `id` for the event of `year`. It's only visible to
+/// its members, and should be fetched at most every `LEADERBOARD_REFRESH`.
+pub fn leaderboard(year: u16, id: u64, session: &str) -> Result<String, Error> {
+    let json = get(&format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json"), session)?;
+    // Leaderboards that can't be seen redirect to the page of the private leaderboards
+    match json.trim_start().starts_with('<') {
+        true => Err(Error::UnknownLeaderboard(id)),
+        false => Ok(json)
+    }
+}
+
+fn get(url: &str, session: &str) -> Result<String, Error> {
+    let mut request = ureq::get(url).set("User-Agent", USER_AGENT);
+    if !session.trim().is_empty() {
+        request = request.set("Cookie", &format!("session={}", session.trim()));
+    }
+
+    let response = request.call().map_err(|err| Error::HttpError(Box::new(err)))?;
+
+    Ok(response.into_string()?)
+}
+
+/// Days of the calendar are links classed `calendar-complete` with one star, and
+/// `calendar-verycomplete` with both.
+fn count_stars(calendar: &str) -> u8 {
+    (calendar.matches("calendar-complete").count() + 2 * calendar.matches("calendar-verycomplete").count()) as u8
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_count_stars() {
+        let calendar = r#"
+<a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">
+<a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">
+<a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">
+"#;
+        assert_eq!(count_stars(calendar), 3);
+    }
+
+    #[test]
+    fn test_parse_leaderboard() {
+        let json = r#"{"event":"2023","owner_id":1,"members":{
+            "1":{"id":1,"name":"Alice","stars":3,"local_score":10,"global_score":0,"last_star_ts":1701500000,
+                 "completion_day_level":{"1":{"1":{"get_star_ts":1701400000,"star_index":1},"2":{"get_star_ts":1701400100,"star_index":2}},"2":{"1":{"get_star_ts":1701500000,"star_index":3}}}},
+            "2":{"id":2,"name":null,"stars":0,"local_score":0,"global_score":0,"last_star_ts":0,"completion_day_level":{}}}}"#;
+        let leaderboard = Leaderboard::parse(json).unwrap();
+        assert_eq!(leaderboard.event, "2023");
+
+        let alice = &leaderboard.members["1"];
+        assert_eq!((alice.display_name().as_str(), alice.stars, alice.local_score), ("Alice", 3, 10));
+        assert_eq!([alice.stars_of(1), alice.stars_of(2), alice.stars_of(3)], [2, 1, 0]);
+        assert_eq!(leaderboard.members["2"].display_name(), "(anonymous user #2)");
+
+        assert!(matches!(Leaderboard::parse("{}"), Err(Error::ParsingError(_))));
+    }
+
+    #[test]
+    fn test_check_input() {
+        let date = PuzzleDate::new(2023, 6).unwrap();
+        assert!(check_input(date, "Time:      7  15   30\nDistance:  9  40  200\n").is_ok());
+        assert!(matches!(check_input(date, "<!DOCTYPE html>\n<html lang=\"en-us\">"), Err(Error::NotAnInput)));
+        assert!(matches!(check_input(date, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"), Err(Error::ExpiredSession)));
+        assert!(matches!(check_input(date, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n"), Err(Error::Locked(_))));
+    }
+}
@@ -0,0 +1,781 @@
+use crate::intervals::{ Interval, IntervalSet };
+use crate::math::gcd;
+use alloc::string::{ String, ToString };
+use alloc::{ vec, vec::Vec };
+use core::ops::{ Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign };
+use core::str::FromStr;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("Invalid direction '{0}'")]
+    InvalidDirection(String)
+}
+
+/// A point or a vector on the plane.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan_distance(&self, other: Vec2) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of king moves between the points, diagonals included.
+    pub fn chebyshev_distance(&self, other: Vec2) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+/// A point or a vector in space.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64
+}
+
+impl Vec3 {
+    pub const ZERO: Vec3 = Vec3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Vec3 {
+        Vec3 { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, other: Vec3) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev_distance(&self, other: Vec3) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y)).max(self.z.abs_diff(other.z))
+    }
+
+    /// The six positions sharing a face with this one.
+    pub fn neighbors(&self) -> [Vec3; 6] {
+        [(1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)].map(|(x, y, z)| *self + Vec3::new(x, y, z))
+    }
+
+    /// The 26 positions sharing a face, an edge or a corner with this one.
+    pub fn neighbors26(&self) -> impl Iterator<Item = Vec3> + '_ {
+        let offsets = (-1..=1).flat_map(|x| (-1..=1).flat_map(move |y| (-1..=1).map(move |z| Vec3::new(x, y, z))));
+        offsets.filter(|offset| *offset != Vec3::ZERO).map(|offset| *self + offset)
+    }
+}
+
+/// The four orthogonal directions, in clockwise order. `Up` is towards negative `y`,
+/// matching the grid convention where rows grow downwards.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub const fn offset(&self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(0, -1),
+            Direction::Right => Vec2::new(1, 0),
+            Direction::Down => Vec2::new(0, 1),
+            Direction::Left => Vec2::new(-1, 0)
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        Direction::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        Direction::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn opposite(&self) -> Direction {
+        Direction::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+/// Either the initial of the direction, `U`, `R`, `D` and `L`, or of the cardinal point, `N`,
+/// `E`, `S` and `W`.
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" | "N" => Ok(Direction::Up),
+            "R" | "E" => Ok(Direction::Right),
+            "D" | "S" => Ok(Direction::Down),
+            "L" | "W" => Ok(Direction::Left),
+            _ => Err(Error::InvalidDirection(s.to_string()))
+        }
+    }
+}
+
+/// Someone walking on the plane: where they are and which way they face.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Walker {
+    pub position: Vec2,
+    pub direction: Direction
+}
+
+impl Walker {
+    pub const fn new(position: Vec2, direction: Direction) -> Walker {
+        Walker { position, direction }
+    }
+
+    /// The position one step ahead.
+    pub fn ahead(&self) -> Vec2 {
+        self.position + self.direction.offset()
+    }
+
+    /// The walker `steps` steps ahead, or behind if negative, facing the same way.
+    pub fn step(&self, steps: i64) -> Walker {
+        Walker { position: self.position + self.direction.offset() * steps, ..*self }
+    }
+
+    pub fn turn_right(&self) -> Walker {
+        Walker { direction: self.direction.turn_right(), ..*self }
+    }
+
+    pub fn turn_left(&self) -> Walker {
+        Walker { direction: self.direction.turn_left(), ..*self }
+    }
+
+    pub fn turn_around(&self) -> Walker {
+        Walker { direction: self.direction.opposite(), ..*self }
+    }
+
+    /// The positions walked through taking `steps` steps ahead, not counting the current one.
+    pub fn trail(&self, steps: usize) -> impl Iterator<Item = Vec2> + '_ {
+        (1..=steps as i64).map(|steps| self.step(steps).position)
+    }
+}
+
+/// The four orthogonal directions and the four diagonals between them, in clockwise order, e.g.
+/// for the neighbours of a cell including the corners.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft
+    ];
+
+    pub const fn offset(&self) -> Vec2 {
+        match self {
+            Direction8::Up => Vec2::new(0, -1),
+            Direction8::UpRight => Vec2::new(1, -1),
+            Direction8::Right => Vec2::new(1, 0),
+            Direction8::DownRight => Vec2::new(1, 1),
+            Direction8::Down => Vec2::new(0, 1),
+            Direction8::DownLeft => Vec2::new(-1, 1),
+            Direction8::Left => Vec2::new(-1, 0),
+            Direction8::UpLeft => Vec2::new(-1, -1)
+        }
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn counterclockwise.
+    pub fn turn_left(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 7) % 8]
+    }
+
+    pub fn opposite(&self) -> Direction8 {
+        Direction8::ALL[(*self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        *self as usize % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+/// Like `Direction`, with the diagonals as pairs of initials, e.g. `UR` or `NE`.
+impl FromStr for Direction8 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "UR" | "NE" => Ok(Direction8::UpRight),
+            "DR" | "SE" => Ok(Direction8::DownRight),
+            "DL" | "SW" => Ok(Direction8::DownLeft),
+            "UL" | "NW" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction>().map(Direction8::from)
+        }
+    }
+}
+
+/// A tile of a hexagonal grid in axial coordinates, with rows of pointy-topped tiles: `q` grows
+/// to the east and `r` to the south-east. The third cube coordinate is `s`.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64
+}
+
+impl Hex {
+    pub const ZERO: Hex = Hex { q: 0, r: 0 };
+
+    pub const fn new(q: i64, r: i64) -> Hex {
+        Hex { q, r }
+    }
+
+    /// The third cube coordinate, keeping `q + r + s = 0`.
+    pub const fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    /// The number of steps between the tiles.
+    pub fn distance(&self, other: Hex) -> u64 {
+        (self.q.abs_diff(other.q) + self.r.abs_diff(other.r) + self.s().abs_diff(other.s())) / 2
+    }
+
+    /// The six adjacent tiles.
+    pub fn neighbors(&self) -> [Hex; 6] {
+        HexDirection::ALL.map(|direction| *self + direction.offset())
+    }
+}
+
+/// The six directions between adjacent tiles of a `Hex` grid, in clockwise order.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast
+}
+
+impl HexDirection {
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast
+    ];
+
+    pub const fn offset(&self) -> Hex {
+        match self {
+            HexDirection::East => Hex::new(1, 0),
+            HexDirection::SouthEast => Hex::new(0, 1),
+            HexDirection::SouthWest => Hex::new(-1, 1),
+            HexDirection::West => Hex::new(-1, 0),
+            HexDirection::NorthWest => Hex::new(0, -1),
+            HexDirection::NorthEast => Hex::new(1, -1)
+        }
+    }
+
+    pub fn turn_right(&self) -> HexDirection {
+        HexDirection::ALL[(*self as usize + 1) % 6]
+    }
+
+    pub fn turn_left(&self) -> HexDirection {
+        HexDirection::ALL[(*self as usize + 5) % 6]
+    }
+
+    pub fn opposite(&self) -> HexDirection {
+        HexDirection::ALL[(*self as usize + 3) % 6]
+    }
+
+    /// The moves of a path written without separators, e.g. `esenee`.
+    pub fn parse_path(path: &str) -> Result<Vec<HexDirection>, Error> {
+        let mut moves = vec![];
+        let mut rest = path.trim();
+        while !rest.is_empty() {
+            // Going north or south always takes a second letter
+            let length = match rest.starts_with(['n', 's']) {
+                true => 2,
+                false => 1
+            };
+            let step = rest.get(..length).ok_or_else(|| Error::InvalidDirection(rest.to_string()))?;
+            moves.push(step.parse()?);
+            rest = &rest[length..];
+        }
+
+        Ok(moves)
+    }
+}
+
+impl FromStr for HexDirection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(HexDirection::East),
+            "se" => Ok(HexDirection::SouthEast),
+            "sw" => Ok(HexDirection::SouthWest),
+            "w" => Ok(HexDirection::West),
+            "nw" => Ok(HexDirection::NorthWest),
+            "ne" => Ok(HexDirection::NorthEast),
+            _ => Err(Error::InvalidDirection(s.to_string()))
+        }
+    }
+}
+
+/// Which way a path turns at a point, as seen with `y` growing downwards like on a `Grid`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear
+}
+
+/// Which way the path from `a` through `b` to `c` turns at `b`.
+pub fn orientation(a: Vec2, b: Vec2, c: Vec2) -> Orientation {
+    let (first, second) = (b - a, c - a);
+    match (first.x * second.y - first.y * second.x).signum() {
+        1 => Orientation::Clockwise,
+        -1 => Orientation::CounterClockwise,
+        _ => Orientation::Collinear
+    }
+}
+
+/// The line segment between two points, both included.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment {
+    pub start: Vec2,
+    pub end: Vec2
+}
+
+impl Segment {
+    pub const fn new(start: Vec2, end: Vec2) -> Segment {
+        Segment { start, end }
+    }
+
+    /// Whether the segment is horizontal or vertical.
+    pub fn is_axis_aligned(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        orientation(self.start, self.end, point) == Orientation::Collinear
+            && (self.start.x.min(self.end.x)..=self.start.x.max(self.end.x)).contains(&point.x)
+            && (self.start.y.min(self.end.y)..=self.start.y.max(self.end.y)).contains(&point.y)
+    }
+
+    /// Whether the segments share any point, touching or overlapping included.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let (a, b) = (orientation(self.start, self.end, other.start), orientation(self.start, self.end, other.end));
+        let (c, d) = (orientation(other.start, other.end, self.start), orientation(other.start, other.end, self.end));
+        (a != b && c != d)
+            || self.contains(other.start)
+            || self.contains(other.end)
+            || other.contains(self.start)
+            || other.contains(self.end)
+    }
+
+    /// The points of whole coordinates on the segment, from `start` to `end`, e.g. the cells a
+    /// horizontal, vertical or diagonal line covers.
+    pub fn points(&self) -> impl Iterator<Item = Vec2> + '_ {
+        let delta = self.end - self.start;
+        let steps = gcd(delta.x.unsigned_abs(), delta.y.unsigned_abs()) as i64;
+        let step = match steps {
+            0 => Vec2::ZERO,
+            _ => Vec2::new(delta.x / steps, delta.y / steps)
+        };
+
+        (0..=steps).map(move |index| self.start + step * index)
+    }
+}
+
+/// The rectangle of cells from `min` to `max`, both included, with its sides along the axes.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    pub min: Vec2,
+    pub max: Vec2
+}
+
+impl Rect {
+    /// The rectangle with opposite corners `a` and `b`, whichever they are.
+    pub fn new(a: Vec2, b: Vec2) -> Rect {
+        Rect { min: Vec2::new(a.x.min(b.x), a.y.min(b.y)), max: Vec2::new(a.x.max(b.x), a.y.max(b.y)) }
+    }
+
+    /// The rectangle of `width` by `height` cells from `corner` on, both above 0.
+    pub fn with_size(corner: Vec2, width: i64, height: i64) -> Rect {
+        Rect { min: corner, max: corner + Vec2::new(width - 1, height - 1) }
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    /// The number of cells in the rectangle.
+    pub fn area(&self) -> u64 {
+        self.width() as u64 * self.height() as u64
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The cells in both rectangles, if any.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Vec2::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Vec2::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Rect { min, max })
+    }
+
+    /// All the cells of the rectangle, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Vec2> + '_ {
+        (self.min.y..=self.max.y).flat_map(move |y| (self.min.x..=self.max.x).map(move |x| Vec2::new(x, y)))
+    }
+}
+
+/// The points within a Manhattan distance of `radius` of `center`, e.g. where a sensor sees.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diamond {
+    pub center: Vec2,
+    pub radius: u64
+}
+
+impl Diamond {
+    pub const fn new(center: Vec2, radius: u64) -> Diamond {
+        Diamond { center, radius }
+    }
+
+    /// The diamond around `center` reaching just as far as `edge`.
+    pub fn reaching(center: Vec2, edge: Vec2) -> Diamond {
+        Diamond { center, radius: center.manhattan_distance(edge) }
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.center.manhattan_distance(point) <= self.radius
+    }
+
+    /// The `x` of the points of row `y` within the diamond, if any.
+    pub fn row(&self, y: i64) -> Option<Interval> {
+        let half_width = self.radius.checked_sub(self.center.y.abs_diff(y))? as i64;
+        Interval::new(self.center.x - half_width, self.center.x + half_width)
+    }
+}
+
+/// The `x` of the points of row `y` within any of the `diamonds`.
+pub fn row_coverage<'a>(diamonds: impl IntoIterator<Item = &'a Diamond>, y: i64) -> IntervalSet {
+    diamonds.into_iter().filter_map(|diamond| diamond.row(y)).collect()
+}
+
+/// Twice the area enclosed by the polygon through `vertices` (shoelace formula). Doubling keeps
+/// the result an integer. The polygon is closed implicitly, and may be given in either winding.
+pub fn shoelace_doubled_area(vertices: &[Vec2]) -> i64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges.map(|(from, to)| from.x * to.y - to.x * from.y).sum::<i64>().abs()
+}
+
+/// Number of lattice points lying on the edges of the polygon through `vertices`.
+pub fn boundary_points(vertices: &[Vec2]) -> u64 {
+    let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+    edges.map(|(from, to)| gcd(from.x.abs_diff(to.x), from.y.abs_diff(to.y))).sum()
+}
+
+/// Number of lattice points strictly inside the polygon through `vertices`, by Pick's theorem
+/// (`area = interior + boundary / 2 - 1`).
+pub fn interior_points(vertices: &[Vec2]) -> u64 {
+    let doubled_area = shoelace_doubled_area(vertices) as u64;
+    (doubled_area + 2).saturating_sub(boundary_points(vertices)) / 2
+}
+
+/// The arithmetic of vectors: adding and subtracting them component by component, and scaling
+/// them by a number.
+macro_rules! vector_ops {
+    ($vector:ident { $($component:ident),+ }) => {
+        impl Add for $vector {
+            type Output = $vector;
+            fn add(self, rhs: Self) -> Self::Output {
+                $vector { $($component: self.$component + rhs.$component),+ }
+            }
+        }
+
+        impl Sub for $vector {
+            type Output = $vector;
+            fn sub(self, rhs: Self) -> Self::Output {
+                $vector { $($component: self.$component - rhs.$component),+ }
+            }
+        }
+
+        impl Neg for $vector {
+            type Output = $vector;
+            fn neg(self) -> Self::Output {
+                $vector { $($component: -self.$component),+ }
+            }
+        }
+
+        impl Mul<i64> for $vector {
+            type Output = $vector;
+            fn mul(self, rhs: i64) -> Self::Output {
+                $vector { $($component: self.$component * rhs),+ }
+            }
+        }
+
+        impl AddAssign for $vector {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $vector {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<i64> for $vector {
+            fn mul_assign(&mut self, rhs: i64) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+vector_ops!(Vec2 { x, y });
+vector_ops!(Vec3 { x, y, z });
+vector_ops!(Hex { q, r });
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Vec2::new(1, 2) + Vec2::new(3, -4);
+        assert_eq!(point, Vec2::new(4, -2));
+        assert_eq!(point - Vec2::new(4, -2), Vec2::ZERO);
+        assert_eq!(-point, Vec2::new(-4, 2));
+
+        point += Vec2::new(1, 1);
+        assert_eq!(point, Vec2::new(5, -1));
+        point -= Vec2::new(5, -1);
+        assert_eq!(point, Vec2::ZERO);
+
+        let mut step = Direction::Left.offset() * 3;
+        assert_eq!(step, Vec2::new(-3, 0));
+        step *= -2;
+        assert_eq!(step, Vec2::new(6, 0));
+    }
+
+    #[test]
+    fn test_arithmetic_3d() {
+        let mut point = Vec3::new(1, 2, 3) + Vec3::new(3, -4, 0) * 2;
+        assert_eq!(point, Vec3::new(7, -6, 3));
+        assert_eq!(point - Vec3::new(7, -6, 3), Vec3::ZERO);
+        assert_eq!(-point, Vec3::new(-7, 6, -3));
+
+        point -= Vec3::new(7, -6, 3);
+        point += Vec3::new(0, 0, 1);
+        point *= 5;
+        assert_eq!(point, Vec3::new(0, 0, 5));
+    }
+
+    #[test]
+    fn test_neighbors_3d() {
+        let center = Vec3::new(1, 2, 3);
+        assert!(center.neighbors().iter().all(|neighbor| neighbor.manhattan_distance(center) == 1));
+        assert_eq!(center.neighbors26().count(), 26);
+        assert!(center.neighbors26().all(|neighbor| neighbor.chebyshev_distance(center) == 1));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::ALL.iter().map(Direction::offset).fold(Vec2::ZERO, |sum, offset| sum + offset), Vec2::ZERO);
+
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("W".parse(), Ok(Direction::Left));
+        assert_eq!("X".parse::<Direction>(), Err(Error::InvalidDirection("X".to_string())));
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+        assert_eq!(Direction8::UpLeft.offset(), Direction::Up.offset() + Direction::Left.offset());
+        assert_eq!(Direction8::ALL.iter().filter(|direction| direction.is_diagonal()).count(), 4);
+        for direction in Direction::ALL {
+            assert_eq!(Direction8::from(direction).offset(), direction.offset());
+        }
+
+        assert_eq!("NE".parse(), Ok(Direction8::UpRight));
+        assert_eq!("D".parse(), Ok(Direction8::Down));
+        assert_eq!("UD".parse::<Direction8>(), Err(Error::InvalidDirection("UD".to_string())));
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(HexDirection::ALL.iter().map(HexDirection::offset).fold(Hex::ZERO, |sum, offset| sum + offset), Hex::ZERO);
+        assert!(Hex::ZERO.neighbors().iter().all(|neighbor| neighbor.distance(Hex::ZERO) == 1));
+        assert_eq!(Hex::new(3, -1).s(), -2);
+        assert_eq!(Hex::new(-2, 3).distance(Hex::new(2, -1)), 4);
+        assert_eq!((HexDirection::NorthEast.offset() * 2 + HexDirection::SouthEast.offset()).distance(Hex::ZERO), 2);
+
+        assert_eq!(HexDirection::East.turn_right(), HexDirection::SouthEast);
+        assert_eq!(HexDirection::East.turn_left(), HexDirection::NorthEast);
+        assert_eq!(HexDirection::SouthWest.opposite(), HexDirection::NorthEast);
+    }
+
+    #[test]
+    fn test_hex_path() {
+        let path = HexDirection::parse_path("esenee").unwrap();
+        assert_eq!(path, vec![HexDirection::East, HexDirection::SouthEast, HexDirection::NorthEast, HexDirection::East]);
+        assert_eq!(path.iter().fold(Hex::ZERO, |tile, step| tile + step.offset()), Hex::new(3, 0));
+
+        // Going round back to the start
+        let path = HexDirection::parse_path("nwwswee").unwrap();
+        assert_eq!(path.iter().fold(Hex::ZERO, |tile, step| tile + step.offset()), Hex::ZERO);
+
+        assert_eq!(HexDirection::parse_path("enx"), Err(Error::InvalidDirection("nx".to_string())));
+        assert_eq!(HexDirection::parse_path("es"), Err(Error::InvalidDirection("s".to_string())));
+    }
+
+    #[test]
+    fn test_polygon() {
+        let square = [Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(4, 4), Vec2::new(0, 4)];
+        assert_eq!(shoelace_doubled_area(&square), 32);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+
+        let reversed: Vec<_> = square.iter().rev().copied().collect();
+        assert_eq!(shoelace_doubled_area(&reversed), 32);
+
+        let triangle = [Vec2::new(0, 0), Vec2::new(3, 0), Vec2::new(0, 3)];
+        assert_eq!(shoelace_doubled_area(&triangle), 9);
+        assert_eq!(interior_points(&triangle), 1);
+    }
+
+    #[test]
+    fn test_orientation() {
+        let (origin, right) = (Vec2::ZERO, Vec2::new(1, 0));
+        assert_eq!(orientation(origin, right, Vec2::new(1, 1)), Orientation::Clockwise);
+        assert_eq!(orientation(origin, right, Vec2::new(1, -1)), Orientation::CounterClockwise);
+        assert_eq!(orientation(origin, right, Vec2::new(5, 0)), Orientation::Collinear);
+    }
+
+    #[test]
+    fn test_segments() {
+        let diagonal = Segment::new(Vec2::new(0, 0), Vec2::new(4, 4));
+        assert!(diagonal.contains(Vec2::new(2, 2)));
+        assert!(!diagonal.contains(Vec2::new(5, 5)));
+        assert!(!diagonal.is_axis_aligned());
+
+        assert!(diagonal.intersects(&Segment::new(Vec2::new(0, 4), Vec2::new(4, 0))));
+        assert!(diagonal.intersects(&Segment::new(Vec2::new(4, 4), Vec2::new(9, 0))));
+        assert!(diagonal.intersects(&Segment::new(Vec2::new(3, 3), Vec2::new(6, 6))));
+        assert!(!diagonal.intersects(&Segment::new(Vec2::new(5, 5), Vec2::new(6, 6))));
+        assert!(!diagonal.intersects(&Segment::new(Vec2::new(1, 0), Vec2::new(4, 3))));
+
+        let points: Vec<_> = Segment::new(Vec2::new(9, 7), Vec2::new(7, 9)).points().collect();
+        assert_eq!(points, vec![Vec2::new(9, 7), Vec2::new(8, 8), Vec2::new(7, 9)]);
+        assert_eq!(Segment::new(Vec2::new(0, 0), Vec2::new(6, 3)).points().count(), 4);
+        assert_eq!(Segment::new(Vec2::new(1, 1), Vec2::new(1, 1)).points().count(), 1);
+    }
+
+    #[test]
+    fn test_rects() {
+        // Fabric claims from 2018/03
+        let claims = [
+            Rect::with_size(Vec2::new(1, 3), 4, 4),
+            Rect::with_size(Vec2::new(3, 1), 4, 4),
+            Rect::with_size(Vec2::new(5, 5), 2, 2)
+        ];
+        assert_eq!(claims[0].intersection(&claims[1]), Some(Rect::new(Vec2::new(4, 4), Vec2::new(3, 3))));
+        assert_eq!(claims[0].intersection(&claims[1]).map(|overlap| overlap.area()), Some(4));
+        assert!(!claims[2].overlaps(&claims[0]) && !claims[2].overlaps(&claims[1]));
+
+        assert_eq!((claims[2].width(), claims[2].height(), claims[2].area()), (2, 2, 4));
+        assert!(claims[2].contains(Vec2::new(6, 6)) && !claims[2].contains(Vec2::new(7, 6)));
+        assert_eq!(claims[2].points().collect::<Vec<_>>(), vec![Vec2::new(5, 5), Vec2::new(6, 5), Vec2::new(5, 6), Vec2::new(6, 6)]);
+    }
+
+    #[test]
+    fn test_diamonds() {
+        let diamond = Diamond::reaching(Vec2::new(8, 7), Vec2::new(2, 10));
+        assert_eq!(diamond.radius, 9);
+        assert!(diamond.contains(Vec2::new(8, -2)) && !diamond.contains(Vec2::new(8, -3)));
+        assert_eq!(diamond.row(7), Interval::new(-1, 17));
+        assert_eq!(diamond.row(10), Interval::new(2, 14));
+        assert_eq!(diamond.row(16), Interval::new(8, 8));
+        assert_eq!(diamond.row(17), None);
+
+        let diamonds = [diamond, Diamond::new(Vec2::new(20, 10), 2), Diamond::new(Vec2::new(17, 10), 1)];
+        let coverage: Vec<_> = row_coverage(&diamonds, 10).iter().copied().collect();
+        assert_eq!(coverage, vec![Interval::new(2, 14).unwrap(), Interval::new(16, 22).unwrap()]);
+        assert!(row_coverage(&diamonds, 100).is_empty());
+    }
+
+    #[test]
+    fn test_walker() {
+        let walker = Walker::new(Vec2::ZERO, Direction::Up);
+        assert_eq!(walker.ahead(), Vec2::new(0, -1));
+        assert_eq!(walker.step(3).turn_right().step(2), Walker::new(Vec2::new(2, -3), Direction::Right));
+        assert_eq!(walker.turn_left().step(-1).position, Vec2::new(1, 0));
+        assert_eq!(walker.turn_around().direction, Direction::Down);
+        assert_eq!(walker.turn_right().trail(3).collect::<Vec<_>>(), vec![Vec2::new(1, 0), Vec2::new(2, 0), Vec2::new(3, 0)]);
+        assert_eq!(walker.trail(0).count(), 0);
+    }
+
+    #[test]
+    fn test_distances() {
+        assert_eq!(Vec2::new(1, -2).manhattan_distance(Vec2::new(-3, 4)), 10);
+        assert_eq!(Vec2::new(1, -2).chebyshev_distance(Vec2::new(-3, 4)), 6);
+        assert_eq!(Vec3::new(1, -2, 0).manhattan_distance(Vec3::new(-3, 4, 7)), 17);
+        assert_eq!(Vec3::new(1, -2, 0).chebyshev_distance(Vec3::new(-3, 4, 7)), 7);
+    }
+}
@@ -0,0 +1,546 @@
+use alloc::collections::{ BinaryHeap, VecDeque };
+use alloc::{ vec, vec::Vec };
+use core::cmp::Reverse;
+use core::hash::Hash;
+use crate::collections::{ hash_map::Entry, HashMap, HashSet };
+
+/// The nodes `topo_sort` couldn't order, being part of a cycle or coming after one, sorted.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("The dependencies of {} nodes form a cycle", .0.len())]
+pub struct CycleError<N>(pub Vec<N>);
+
+fn build_path<S>(parents: &HashMap<S, Option<S>>, goal: S) -> Vec<S>
+where S: Clone + Eq + Hash
+{
+    let mut path = vec![goal];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+/// Breadth-first search starting from all `starts` at once. Returns the shortest path
+/// (starting state included) to the first reached state that satisfies `is_goal`.
+pub fn bfs<S, I>(starts: impl IntoIterator<Item = S>, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Vec<S>>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = S>
+{
+    let _span = tracing::info_span!("bfs").entered();
+    let mut parents: HashMap<S, Option<S>> = HashMap::default();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert(None);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some(build_path(&parents, state));
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(state.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Number of steps from `start` to every state reachable from it.
+pub fn bfs_distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = S>
+{
+    let _span = tracing::info_span!("bfs_distances").entered();
+    let mut distances = HashMap::from_iter([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state];
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// The states reachable from `start`, itself included, in breadth-first order. Being lazy it
+/// also walks graphs too large to visit whole, e.g. with `take_while`.
+pub fn bfs_reach<S, I, F>(start: S, successors: F) -> BfsReach<S, F>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = S>
+    , F: FnMut(&S) -> I
+{
+    BfsReach { visited: HashSet::from_iter([start.clone()]), queue: VecDeque::from([start]), successors }
+}
+
+/// The iterator of `bfs_reach`.
+pub struct BfsReach<S, F> {
+    visited: HashSet<S>,
+    queue: VecDeque<S>,
+    successors: F
+}
+
+impl<S, I, F> Iterator for BfsReach<S, F>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = S>
+    , F: FnMut(&S) -> I
+{
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = self.queue.pop_front()?;
+        for next in (self.successors)(&state) {
+            if self.visited.insert(next.clone()) {
+                self.queue.push_back(next);
+            }
+        }
+
+        Some(state)
+    }
+}
+
+/// Depth-first search from `start`. Returns the path (starting state included) to the first
+/// state reached that satisfies `is_goal`, which unlike with `bfs` may not be the shortest.
+pub fn dfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Vec<S>>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = S>
+{
+    let _span = tracing::info_span!("dfs").entered();
+    let mut parents: HashMap<S, Option<S>> = HashMap::default();
+    let mut stack = vec![(start, None)];
+
+    // States are only visited once popped, so a state is reached through the deepest path first
+    while let Some((state, parent)) = stack.pop() {
+        let Entry::Vacant(entry) = parents.entry(state.clone()) else { continue };
+        entry.insert(parent);
+        if is_goal(&state) {
+            return Some(build_path(&parents, state));
+        }
+
+        for next in successors(&state) {
+            if !parents.contains_key(&next) {
+                stack.push((next, Some(state.clone())));
+            }
+        }
+    }
+
+    None
+}
+
+/// Orders `nodes` so that for each of the `edges` `(before, after)`, `before` comes first. Nodes
+/// only named by edges are ordered too. Whenever several nodes could come next the smallest
+/// does, so the order is the same however the input is listed, e.g. the alphabetical one among
+/// the orders of steps that respect their requirements.
+pub fn topo_sort<N>(nodes: impl IntoIterator<Item = N>, edges: impl IntoIterator<Item = (N, N)>) -> Result<Vec<N>, CycleError<N>>
+where N: Clone + Ord + Hash
+{
+    let mut dependencies: HashMap<N, usize> = nodes.into_iter().map(|node| (node, 0)).collect();
+    let mut dependents: HashMap<N, Vec<N>> = HashMap::default();
+    for (before, after) in edges {
+        dependencies.entry(before.clone()).or_default();
+        *dependencies.entry(after.clone()).or_default() += 1;
+        dependents.entry(before).or_default().push(after);
+    }
+
+    let mut ready: BinaryHeap<Reverse<N>> = dependencies.iter().filter(|(_, count)| **count == 0).map(|(node, _)| Reverse(node.clone())).collect();
+    let mut order = Vec::with_capacity(dependencies.len());
+    while let Some(Reverse(node)) = ready.pop() {
+        for dependent in dependents.get(&node).into_iter().flatten() {
+            let count = dependencies.get_mut(dependent).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                ready.push(Reverse(dependent.clone()));
+            }
+        }
+
+        order.push(node);
+    }
+
+    match order.len() == dependencies.len() {
+        true => Ok(order),
+        false => {
+            let mut cyclic: Vec<N> = dependencies.into_iter().filter(|(_, count)| *count > 0).map(|(node, _)| node).collect();
+            cyclic.sort();
+            Err(CycleError(cyclic))
+        }
+    }
+}
+
+/// A region found by `flood_fill`: the states filled, and those next to them that stopped it.
+#[derive(Clone, Debug, Default)]
+pub struct Region<S> {
+    pub cells: HashSet<S>,
+    pub boundary: HashSet<S>
+}
+
+/// Fills from `start` through the `neighbors` where `passable` holds, e.g. the inside of a loop
+/// on a grid or the air around a droplet in space. Unbounded spaces need `passable` to bound
+/// them. `passable` is called once per state.
+pub fn flood_fill<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I, mut passable: impl FnMut(&S) -> bool) -> Region<S>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = S>
+{
+    let _span = tracing::info_span!("flood_fill").entered();
+    let mut region = Region { cells: HashSet::default(), boundary: HashSet::default() };
+    let mut stack = vec![start];
+
+    while let Some(state) = stack.pop() {
+        if region.cells.contains(&state) || region.boundary.contains(&state) {
+            continue;
+        }
+
+        match passable(&state) {
+            true => {
+                stack.extend(neighbors(&state));
+                region.cells.insert(state);
+            },
+            false => {
+                region.boundary.insert(state);
+            }
+        }
+    }
+
+    region
+}
+
+/// The shortest route through every node, given the distance from each node to each other one,
+/// with its length and its nodes in order. With `return_to_start` it ends back at the first
+/// node, 0. Takes about `2^n * n^2` steps, so up to 20 nodes or so.
+pub fn shortest_route<D: AsRef<[u64]>>(distances: &[D], return_to_start: bool) -> Option<(u64, Vec<usize>)> {
+    held_karp(distances, return_to_start, |cost, other| cost < other)
+}
+
+/// Like `shortest_route`, for the longest one.
+pub fn longest_route<D: AsRef<[u64]>>(distances: &[D], return_to_start: bool) -> Option<(u64, Vec<usize>)> {
+    held_karp(distances, return_to_start, |cost, other| cost > other)
+}
+
+/// Dynamic programming over the sets of nodes visited so far, keeping the best route through
+/// each set for every node it could end at.
+fn held_karp<D: AsRef<[u64]>>(distances: &[D], return_to_start: bool, better: impl Fn(u64, u64) -> bool) -> Option<(u64, Vec<usize>)> {
+    let _span = tracing::info_span!("held_karp").entered();
+    let nodes = distances.len();
+    if nodes == 0 {
+        return Some((0, vec![]));
+    }
+
+    let distance = |from: usize, to: usize| distances[from].as_ref()[to];
+    let all = (1usize << nodes) - 1;
+    // The best length through the set `mask` ending at `last`, at `mask * nodes + last`, with
+    // the node before `last`. Routes start from themselves.
+    let mut best: Vec<Option<(u64, usize)>> = vec![None; (all + 1) * nodes];
+    for start in 0..match return_to_start { true => 1, false => nodes } {
+        best[(1 << start) * nodes + start] = Some((0, start));
+    }
+
+    // Adding a node only ever grows the set, so every set is done before those it leads to
+    for mask in 1..all {
+        for last in 0..nodes {
+            let Some((length, _)) = best[mask * nodes + last] else { continue };
+            for next in (0..nodes).filter(|next| mask & (1 << next) == 0) {
+                let length = length + distance(last, next);
+                let entry = &mut best[(mask | 1 << next) * nodes + next];
+                if entry.is_none_or(|(other, _)| better(length, other)) {
+                    *entry = Some((length, last));
+                }
+            }
+        }
+    }
+
+    let (length, mut last) = (0..nodes)
+        .filter_map(|last| {
+            let (length, _) = best[all * nodes + last]?;
+            Some((length + if return_to_start { distance(last, 0) } else { 0 }, last))
+        })
+        .reduce(|current, other| if better(other.0, current.0) { other } else { current })?;
+
+    let (mut mask, mut route) = (all, vec![last]);
+    while mask != 1 << last {
+        let (_, previous) = best[mask * nodes + last]?;
+        mask ^= 1 << last;
+        last = previous;
+        route.push(last);
+    }
+
+    route.reverse();
+    Some((length, route))
+}
+
+/// Dijkstra's algorithm starting from all `starts` at once. `successors` yields the next states
+/// with the cost of moving there. Returns the cost and the path to the cheapest goal reached.
+pub fn dijkstra<S, I>(starts: impl IntoIterator<Item = S>, successors: impl FnMut(&S) -> I, is_goal: impl FnMut(&S) -> bool) -> Option<(u64, Vec<S>)>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = (S, u64)>
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost to a goal, and must be
+/// consistent, or the returned path may not be the cheapest one.
+///
+/// States are interned into indices as they are discovered, so the priority queue only holds
+/// `(priority, cost, index)` triples and `S` doesn't need to be `Ord`. Queue entries that were
+/// superseded by a cheaper path are skipped when popped.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool
+) -> Option<(u64, Vec<S>)>
+where S: Clone + Eq + Hash
+    , I: IntoIterator<Item = (S, u64)>
+{
+    let _span = tracing::info_span!("astar").entered();
+    let mut states: Vec<S> = vec![];
+    let mut indices: HashMap<S, usize> = HashMap::default();
+    let mut costs: Vec<u64> = vec![];
+    let mut parents: Vec<Option<usize>> = vec![];
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start.clone()) {
+            entry.insert(states.len());
+            queue.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+            costs.push(0);
+            parents.push(None);
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+
+        if is_goal(&states[index]) {
+            let mut path = vec![index];
+            while let Some(parent) = parents[*path.last().unwrap()] {
+                path.push(parent);
+            }
+
+            return Some((cost, path.into_iter().rev().map(|index| states[index].clone()).collect()));
+        }
+
+        for (next, step_cost) in successors(&states[index]) {
+            let next_cost = cost + step_cost;
+            let next_index = match indices.entry(next) {
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= costs[next_index] {
+                        continue;
+                    }
+
+                    costs[next_index] = next_cost;
+                    parents[next_index] = Some(index);
+                    next_index
+                },
+                Entry::Vacant(entry) => {
+                    states.push(entry.key().clone());
+                    entry.insert(states.len() - 1);
+                    costs.push(next_cost);
+                    parents.push(Some(index));
+                    states.len() - 1
+                }
+            };
+
+            queue.push(Reverse((next_cost + heuristic(&states[next_index]), next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn line_successors(state: &i32) -> Vec<i32> {
+        vec![state - 1, state + 1]
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let path = bfs([0], line_successors, |state| *state == 3).unwrap();
+        assert_eq!(path, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_start_is_goal() {
+        assert_eq!(bfs([5], line_successors, |state| *state == 5), Some(vec![5]));
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let path = bfs([-10, 10, 4], line_successors, |state| *state == 6).unwrap();
+        assert_eq!(path, vec![4, 5, 6]);
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let successors = |state: &i32| if *state < 5 { vec![state + 1] } else { vec![] };
+        assert_eq!(bfs([0], successors, |state| *state == 6), None);
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let successors = |state: &i32| if *state < 3 { vec![state + 1, state + 2] } else { vec![] };
+        let distances = bfs_distances(0, successors);
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&3], 2);
+        assert_eq!(distances[&4], 2);
+    }
+
+    #[test]
+    fn test_bfs_reach() {
+        let successors = |state: &i32| if *state < 3 { vec![state + 1, state + 2] } else { vec![] };
+        assert_eq!(bfs_reach(0, successors).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+
+        // Lazily, over a graph without end
+        let reached: Vec<i32> = bfs_reach(0, line_successors).take_while(|state| state.abs() <= 2).collect();
+        assert_eq!(reached, vec![0, -1, 1, -2, 2]);
+    }
+
+    #[test]
+    fn test_dfs() {
+        // Deep first: 0 -> 1 -> 2 -> 3 is taken over the shorter 0 -> 3
+        let successors = |state: &i32| match state {
+            0 => vec![3, 1],
+            3 => vec![],
+            _ => vec![state + 1]
+        };
+        assert_eq!(dfs(0, successors, |state| *state == 3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(dfs(0, successors, |state| *state == 0), Some(vec![0]));
+        assert_eq!(dfs(3, successors, |state| *state == 0), None);
+
+        let path = dfs(0, line_successors, |state| *state == 5).unwrap();
+        assert!(path.windows(2).all(|step| step[0].abs_diff(step[1]) == 1));
+        assert_eq!((path[0], path.last()), (0, Some(&5)));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        use crate::geometry::{ Direction, Vec2 };
+
+        // A closed box of walls, flooded from inside and from outside within bounds
+        let walls: HashSet<Vec2> = (0..4).flat_map(|i| [Vec2::new(i, 0), Vec2::new(i, 3), Vec2::new(0, i), Vec2::new(3, i)]).collect();
+        let neighbors = |position: &Vec2| Direction::ALL.map(|direction| *position + direction.offset());
+        let inside = flood_fill(Vec2::new(1, 1), neighbors, |position| !walls.contains(position));
+        assert_eq!(inside.cells.len(), 4);
+        assert_eq!(inside.boundary.len(), 8);
+
+        let in_bounds = |position: &Vec2| (-1..=4).contains(&position.x) && (-1..=4).contains(&position.y);
+        let outside = flood_fill(Vec2::new(-1, -1), neighbors, |position| in_bounds(position) && !walls.contains(position));
+        assert_eq!(outside.cells.len(), 36 - 16);
+        assert!(outside.cells.is_disjoint(&inside.cells));
+        assert!(outside.boundary.iter().filter(|position| in_bounds(position)).all(|position| walls.contains(position)));
+
+        let blocked = flood_fill(Vec2::ZERO, neighbors, |position| !walls.contains(position));
+        assert!(blocked.cells.is_empty());
+        assert_eq!(blocked.boundary, HashSet::from_iter([Vec2::ZERO]));
+    }
+
+    #[test]
+    fn test_topo_sort() {
+        // The steps and requirements of 2018/07, whose order is alphabetical among the valid ones
+        let edges = [('C', 'A'), ('C', 'F'), ('A', 'B'), ('A', 'D'), ('B', 'E'), ('D', 'E'), ('F', 'E')];
+        assert_eq!(topo_sort([], edges), Ok(vec!['C', 'A', 'B', 'D', 'F', 'E']));
+        assert_eq!(topo_sort(['G', 'A'], edges.iter().rev().copied()), Ok(vec!['C', 'A', 'B', 'D', 'F', 'E', 'G']));
+        assert_eq!(topo_sort([3, 1, 2], []), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_topo_sort_cycle() {
+        let error = topo_sort([0], [(1, 2), (2, 3), (3, 1), (3, 4)]).unwrap_err();
+        assert_eq!(error, CycleError(vec![1, 2, 3, 4]));
+        assert_eq!(error.to_string(), "The dependencies of 4 nodes form a cycle");
+    }
+
+    #[test]
+    fn test_routes() {
+        let distances = [[0, 7, 2, 9], [7, 0, 3, 4], [2, 3, 0, 8], [9, 4, 8, 0]];
+        let length = |route: &[usize], back: bool| {
+            let legs = route.windows(2).map(|leg| distances[leg[0]][leg[1]]).sum::<u64>();
+            legs + if back { distances[*route.last().unwrap()][route[0]] } else { 0 }
+        };
+
+        for (back, shortest, longest) in [(false, 9, 24), (true, 18, 27)] {
+            let (cost, route) = shortest_route(&distances, back).unwrap();
+            assert_eq!((cost, length(&route, back), route.len()), (shortest, shortest, 4));
+            let (cost, route) = longest_route(&distances, back).unwrap();
+            assert_eq!((cost, length(&route, back), route.len()), (longest, longest, 4));
+            assert!(!back || route[0] == 0);
+        }
+
+        // London, Dublin and Belfast, from 2015/09
+        let cities = vec![vec![0, 464, 518], vec![464, 0, 141], vec![518, 141, 0]];
+        let (shortest, route) = shortest_route(&cities, false).unwrap();
+        assert_eq!(shortest, 605);
+        assert!(route == [0, 1, 2] || route == [2, 1, 0]);
+        assert_eq!(longest_route(&cities, false).map(|(cost, _)| cost), Some(982));
+        assert_eq!(shortest_route(&[[0]], true), Some((0, vec![0])));
+        assert_eq!(shortest_route::<[u64; 0]>(&[], false), Some((0, vec![])));
+    }
+
+    fn weighted_successors(state: &char) -> Vec<(char, u64)> {
+        match state {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 1), ('d', 10)],
+            'c' => vec![('d', 2)],
+            _ => vec![]
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(dijkstra(['a'], weighted_successors, |state| *state == 'd'), Some((4, vec!['a', 'b', 'c', 'd'])));
+        assert_eq!(dijkstra(['a'], weighted_successors, |state| *state == 'a'), Some((0, vec!['a'])));
+        assert_eq!(dijkstra(['d'], weighted_successors, |state| *state == 'a'), None);
+    }
+
+    #[test]
+    fn test_dijkstra_multiple_starts() {
+        assert_eq!(dijkstra(['a', 'c'], weighted_successors, |state| *state == 'd'), Some((2, vec!['c', 'd'])));
+    }
+
+    #[test]
+    fn test_dijkstra_cycles() {
+        // Going round the cycle is free, but only the detour through 3 reaches 4 cheaply
+        let successors = |state: &u8| match state {
+            0 => vec![(1, 0), (4, 10)],
+            1 => vec![(2, 0), (3, 1)],
+            2 => vec![(0, 0)],
+            3 => vec![(4, 1)],
+            _ => vec![]
+        };
+        assert_eq!(dijkstra([0], successors, |state| *state == 4), Some((2, vec![0, 1, 3, 4])));
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walking on a line towards 50, with the exact distance as heuristic
+        let successors = |state: &i64| [(state - 1, 1), (state + 1, 1)];
+        let mut expanded = 0;
+        let result = astar([0], successors, |state| state.abs_diff(50), |state| {
+            expanded += 1;
+            *state == 50
+        });
+
+        assert_eq!(result.map(|(cost, path)| (cost, path.len())), Some((50, 51)));
+        assert_eq!(expanded, 51);
+    }
+}
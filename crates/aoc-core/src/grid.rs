@@ -0,0 +1,368 @@
+use crate::geometry::{ Direction, Vec2 };
+use crate::graph::{ bfs, bfs_distances, flood_fill, Region };
+use alloc::{ vec, vec::Vec };
+use arrayvec::ArrayVec;
+use core::ops::{ Index, IndexMut };
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("Row {0} has {1} cells, expected {2}")]
+    RaggedRow(usize, usize, usize),
+    #[error("Invalid cell '{cell}' at ({x}, {y})", cell = .1, x = .0.x, y = .0.y)]
+    InvalidCell(Vec2, char),
+    #[error("No '{0}' marker in the map")]
+    MissingMarker(char)
+}
+
+/// Positions of the marker cells found by `Grid::parse_with_markers`, in reading order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: Vec<(char, Vec2)>
+}
+
+impl Markers {
+    /// All positions of `marker`.
+    pub fn all(&self, marker: char) -> impl Iterator<Item = Vec2> + '_ {
+        self.positions.iter().filter(move |(ch, _)| *ch == marker).map(|(_, position)| *position)
+    }
+
+    /// The first position of `marker`, if any.
+    pub fn get(&self, marker: char) -> Option<Vec2> {
+        self.all(marker).next()
+    }
+
+    /// The first position of `marker`, or a `MissingMarker` error.
+    pub fn require(&self, marker: char) -> Result<Vec2, Error> {
+        self.get(marker).ok_or(Error::MissingMarker(marker))
+    }
+}
+
+/// The lines of a textual grid, without trailing whitespace or blank lines.
+fn rows(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim_end).filter(|line| !line.is_empty())
+}
+
+/// A dense, rectangular grid. Positions are `Vec2` with `x` growing to the right
+/// and `y` growing downwards, so that row `y` of the input is `y` in the grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, Error> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(Error::RaggedRow(y, row.len(), width));
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    /// Parses one row per non-empty line, converting each character with `parse_cell`.
+    pub fn parse(input: &str, mut parse_cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>, Error> {
+        let _span = tracing::info_span!("parse_grid").entered();
+        let rows: Vec<Vec<T>> = rows(input)
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, ch)| parse_cell(ch).ok_or(Error::InvalidCell(Vec2::new(x as i64, y as i64), ch)))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Grid::from_rows(rows)
+    }
+
+    /// Like `parse`, but cells holding one of the `markers` are recorded and then parsed as if
+    /// they held the paired replacement, e.g. `('S', '.')` for a start standing on open floor.
+    pub fn parse_with_markers(input: &str, markers: &[(char, char)], mut parse_cell: impl FnMut(char) -> Option<T>) -> Result<(Grid<T>, Markers), Error> {
+        let replacement_of = |ch: char| markers.iter().find(|(marker, _)| *marker == ch).map(|(_, replacement)| *replacement);
+        let grid = Grid::parse(input, |ch| parse_cell(replacement_of(ch).unwrap_or(ch)))?;
+
+        let positions = rows(input)
+            .enumerate()
+            .flat_map(|(y, line)| line.chars().enumerate().map(move |(x, ch)| (ch, Vec2::new(x as i64, y as i64))))
+            .filter(|(ch, _)| replacement_of(*ch).is_some())
+            .collect();
+
+        Ok((grid, Markers { positions }))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Vec2) -> bool {
+        self.index_of(position).is_some()
+    }
+
+    fn index_of(&self, position: Vec2) -> Option<usize> {
+        let x = usize::try_from(position.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(position.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    fn position_of(&self, index: usize) -> Vec2 {
+        Vec2::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn get(&self, position: Vec2) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Vec2) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2> + '_ {
+        (0..self.cells.len()).map(|index| self.position_of(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        self.cells.iter().enumerate().map(|(index, cell)| (self.position_of(index), cell))
+    }
+
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Vec2> {
+        self.cells.iter().position(predicate).map(|index| self.position_of(index))
+    }
+
+    /// The up to four orthogonally adjacent positions that lie within the grid.
+    pub fn neighbors(&self, position: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        Direction::ALL
+            .iter()
+            .map(move |direction| position + direction.offset())
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// The steps between orthogonal neighbours that `passable` allows, given the cells moved
+    /// from and to. There are at most four, so they're kept off the heap.
+    fn steps<'a>(&'a self, mut passable: impl FnMut(&T, &T) -> bool + 'a) -> impl FnMut(&Vec2) -> ArrayVec<Vec2, 4> + 'a {
+        move |position: &Vec2| {
+            let from = &self[*position];
+            self.neighbors(*position).filter(|next| passable(from, &self[*next])).collect()
+        }
+    }
+
+    /// The shortest path from `start` to `goal`, both included, moving orthogonally between
+    /// cells where `passable(from, to)` holds. Searches over other states than a position can
+    /// still use `graph` directly.
+    pub fn shortest_path(&self, start: Vec2, goal: Vec2, passable: impl FnMut(&T, &T) -> bool) -> Option<Vec<Vec2>> {
+        self.shortest_path_from_any([start], goal, passable)
+    }
+
+    /// Like `shortest_path`, from whichever of the `starts` is closest to `goal`, e.g. any cell
+    /// of some kind.
+    pub fn shortest_path_from_any(&self, starts: impl IntoIterator<Item = Vec2>, goal: Vec2, passable: impl FnMut(&T, &T) -> bool) -> Option<Vec<Vec2>> {
+        bfs(starts, self.steps(passable), |position| *position == goal)
+    }
+
+    /// The number of steps from `start` to every cell, moving like `shortest_path` does, or
+    /// `None` for the cells that can't be reached.
+    pub fn distances_from(&self, start: Vec2, passable: impl FnMut(&T, &T) -> bool) -> Grid<Option<usize>> {
+        let mut distances = Grid { width: self.width, height: self.height, cells: vec![None; self.cells.len()] };
+        for (position, distance) in bfs_distances(start, self.steps(passable)) {
+            distances[position] = Some(distance);
+        }
+
+        distances
+    }
+
+    /// The cells reachable from `start` through those where `passable` holds, and the cells
+    /// next to them where it doesn't. See `graph::flood_fill`.
+    pub fn flood_fill(&self, start: Vec2, mut passable: impl FnMut(&T) -> bool) -> Region<Vec2> {
+        flood_fill(start, |position| self.neighbors(*position), |position| self.get(*position).is_some_and(&mut passable))
+    }
+}
+
+impl Grid<u8> {
+    /// A grid of single digits, such as a height map.
+    pub fn parse_digits(input: &str) -> Result<Grid<u8>, Error> {
+        Grid::parse(input, |ch| ch.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+}
+
+/// The sums of the cells of a grid over any rectangle, each in constant time, e.g. to find the
+/// square of most power.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrefixSum2D {
+    width: usize,
+    height: usize,
+    // The sum of the cells above and left of each corner, with a row and column of 0 before
+    sums: Vec<i64>
+}
+
+impl PrefixSum2D {
+    pub fn new(grid: &Grid<i64>) -> PrefixSum2D {
+        let (width, height) = (grid.width, grid.height);
+        let at = |x: usize, y: usize| y * (width + 1) + x;
+        let mut sums = vec![0; (width + 1) * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                sums[at(x + 1, y + 1)] = grid.cells[y * width + x] + sums[at(x, y + 1)] + sums[at(x + 1, y)] - sums[at(x, y)];
+            }
+        }
+
+        PrefixSum2D { width, height, sums }
+    }
+
+    /// The sum of the cells from `min` to `max`, both included. Only the part of the rectangle
+    /// within the grid counts, so it's 0 for one outside or empty.
+    pub fn sum(&self, min: Vec2, max: Vec2) -> i64 {
+        let clamp = |value: i64, size: usize| value.clamp(0, size as i64) as usize;
+        let (left, right) = (clamp(min.x, self.width), clamp(max.x + 1, self.width));
+        let (top, bottom) = (clamp(min.y, self.height), clamp(max.y + 1, self.height));
+        if left >= right || top >= bottom {
+            return 0;
+        }
+
+        let corner = |x: usize, y: usize| self.sums[y * (self.width + 1) + x];
+        corner(right, bottom) - corner(left, bottom) - corner(right, top) + corner(left, top)
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Vec2) -> &Self::Output {
+        self.get(position).expect("Position out of grid bounds")
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, position: Vec2) -> &mut Self::Output {
+        self.get_mut(position).expect("Position out of grid bounds")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |ch| ch.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Vec2::new(0, 0)], 1);
+        assert_eq!(grid[Vec2::new(2, 1)], 6);
+        assert_eq!(grid.get(Vec2::new(3, 0)), None);
+        assert_eq!(grid.get(Vec2::new(0, -1)), None);
+        assert_eq!(Grid::parse_digits("123\n456\n"), Ok(grid.map(|digit| *digit as u8)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Grid::parse("12\n3", |ch| ch.to_digit(10)), Err(Error::RaggedRow(1, 1, 2)));
+        assert_eq!(Grid::parse("12\n3x", |ch| ch.to_digit(10)), Err(Error::InvalidCell(Vec2::new(1, 1), 'x')));
+    }
+
+    #[test]
+    fn test_parse_with_markers() {
+        let (grid, markers) = Grid::parse_with_markers("S.#\n.#E\nS..", &[('S', '.'), ('E', '.')], |ch| Some(ch == '#')).unwrap();
+        assert_eq!(grid, Grid::from_rows(vec![vec![false, false, true], vec![false, true, false], vec![false, false, false]]).unwrap());
+        assert_eq!(markers.get('S'), Some(Vec2::new(0, 0)));
+        assert_eq!(markers.all('S').collect::<Vec<_>>(), vec![Vec2::new(0, 0), Vec2::new(0, 2)]);
+        assert_eq!(markers.require('E'), Ok(Vec2::new(2, 1)));
+        assert_eq!(markers.require('^'), Err(Error::MissingMarker('^')));
+    }
+
+    #[test]
+    fn test_positions() {
+        let grid = digits("12\n34");
+        let positions: Vec<_> = grid.positions().collect();
+        assert_eq!(positions, vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(0, 1), Vec2::new(1, 1)]);
+        assert_eq!(grid.find(|cell| *cell == 3), Some(Vec2::new(0, 1)));
+        assert_eq!(grid.iter().map(|(_, cell)| cell).sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(grid.neighbors(Vec2::new(1, 1)).count(), 4);
+        let corner: Vec<_> = grid.neighbors(Vec2::new(0, 0)).collect();
+        assert_eq!(corner, vec![Vec2::new(1, 0), Vec2::new(0, 1)]);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let maze = Grid::parse("..#\n#..\n#.#", |ch| Some(ch == '#')).unwrap();
+        let open = |_: &bool, to: &bool| !*to;
+        let path = maze.shortest_path(Vec2::new(0, 0), Vec2::new(1, 2), open).unwrap();
+        assert_eq!(path, vec![Vec2::new(0, 0), Vec2::new(1, 0), Vec2::new(1, 1), Vec2::new(1, 2)]);
+        assert_eq!(maze.shortest_path(Vec2::new(0, 0), Vec2::new(2, 0), open), None);
+
+        let path = maze.shortest_path_from_any([Vec2::new(0, 0), Vec2::new(2, 1)], Vec2::new(1, 2), open).unwrap();
+        assert_eq!(path, vec![Vec2::new(2, 1), Vec2::new(1, 1), Vec2::new(1, 2)]);
+
+        let distances = maze.distances_from(Vec2::new(0, 0), open);
+        assert_eq!(distances[Vec2::new(2, 1)], Some(3));
+        assert_eq!(distances[Vec2::new(0, 1)], None);
+
+        let climb = digits("121\n343");
+        let distances = climb.distances_from(Vec2::new(0, 0), |from, to| *to <= from + 1);
+        assert_eq!(distances.iter().filter(|(_, distance)| distance.is_some()).count(), 3);
+        assert_eq!(distances[Vec2::new(1, 1)], None);
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let maze = Grid::parse("..#\n#..\n#.#", |ch| Some(ch == '#')).unwrap();
+        let region = maze.flood_fill(Vec2::new(0, 0), |wall| !wall);
+        assert_eq!(region.cells.len(), 5);
+        assert_eq!(region.boundary, maze.positions().filter(|position| maze[*position]).collect());
+    }
+
+    #[test]
+    fn test_prefix_sum() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, -5, 6], vec![7, 8, 9]]).unwrap();
+        let sums = PrefixSum2D::new(&grid);
+        assert_eq!(sums.sum(Vec2::new(0, 0), Vec2::new(2, 2)), 35);
+        assert_eq!(sums.sum(Vec2::new(1, 1), Vec2::new(2, 2)), 18);
+        assert_eq!(sums.sum(Vec2::new(1, 0), Vec2::new(1, 1)), -3);
+        assert_eq!(sums.sum(Vec2::new(2, 2), Vec2::new(2, 2)), 9);
+
+        // Clipped to the grid
+        assert_eq!(sums.sum(Vec2::new(-5, 2), Vec2::new(0, 10)), 7);
+        assert_eq!(sums.sum(Vec2::new(3, 0), Vec2::new(5, 5)), 0);
+        assert_eq!(sums.sum(Vec2::new(2, 2), Vec2::new(1, 1)), 0);
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut grid = Grid::filled(2, 2, 0);
+        grid[Vec2::new(1, 0)] = 5;
+        *grid.get_mut(Vec2::new(0, 1)).unwrap() += 2;
+        assert_eq!(grid.map(|cell| cell * 2), Grid::from_rows(vec![vec![0, 10], vec![4, 0]]).unwrap());
+    }
+}
@@ -0,0 +1,68 @@
+//! MD5, and mining for the numbers that make the hash of a salt followed by them start a given
+//! way, as the puzzles of 2015 and 2016 keep asking. Build with the `hash` feature.
+
+use rayon::prelude::*;
+
+/// How many numbers are hashed in parallel before the matches among them are handed out.
+const BATCH: u64 = 10_000;
+
+pub fn md5(text: &str) -> [u8; 16] {
+    md5::compute(text).0
+}
+
+/// The hash as the 32 hexadecimal digits it is usually written as.
+pub fn md5_hex(text: &str) -> String {
+    format!("{:x}", md5::compute(text))
+}
+
+/// The number of zeros the hash starts with, written in hexadecimal.
+pub fn leading_hex_zeros(digest: &[u8; 16]) -> u32 {
+    u128::from_be_bytes(*digest).leading_zeros() / 4
+}
+
+/// The numbers `n` from 0 up for which the hash of `{salt}{n}` satisfies `predicate`, in order,
+/// with their hash. The search never ends by itself, so take what's needed from it.
+pub fn mine<'a>(salt: &'a str, predicate: impl Fn(&[u8; 16]) -> bool + Sync + 'a) -> impl Iterator<Item = (u64, [u8; 16])> + 'a {
+    (0..).step_by(BATCH as usize).flat_map(move |start| {
+        (start..start + BATCH)
+            .into_par_iter()
+            .filter_map(|n| {
+                let digest = md5(&format!("{salt}{n}"));
+                predicate(&digest).then_some((n, digest))
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_md5() {
+        assert_eq!(md5_hex("abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(md5_hex(""), "d41d8cd98f00b204e9800998ecf8427e");
+    }
+
+    #[test]
+    fn test_leading_hex_zeros() {
+        let digest = |hex: &str| core::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap());
+        assert_eq!(leading_hex_zeros(&digest("000001dbbfa3a5c83a2d506429c7b00e")), 5);
+        assert_eq!(leading_hex_zeros(&digest("00000fdbbfa3a5c83a2d506429c7b00e")), 5);
+        assert_eq!(leading_hex_zeros(&digest("0000f1dbbfa3a5c83a2d506429c7b00e")), 4);
+        assert_eq!(leading_hex_zeros(&digest("900150983cd24fb0d6963f7d28e17f72")), 0);
+        assert_eq!(leading_hex_zeros(&[0; 16]), 32);
+    }
+
+    #[test]
+    fn test_mine() {
+        let (n, digest) = mine("abcdef", |digest| leading_hex_zeros(digest) >= 5).next().unwrap();
+        assert_eq!(n, 609043);
+        assert!(md5_hex(&format!("abcdef{n}")).starts_with("000001dbbfa"));
+        assert_eq!(digest, md5("abcdef609043"));
+
+        let first: Vec<u64> = mine("abc", |digest| digest[0] == 0).take(3).map(|(n, _)| n).collect();
+        assert!(first.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(first.iter().all(|n| md5_hex(&format!("abc{n}")).starts_with("00")));
+    }
+}
@@ -0,0 +1,219 @@
+//! Where the puzzle inputs live on disk, relative to the repository root. With the `download`
+//! feature, inputs that aren't there yet are downloaded on first use.
+
+#[cfg(feature = "download")]
+use {
+    crate::{ cache::{ self, Downloads }, download },
+    std::time::SystemTime
+};
+use crate::{ date::PuzzleDate, solution::{ Error, Metadata } };
+use std::fs::File;
+use std::io::{ self, BufRead, BufReader };
+use std::path::{ Path, PathBuf };
+use std::sync::mpsc::{ self, Receiver };
+use std::thread::{ self, JoinHandle };
+
+/// Lines are handed over in batches, so the channel isn't the bottleneck.
+const LINES_PER_BATCH: usize = 4096;
+/// How far the reader can get ahead of the consumer, bounding the memory in flight.
+const BATCHES_IN_FLIGHT: usize = 16;
+
+/// 2022 inputs are named after the puzzle, e.g. `inputs/2022/12/HillClimbingAlgorithm.txt`.
+/// Later years just use `inputs/<year>/<day>/input.txt`.
+pub fn path(metadata: &Metadata) -> PathBuf {
+    path_for(metadata.date(), metadata.title)
+}
+
+/// Where the input of the puzzle titled `title` goes, for puzzles without a solution yet.
+pub fn path_for(date: PuzzleDate, title: &str) -> PathBuf {
+    let file_name = match date.year() {
+        2022 => format!("{}.txt", pascal_case(title)),
+        _ => "input.txt".to_string()
+    };
+
+    PathBuf::from(format!("inputs/{}/{:02}", date.year(), date.day())).join(file_name)
+}
+
+pub fn read(metadata: &Metadata) -> io::Result<String> {
+    let _span = tracing::info_span!("read").entered();
+    std::fs::read_to_string(path(metadata))
+}
+
+/// Reads the input of the puzzle. With the `download` feature an input that isn't on disk yet is
+/// downloaded with the `AOC_SESSION` cookie and saved, so it's only fetched once.
+pub fn load(metadata: &Metadata) -> Result<String, Error> {
+    match read(metadata) {
+        #[cfg(feature = "download")]
+        Err(err) if err.kind() == io::ErrorKind::NotFound => fetch(metadata),
+        input => input.map_err(|err| format!("Failed to read input: {err}").into())
+    }
+}
+
+/// Writes a freshly obtained input where `read` will find it.
+pub fn write(metadata: &Metadata, input: &str) -> io::Result<()> {
+    write_to(&path(metadata), input)
+}
+
+fn write_to(path: &Path, input: &str) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+
+    std::fs::write(path, input)
+}
+
+/// Downloads the input of the puzzle and saves it where `read` will find it.
+#[cfg(feature = "download")]
+pub fn fetch(metadata: &Metadata) -> Result<String, Error> {
+    fetch_to(metadata.date(), &path(metadata))
+}
+
+/// Downloads the input of the puzzle and saves it at `path`, remembering when it was downloaded.
+#[cfg(feature = "download")]
+pub fn fetch_to(date: PuzzleDate, path: &Path) -> Result<String, Error> {
+    let _span = tracing::info_span!("download").entered();
+    let input = download::input(date, &download::session()?)?;
+    write_to(path, &input)?;
+
+    // Failing to record the download only means it isn't known to be fresh
+    if let Ok(mut downloads) = Downloads::open(Downloads::DEFAULT_PATH) {
+        downloads.record(date, &input);
+        let _ = downloads.save();
+    }
+
+    Ok(input)
+}
+
+/// The state of the input of a puzzle on disk, compared to when it was downloaded.
+#[cfg(feature = "download")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Freshness {
+    Missing,
+    /// A web page was saved in place of the input, e.g. while the session was expired
+    Invalid,
+    /// Untouched since it was downloaded at this time
+    Downloaded(SystemTime),
+    /// Changed by hand since it was downloaded, or never downloaded by `fetch`
+    Modified
+}
+
+#[cfg(feature = "download")]
+pub fn freshness(metadata: &Metadata, downloads: &Downloads) -> io::Result<Freshness> {
+    let input = match read(metadata) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Freshness::Missing),
+        input => input?
+    };
+
+    if download::check_input(metadata.date(), &input).is_err() {
+        return Ok(Freshness::Invalid);
+    }
+
+    match downloads.get(metadata.date()) {
+        Some(download) if download.hash == cache::input_hash(&input) => Ok(Freshness::Downloaded(download.at)),
+        _ => Ok(Freshness::Modified)
+    }
+}
+
+/// The lines of a file, read and split on a background thread while they're being consumed.
+/// Iteration stops at the end of the file or at the first read error, which `finish` reports.
+pub struct PipelinedLines {
+    batches: Receiver<Vec<String>>,
+    current: std::vec::IntoIter<String>,
+    reader: JoinHandle<io::Result<()>>
+}
+
+impl PipelinedLines {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<PipelinedLines> {
+        let file = BufReader::new(File::open(path)?);
+        let (sender, batches) = mpsc::sync_channel(BATCHES_IN_FLIGHT);
+        let reader = thread::spawn(move || {
+            let mut batch = Vec::with_capacity(LINES_PER_BATCH);
+            for line in file.lines() {
+                batch.push(line?);
+                if batch.len() == LINES_PER_BATCH && sender.send(std::mem::take(&mut batch)).is_err() {
+                    // The consumer stopped early, nobody wants the rest
+                    return Ok(());
+                }
+            }
+
+            let _ = sender.send(batch);
+            Ok(())
+        });
+
+        Ok(PipelinedLines { batches, current: vec![].into_iter(), reader })
+    }
+
+    /// Waits for the reader thread, returning the read error that cut the lines short, if any.
+    pub fn finish(self) -> io::Result<()> {
+        drop(self.batches);
+        self.reader.join().expect("Input reader thread panicked")
+    }
+}
+
+impl Iterator for PipelinedLines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        loop {
+            if let Some(line) = self.current.next() {
+                return Some(line);
+            }
+
+            self.current = self.batches.recv().ok()?.into_iter();
+        }
+    }
+}
+
+/// `Hill Climbing Algorithm` becomes `HillClimbingAlgorithm`, as in the input and source paths.
+pub fn pascal_case(title: &str) -> String {
+    title
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_ascii_uppercase()).into_iter().chain(chars)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::RuntimeTier;
+
+    fn metadata(year: u16, day: u8, title: &'static str) -> Metadata {
+        Metadata { year, day, title, tags: &[], runtime: RuntimeTier::Instant, strategies: &[] }
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(path(&metadata(2022, 11, "Monkey in the Middle")), PathBuf::from("inputs/2022/11/MonkeyInTheMiddle.txt"));
+        assert_eq!(path(&metadata(2022, 10, "Cathode-Ray Tube")), PathBuf::from("inputs/2022/10/CathodeRayTube.txt"));
+        assert_eq!(path(&metadata(2023, 6, "Wait For It")), PathBuf::from("inputs/2023/06/input.txt"));
+    }
+
+    #[test]
+    fn test_write_creates_directories() {
+        let path = std::env::temp_dir().join(format!("aoc_inputs_{}", std::process::id())).join("2023/06/input.txt");
+        write_to(&path, "Time: 7").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "Time: 7");
+        std::fs::remove_dir_all(path.ancestors().nth(3).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_pipelined_lines() {
+        let path = std::env::temp_dir().join(format!("aoc_pipelined_lines_{}.txt", std::process::id()));
+        let input: String = (0..10000).map(|line| format!("{line}\n")).collect();
+        std::fs::write(&path, &input).unwrap();
+
+        let mut lines = PipelinedLines::open(&path).unwrap();
+        assert_eq!(lines.by_ref().map(|line| line.parse::<u64>().unwrap()).sum::<u64>(), 49995000);
+        assert!(lines.finish().is_ok());
+
+        let mut lines = PipelinedLines::open(&path).unwrap();
+        assert_eq!(lines.next().as_deref(), Some("0"));
+        assert!(lines.finish().is_ok());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(PipelinedLines::open(&path).is_err());
+    }
+}
@@ -0,0 +1,254 @@
+//! The Intcode computer of 2019: a program of comma-separated integers running in its own
+//! memory, with parameter modes and a relative base. Input and output go through queues, and the
+//! computer pauses when it needs input none has been given yet, so several of them can feed each
+//! other in turns.
+
+use crate::privacy::Excerpt;
+use std::collections::VecDeque;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("Invalid program value: {0}")]
+    InvalidValue(Excerpt),
+    #[error("Unknown opcode {opcode} at {address}")]
+    UnknownOpcode { opcode: i64, address: usize },
+    #[error("Unknown parameter mode {mode} at {address}")]
+    UnknownMode { mode: i64, address: usize },
+    #[error("Negative address {address} at {at}")]
+    NegativeAddress { address: i64, at: usize },
+    #[error("Write in immediate mode at {0}")]
+    ImmediateWrite(usize)
+}
+
+/// Why `run` returned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Waiting on an input, run again once it's given.
+    NeedsInput,
+    Halted
+}
+
+#[derive(Clone, Debug)]
+pub struct Computer {
+    memory: Vec<i64>,
+    ip: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+    output: VecDeque<i64>,
+    halted: bool
+}
+
+impl Computer {
+    pub fn new(program: Vec<i64>) -> Computer {
+        Computer { memory: program, ip: 0, relative_base: 0, input: VecDeque::new(), output: VecDeque::new(), halted: false }
+    }
+
+    /// The computer running the comma-separated program in `source`.
+    pub fn parse(source: &str) -> Result<Computer, Error> {
+        let program = source
+            .trim()
+            .split(',')
+            .map(|value| value.trim().parse().map_err(|_| Error::InvalidValue(value.into())))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Computer::new(program))
+    }
+
+    /// The value at `address`. Memory past the program reads as 0.
+    pub fn read(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or_default()
+    }
+
+    /// Sets the value at `address`, growing the memory as needed.
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+
+        self.memory[address] = value;
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Queues a value for the input instructions.
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    /// The oldest output not taken yet.
+    pub fn pop_output(&mut self) -> Option<i64> {
+        self.output.pop_front()
+    }
+
+    /// Every output not taken yet, oldest first.
+    pub fn take_output(&mut self) -> Vec<i64> {
+        self.output.drain(..).collect()
+    }
+
+    /// Runs until the program halts or needs an input it hasn't been given.
+    pub fn run(&mut self) -> Result<Status, Error> {
+        while !self.halted {
+            if !self.step()? {
+                return Ok(Status::NeedsInput);
+            }
+        }
+
+        Ok(Status::Halted)
+    }
+
+    /// Queues `input`, runs, and returns what the program output meanwhile, e.g. to run it once
+    /// through.
+    pub fn run_with(&mut self, input: impl IntoIterator<Item = i64>) -> Result<Vec<i64>, Error> {
+        self.input.extend(input);
+        self.run()?;
+        Ok(self.take_output())
+    }
+
+    /// Executes the instruction at the instruction pointer. `false` if it waits on an input.
+    fn step(&mut self) -> Result<bool, Error> {
+        let instruction = self.read(self.ip);
+        let opcode = instruction % 100;
+        let parameters = match opcode {
+            1 | 2 | 7 | 8 => 3,
+            5 | 6 => 2,
+            3 | 4 | 9 => 1,
+            99 => 0,
+            _ => return Err(Error::UnknownOpcode { opcode, address: self.ip })
+        };
+
+        let written = match opcode {
+            1 | 2 | 7 | 8 => Some(2),
+            3 => Some(0),
+            _ => None
+        };
+        let mut addresses = [0; 3];
+        for (index, address) in addresses.iter_mut().enumerate().take(parameters) {
+            let mode = instruction / 10i64.pow(index as u32 + 2) % 10;
+            if mode == 1 && written == Some(index) {
+                return Err(Error::ImmediateWrite(self.ip));
+            }
+
+            *address = self.address(index, mode)?;
+        }
+
+        let value = |computer: &Computer, index: usize| computer.read(addresses[index]);
+        let mut next = self.ip + parameters + 1;
+        match opcode {
+            1 => self.write(addresses[2], value(self, 0) + value(self, 1)),
+            2 => self.write(addresses[2], value(self, 0) * value(self, 1)),
+            3 => match self.input.pop_front() {
+                Some(input) => self.write(addresses[0], input),
+                None => return Ok(false)
+            },
+            4 => self.output.push_back(value(self, 0)),
+            5 | 6 => {
+                if (value(self, 0) != 0) == (opcode == 5) {
+                    next = self.to_address(value(self, 1))?;
+                }
+            },
+            7 => self.write(addresses[2], (value(self, 0) < value(self, 1)) as i64),
+            8 => self.write(addresses[2], (value(self, 0) == value(self, 1)) as i64),
+            9 => self.relative_base += value(self, 0),
+            _ => {
+                self.halted = true;
+                return Ok(true);
+            }
+        }
+
+        self.ip = next;
+        Ok(true)
+    }
+
+    /// Where parameter `index` of the current instruction is, for its `mode`. In immediate mode
+    /// that's the parameter itself.
+    fn address(&self, index: usize, mode: i64) -> Result<usize, Error> {
+        let parameter = self.ip + index + 1;
+        match mode {
+            0 => self.to_address(self.read(parameter)),
+            1 => Ok(parameter),
+            2 => self.to_address(self.relative_base + self.read(parameter)),
+            _ => Err(Error::UnknownMode { mode, address: self.ip })
+        }
+    }
+
+    fn to_address(&self, address: i64) -> Result<usize, Error> {
+        usize::try_from(address).map_err(|_| Error::NegativeAddress { address, at: self.ip })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_memory() {
+        let mut computer = Computer::parse("1,9,10,3,2,3,11,0,99,30,40,50\n").unwrap();
+        assert_eq!(computer.run(), Ok(Status::Halted));
+        assert_eq!(computer.read(0), 3500);
+        assert_eq!(computer.read(1000), 0);
+
+        computer.write(20, 1);
+        assert_eq!(computer.memory().len(), 21);
+        assert!(Computer::parse("1,x").is_err());
+    }
+
+    #[test]
+    fn test_input_output() {
+        // Whether the input is 8, in position mode then immediate mode
+        for source in ["3,9,8,9,10,9,4,9,99,-1,8", "3,3,1108,-1,8,3,4,3,99"] {
+            assert_eq!(Computer::parse(source).unwrap().run_with([8]), Ok(vec![1]));
+            assert_eq!(Computer::parse(source).unwrap().run_with([7]), Ok(vec![0]));
+        }
+
+        let mut computer = Computer::parse("3,0,4,0,99").unwrap();
+        assert_eq!(computer.run(), Ok(Status::NeedsInput));
+        computer.push_input(42);
+        assert_eq!(computer.run(), Ok(Status::Halted));
+        assert_eq!(computer.pop_output(), Some(42));
+        assert_eq!(computer.pop_output(), None);
+    }
+
+    #[test]
+    fn test_relative_base() {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let program: Vec<i64> = quine.split(',').map(|value| value.parse().unwrap()).collect();
+        assert_eq!(Computer::parse(quine).unwrap().run_with([]), Ok(program));
+        assert_eq!(Computer::parse("104,1125899906842624,99").unwrap().run_with([]), Ok(vec![1125899906842624]));
+    }
+
+    #[test]
+    fn test_feedback_loop() {
+        let source = "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5";
+        let mut amplifiers: Vec<Computer> = [9, 8, 7, 6, 5]
+            .into_iter()
+            .map(|phase| {
+                let mut amplifier = Computer::parse(source).unwrap();
+                amplifier.push_input(phase);
+                amplifier
+            })
+            .collect();
+
+        let mut signal = vec![0];
+        while !amplifiers[4].is_halted() {
+            for amplifier in &mut amplifiers {
+                signal = amplifier.run_with(signal).unwrap();
+            }
+        }
+
+        assert_eq!(signal, vec![139629729]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Computer::new(vec![42]).run(), Err(Error::UnknownOpcode { opcode: 42, address: 0 }));
+        assert_eq!(Computer::new(vec![301, 0, 0, 0]).run(), Err(Error::UnknownMode { mode: 3, address: 0 }));
+        assert_eq!(Computer::new(vec![1, -1, 0, 0]).run(), Err(Error::NegativeAddress { address: -1, at: 0 }));
+        assert_eq!(Computer::new(vec![10001, 0, 0, 0]).run(), Err(Error::ImmediateWrite(0)));
+    }
+}
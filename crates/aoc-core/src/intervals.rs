@@ -0,0 +1,284 @@
+use alloc::{ vec, vec::Vec };
+
+/// A closed range of integers, `start..=end`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64
+}
+
+impl Interval {
+    /// Builds the interval `start..=end`, or `None` if it would be empty.
+    pub fn new(start: i64, end: i64) -> Option<Interval> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    /// Number of integers in the interval, which is never empty.
+    pub fn size(&self) -> u64 {
+        self.start.abs_diff(self.end) + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// The interval moved by `offset`.
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval { start: self.start + offset, end: self.end + offset }
+    }
+}
+
+/// A union of intervals, kept as a sorted list of disjoint, non-adjacent intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntervalSet {
+    intervals: Vec<Interval>
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet { intervals: vec![] }
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let first = self.intervals.partition_point(|other| other.end < interval.start.saturating_sub(1));
+        let last = self.intervals.partition_point(|other| other.start <= interval.end.saturating_add(1));
+
+        let merged = self.intervals[first..last].iter().fold(interval, |merged, other| Interval {
+            start: merged.start.min(other.start),
+            end: merged.end.max(other.end)
+        });
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Takes the integers of `interval` out of the set, splitting the interval around it if any.
+    pub fn remove(&mut self, interval: Interval) {
+        let first = self.intervals.partition_point(|other| other.end < interval.start);
+        let last = self.intervals.partition_point(|other| other.start <= interval.end);
+        if first == last {
+            return;
+        }
+
+        // Only the first and the last of the overlapping intervals can stick out of it
+        let before = Interval::new(self.intervals[first].start, interval.start - 1);
+        let after = Interval::new(interval.end + 1, self.intervals[last - 1].end);
+        self.intervals.splice(first..last, before.into_iter().chain(after));
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let (mut left, mut right) = (self.intervals.iter().peekable(), other.intervals.iter().peekable());
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            intervals.extend(a.intersection(b));
+            // Whichever ends first can't overlap anything further on the other side
+            match a.end < b.end {
+                true => left.next(),
+                false => right.next()
+            };
+        }
+
+        // Parts of disjoint, non-adjacent intervals are still disjoint and non-adjacent
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut difference = self.clone();
+        for interval in other.iter() {
+            difference.remove(*interval);
+        }
+
+        difference
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.intervals.partition_point(|interval| interval.end < value);
+        self.intervals.get(idx).is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Number of integers covered by the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::size).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.intervals.iter()
+    }
+
+    /// The maximal intervals within `bounds` that are not covered by the set.
+    pub fn gaps(&self, bounds: Interval) -> Vec<Interval> {
+        let mut gaps = vec![];
+        let mut next_start = bounds.start;
+
+        for interval in self.intervals.iter().filter_map(|interval| interval.intersection(&bounds)) {
+            gaps.extend(Interval::new(next_start, interval.start - 1));
+            next_start = interval.end + 1;
+        }
+
+        gaps.extend(Interval::new(next_start, bounds.end));
+        gaps
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+
+        set
+    }
+}
+
+/// A piecewise shift of the integers: those in the source interval of a piece move by its
+/// offset, the others stay where they are. The sources are expected not to overlap.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IntervalMap {
+    pieces: Vec<(Interval, i64)>
+}
+
+impl IntervalMap {
+    pub fn new() -> IntervalMap {
+        IntervalMap { pieces: vec![] }
+    }
+
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        let idx = self.pieces.partition_point(|(other, _)| other.start < source.start);
+        self.pieces.insert(idx, (source, offset));
+    }
+
+    /// Where `value` ends up.
+    pub fn get(&self, value: i64) -> i64 {
+        let idx = self.pieces.partition_point(|(source, _)| source.end < value);
+        match self.pieces.get(idx) {
+            Some((source, offset)) if source.contains(value) => value + offset,
+            _ => value
+        }
+    }
+
+    /// Where all of `set` ends up, without going through its integers one by one.
+    pub fn apply(&self, set: &IntervalSet) -> IntervalSet {
+        let mut result = IntervalSet::new();
+        let mut unmoved = set.clone();
+        for (source, offset) in &self.pieces {
+            for overlap in set.iter().filter_map(|interval| interval.intersection(source)) {
+                result.insert(overlap.shift(*offset));
+            }
+
+            unmoved.remove(*source);
+        }
+
+        result.union(&unmoved)
+    }
+}
+
+impl FromIterator<(Interval, i64)> for IntervalMap {
+    fn from_iter<I: IntoIterator<Item = (Interval, i64)>>(iter: I) -> Self {
+        let mut map = IntervalMap::new();
+        for (source, offset) in iter {
+            map.insert(source, offset);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn interval(start: i64, end: i64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(Interval::new(3, 2), None);
+        assert_eq!(interval(-2, 2).size(), 5);
+        assert_eq!(interval(0, 5).intersection(&interval(3, 8)), Some(interval(3, 5)));
+        assert_eq!(interval(0, 2).intersection(&interval(3, 8)), None);
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let set: IntervalSet = [interval(0, 2), interval(10, 12), interval(4, 5), interval(3, 3)].into_iter().collect();
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![interval(0, 5), interval(10, 12)]);
+        assert_eq!(set.len(), 9);
+
+        let mut set = set;
+        set.insert(interval(-5, 20));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![interval(-5, 20)]);
+    }
+
+    #[test]
+    fn test_contains() {
+        let set: IntervalSet = [interval(0, 2), interval(10, 12)].into_iter().collect();
+        assert!(set.contains(0) && set.contains(11));
+        assert!(!set.contains(-1) && !set.contains(5) && !set.contains(13));
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|(start, end)| interval(*start, *end)).collect()
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = set(&[(0, 5), (10, 12), (20, 30)]);
+        set.remove(interval(3, 21));
+        assert_eq!(set, self::set(&[(0, 2), (22, 30)]));
+        set.remove(interval(25, 25));
+        assert_eq!(set, self::set(&[(0, 2), (22, 24), (26, 30)]));
+        set.remove(interval(-10, 40));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let (a, b) = (set(&[(0, 5), (10, 15)]), set(&[(3, 11), (14, 20)]));
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 11), (14, 15)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (12, 13)]));
+        assert_eq!(b.difference(&a), set(&[(6, 9), (16, 20)]));
+        assert_eq!(a.intersection(&IntervalSet::new()), IntervalSet::new());
+        assert_eq!(b.min(), Some(3));
+    }
+
+    #[test]
+    fn test_interval_map() {
+        let map: IntervalMap = [(interval(10, 14), 10), (interval(0, 1), -5)].into_iter().collect();
+        assert_eq!(map.get(12), 22);
+        assert_eq!(map.get(1), -4);
+        assert_eq!(map.get(5), 5);
+
+        assert_eq!(map.apply(&set(&[(12, 13)])), set(&[(22, 23)]));
+        assert_eq!(map.apply(&set(&[(8, 11)])), set(&[(8, 9), (20, 21)]));
+        assert_eq!(map.apply(&set(&[(8, 17)])), set(&[(8, 9), (15, 17), (20, 24)]));
+        assert_eq!(map.apply(&set(&[(-1, 3)])), set(&[(-5, -4), (-1, -1), (2, 3)]));
+    }
+
+    #[test]
+    fn test_gaps() {
+        let set: IntervalSet = [interval(0, 2), interval(5, 6)].into_iter().collect();
+        assert_eq!(set.gaps(interval(-1, 10)), vec![interval(-1, -1), interval(3, 4), interval(7, 10)]);
+        assert_eq!(set.gaps(interval(1, 2)), vec![]);
+    }
+}
@@ -0,0 +1,72 @@
+//! The code the solutions of every year share: the `Solution` trait, reading inputs and caching,
+//! and the algorithms and data structures.
+//!
+//! Without the default `std` feature only the latter are built, on top of `core` and `alloc`,
+//! e.g. `cargo rustc -p aoc-core --lib --no-default-features`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+// Algorithms and data structures, which build without std
+pub mod collections;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod intervals;
+pub mod linear;
+pub mod math;
+pub mod permutations;
+pub mod voxels;
+
+// Solutions, IO and everything else that needs std
+#[cfg(feature = "std")]
+pub mod answer;
+#[cfg(feature = "std")]
+pub mod cache;
+#[cfg(feature = "std")]
+pub mod counter;
+#[cfg(feature = "std")]
+pub mod cycles;
+#[cfg(feature = "std")]
+pub mod date;
+#[cfg(feature = "download")]
+pub mod download;
+#[cfg(feature = "hash")]
+pub mod hash;
+#[cfg(feature = "fs")]
+pub mod inputs;
+#[cfg(feature = "std")]
+pub mod intcode;
+#[cfg(feature = "std")]
+pub mod memo;
+#[cfg(feature = "std")]
+pub mod ocr;
+#[cfg(feature = "parsers")]
+pub mod parsers;
+#[cfg(feature = "std")]
+pub mod parsing;
+#[cfg(feature = "std")]
+pub mod prelude;
+#[cfg(feature = "std")]
+pub mod privacy;
+#[cfg(feature = "std")]
+pub mod scanner;
+#[cfg(feature = "std")]
+pub mod sequences;
+#[cfg(feature = "std")]
+pub mod solution;
+#[cfg(feature = "std")]
+pub mod sparse_grid;
+#[cfg(feature = "std")]
+pub mod visualize;
+#[cfg(feature = "std")]
+pub mod vm;
+
+pub use collections::{ HashMap, HashSet };
+
+pub fn clamp<T>(num: T, min: T, max: T) -> T
+where T: Ord
+{
+    core::cmp::max(core::cmp::min(num, max), min)
+}
@@ -0,0 +1,163 @@
+//! Exact solutions of small systems of linear equations, e.g. the few unknowns left once a
+//! puzzle's equations are subtracted from each other. Everything is a fraction of `i128`s, so
+//! nothing is lost to rounding, and overflowing is reported rather than wrapped around.
+
+use crate::math::egcd;
+use alloc::vec::Vec;
+
+/// A rational number in lowest terms, with a positive denominator.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fraction {
+    numerator: i128,
+    denominator: i128
+}
+
+impl Fraction {
+    pub const ZERO: Fraction = Fraction { numerator: 0, denominator: 1 };
+    pub const ONE: Fraction = Fraction { numerator: 1, denominator: 1 };
+
+    /// `numerator / denominator`, or `None` for a denominator of 0.
+    pub fn new(numerator: i128, denominator: i128) -> Option<Fraction> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let divisor = egcd(numerator, denominator).0.abs() * denominator.signum();
+        Some(Fraction { numerator: numerator / divisor, denominator: denominator / divisor })
+    }
+
+    pub const fn integer(value: i128) -> Fraction {
+        Fraction { numerator: value, denominator: 1 }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// The fraction as a whole number, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn checked_add(&self, other: Fraction) -> Option<Fraction> {
+        let numerator = self.numerator.checked_mul(other.denominator)?.checked_add(other.numerator.checked_mul(self.denominator)?)?;
+        Fraction::new(numerator, self.denominator.checked_mul(other.denominator)?)
+    }
+
+    pub fn checked_sub(&self, other: Fraction) -> Option<Fraction> {
+        self.checked_add(Fraction { numerator: other.numerator.checked_neg()?, ..other })
+    }
+
+    pub fn checked_mul(&self, other: Fraction) -> Option<Fraction> {
+        Fraction::new(self.numerator.checked_mul(other.numerator)?, self.denominator.checked_mul(other.denominator)?)
+    }
+
+    /// `None` when dividing by 0, too.
+    pub fn checked_div(&self, other: Fraction) -> Option<Fraction> {
+        Fraction::new(self.numerator.checked_mul(other.denominator)?, self.denominator.checked_mul(other.numerator)?)
+    }
+}
+
+impl From<i64> for Fraction {
+    fn from(value: i64) -> Fraction {
+        Fraction::integer(value as i128)
+    }
+}
+
+/// Solves the system where each of the `equations` is the coefficients of the unknowns followed by
+/// the constant they add up to, by Gaussian elimination. There may be more equations than
+/// unknowns as long as they agree. Returns `None` unless there's exactly one solution, or if the
+/// arithmetic overflows.
+pub fn solve<E: AsRef<[i128]>>(equations: &[E]) -> Option<Vec<Fraction>> {
+    let unknowns = equations.first()?.as_ref().len().checked_sub(1)?;
+    let mut rows: Vec<Vec<Fraction>> = equations
+        .iter()
+        .map(|equation| equation.as_ref().iter().map(|value| Fraction::integer(*value)).collect())
+        .collect();
+
+    if rows.len() < unknowns || rows.iter().any(|row| row.len() != unknowns + 1) {
+        return None;
+    }
+
+    for column in 0..unknowns {
+        let pivot = (column..rows.len()).find(|row| !rows[*row][column].is_zero())?;
+        rows.swap(column, pivot);
+
+        let divisor = rows[column][column];
+        for value in rows[column].iter_mut() {
+            *value = value.checked_div(divisor)?;
+        }
+
+        // Clears the column from every other row, above included, to leave the solution alone
+        let pivot = rows[column].clone();
+        for row in (0..rows.len()).filter(|row| *row != column) {
+            let factor = rows[row][column];
+            if factor.is_zero() {
+                continue;
+            }
+
+            for (value, pivot) in rows[row].iter_mut().zip(&pivot).skip(column) {
+                *value = value.checked_sub(pivot.checked_mul(factor)?)?;
+            }
+        }
+    }
+
+    // What's left of the extra equations has to read 0 = 0
+    if rows[unknowns..].iter().any(|row| !row[unknowns].is_zero()) {
+        return None;
+    }
+
+    Some(rows[..unknowns].iter().map(|row| row[unknowns]).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_fractions() {
+        let half = Fraction::new(3, 6).unwrap();
+        assert_eq!((half.numerator(), half.denominator()), (1, 2));
+        assert_eq!(Fraction::new(2, -4), Fraction::new(-1, 2));
+        assert_eq!(Fraction::new(1, 0), None);
+        assert_eq!(Fraction::new(0, -5), Some(Fraction::ZERO));
+
+        let third = Fraction::new(1, 3).unwrap();
+        assert_eq!(half.checked_add(third), Fraction::new(5, 6));
+        assert_eq!(half.checked_sub(third), Fraction::new(1, 6));
+        assert_eq!(half.checked_mul(third), Fraction::new(1, 6));
+        assert_eq!(half.checked_div(third), Fraction::new(3, 2));
+        assert_eq!(half.checked_div(Fraction::ZERO), None);
+        assert_eq!(half.checked_add(half).and_then(|one| one.to_integer()), Some(1));
+        assert_eq!(Fraction::integer(i128::MAX).checked_add(Fraction::ONE), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        // x + y = 3, x - y = 0
+        assert_eq!(solve(&[[1, 1, 3], [1, -1, 0]]), Some(vec![Fraction::new(3, 2).unwrap(); 2]));
+
+        // The rock's X, Y, vx and vy from pairs of hailstones of 2023/24
+        let hailstones = [[2, 1, 6, 1, 44], [3, 0, 12, -1, 35], [3, 1, 18, 7, 38], [6, 3, 6, -1, 164]];
+        let solution = solve(&hailstones).unwrap();
+        assert_eq!(solution, [24, 13, -3, 1].map(Fraction::from));
+
+        // Agreeing extra equations, then disagreeing ones
+        assert_eq!(solve(&[[0, 2, 4], [1, 0, 1], [1, 1, 3]]), Some(vec![Fraction::ONE, Fraction::integer(2)]));
+        assert_eq!(solve(&[[0, 2, 4], [1, 0, 1], [1, 1, 4]]), None);
+
+        assert_eq!(solve(&[[1, 1, 2], [2, 2, 4]]), None);
+        assert_eq!(solve(&[[1, 2]]), Some(vec![Fraction::integer(2)]));
+        assert_eq!(solve::<[i128; 3]>(&[]), None);
+    }
+}
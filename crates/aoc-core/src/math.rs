@@ -0,0 +1,222 @@
+use alloc::vec::Vec;
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    a / gcd(a, b) * b
+}
+
+/// Like `lcm`, but `None` instead of overflowing, e.g. when folding over many large periods.
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Extended Euclid: returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`.
+pub fn egcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+
+    let (g, x, y) = egcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+/// Chinese remainder theorem for moduli that need not be coprime. Combines the congruences
+/// `t = residue (mod modulus)` into a single `(residue, modulus)`, with `residue < modulus`.
+/// Returns `None` if a modulus is 0, the congruences are incompatible or the combined modulus
+/// overflows `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    congruences.into_iter().try_fold((0, 1), |(r1, m1), (r2, m2)| {
+        if m2 == 0 {
+            return None;
+        }
+
+        let (r1, m1, r2, m2) = (r1 as i128, m1 as i128, (r2 % m2) as i128, m2 as i128);
+        let (g, p, _) = egcd(m1, m2);
+        if (r2 - r1) % g != 0 {
+            return None;
+        }
+
+        // Products of numbers that fit in a u64 only fit in a u128
+        let step = m2 / g;
+        let k = ((r2 - r1) / g).rem_euclid(step) as u128 * p.rem_euclid(step) as u128 % step as u128;
+        let modulus = u64::try_from(m1 as u128 * step as u128).ok()?;
+        let residue = (r1 as u128 + m1 as u128 * k) % modulus as u128;
+        Some((residue as u64, modulus))
+    })
+}
+
+/// When events that each happen every `period` from `offset` on, given as `(offset, period)`,
+/// first all happen at once, and how often they do from then on, e.g. buses leaving on their
+/// schedules. Returns `None` if a period is 0, or if they never happen at once, or not within a
+/// `u64`.
+pub fn align(events: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    let (start, residue, period) = events.into_iter().try_fold((0, 0, 1), |(start, residue, period), (offset, other)| {
+        let (residue, period) = crt([(residue, period), (offset, other)])?;
+        Some((start.max(offset), residue, period))
+    })?;
+
+    // The first time of the combined schedule once every event has started
+    let laps = start.saturating_sub(residue).div_ceil(period);
+    Some((laps.checked_mul(period)?.checked_add(residue)?, period))
+}
+
+/// Evaluates at `x` the lowest-degree polynomial that takes `values[i]` at `i`, for any integer
+/// `x` (negative to extrapolate backwards). Uses Newton's forward differences, so the arithmetic
+/// stays exact. Returns `None` for an empty sequence or if the result overflows.
+pub fn extrapolate(values: &[i64], x: i64) -> Option<i64> {
+    let mut differences: Vec<i128> = values.iter().map(|value| *value as i128).collect();
+    let mut leading = Vec::with_capacity(values.len());
+    while let Some(first) = differences.first() {
+        leading.push(*first);
+        differences = differences.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    }
+
+    if leading.is_empty() {
+        return None;
+    }
+
+    // sum of leading[k] * binomial(x, k), where the binomial is built up incrementally
+    let (x, mut binomial, mut result) = (x as i128, 1i128, 0i128);
+    for (k, difference) in leading.iter().enumerate() {
+        result = result.checked_add(difference.checked_mul(binomial)?)?;
+        binomial = binomial.checked_mul(x - k as i128)? / (k as i128 + 1);
+    }
+
+    i64::try_from(result).ok()
+}
+
+/// Evaluates at `x` the lowest-degree polynomial through the `points`, given as `(x, y)` at any
+/// distinct `x`, e.g. samples taken every so many steps. Uses Lagrange's formula over exact
+/// fractions. Returns `None` without points, with the same `x` twice, if the result isn't a
+/// whole number or if it overflows.
+pub fn interpolate(points: &[(i64, i64)], x: i64) -> Option<i64> {
+    if points.is_empty() {
+        return None;
+    }
+
+    // The sum so far, as a fraction in lowest terms
+    let (mut numerator, mut denominator) = (0i128, 1i128);
+    for (i, (x_i, y_i)) in points.iter().enumerate() {
+        let (mut term_numerator, mut term_denominator) = (*y_i as i128, 1i128);
+        for (_, (x_j, _)) in points.iter().enumerate().filter(|(j, _)| *j != i) {
+            term_numerator = term_numerator.checked_mul((x - x_j) as i128)?;
+            term_denominator = term_denominator.checked_mul((x_i - x_j) as i128)?;
+        }
+
+        if term_denominator == 0 {
+            return None;
+        }
+
+        numerator = numerator.checked_mul(term_denominator)?.checked_add(term_numerator.checked_mul(denominator)?)?;
+        denominator = denominator.checked_mul(term_denominator)?;
+        let divisor = egcd(numerator, denominator).0;
+        (numerator, denominator) = (numerator / divisor, denominator / divisor);
+    }
+
+    match numerator % denominator {
+        0 => i64::try_from(numerator / denominator).ok(),
+        _ => None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(7, 13), 1);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(7, 13), 91);
+        assert_eq!(lcm(0, 3), 0);
+        assert_eq!([23, 19, 13, 17].into_iter().fold(1, lcm), 96577);
+
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(0, u64::MAX), Some(0));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_egcd() {
+        let (g, x, y) = egcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(7, 5)]), Some((2, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(2, 3), (1, 0)]), None);
+        assert_eq!(crt([(1, u64::MAX), (2, u64::MAX - 1)]), None);
+        assert_eq!(crt([(1, 1 << 32), (2, (1 << 32) - 1)]), Some((4294967297, 18446744069414584320)));
+    }
+
+    #[test]
+    fn test_align() {
+        assert_eq!(align([(2, 2), (3, 3)]), Some((6, 6)));
+        assert_eq!(align([(10, 5), (3, 1)]), Some((10, 5)));
+        assert_eq!(align([(4, 6), (1, 4)]), None);
+        assert_eq!(align([]), Some((0, 1)));
+        assert_eq!(align([(2, 2), (3, 0)]), None);
+
+        // Buses leaving one minute after the other, from 2020/13
+        let buses = [(0, 7), (1, 13), (4, 59), (6, 31), (7, 19)];
+        let events = buses.map(|(delay, period)| ((period - delay % period) % period, period));
+        assert_eq!(align(events), Some((1068781, 3162341)));
+    }
+
+    #[test]
+    fn test_interpolate() {
+        // Samples every 131 steps from 65, like the garden of 2023/21
+        let garden = [(65, 12806), (196, 115641), (327, 321442)];
+        assert_eq!(interpolate(&garden, 26501365), Some(2106967093592406));
+        assert_eq!(interpolate(&garden, 196), Some(115641));
+        assert_eq!(interpolate(&[(3, 7)], -100), Some(7));
+        assert_eq!(interpolate(&[(2, 4), (-1, 1), (0, 0)], -3), Some(9));
+
+        assert_eq!(interpolate(&[(0, 0), (2, 1)], 1), None);
+        assert_eq!(interpolate(&[(1, 2), (1, 3)], 0), None);
+        assert_eq!(interpolate(&[], 0), None);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let squares = [0, 1, 4, 9, 16];
+        assert_eq!(extrapolate(&squares, 5), Some(25));
+        assert_eq!(extrapolate(&squares, 10), Some(100));
+        assert_eq!(extrapolate(&squares, -3), Some(9));
+        assert_eq!(extrapolate(&squares, 2), Some(4));
+
+        assert_eq!(extrapolate(&[7], 100), Some(7));
+        assert_eq!(extrapolate(&[1, 8, 27, 64], -2), Some(-1));
+        assert_eq!(extrapolate(&[], 0), None);
+    }
+}
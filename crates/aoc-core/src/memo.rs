@@ -0,0 +1,84 @@
+use crate::HashMap;
+use std::{ hash::Hash, rc::Rc };
+
+type MemoFn<'f, K, V> = dyn Fn(&mut Memo<'f, K, V>, K) -> V + 'f;
+
+/// A memoized recursive function. The function receives the memo itself, so that its recursive
+/// calls go through `get` and hit the cache:
+///
+/// ```
+/// use aoc_core::memo::Memo;
+///
+/// let mut fibonacci = Memo::new(|memo: &mut Memo<u64, u64>, n: u64| {
+///     if n < 2 { n } else { memo.get(n - 1) + memo.get(n - 2) }
+/// });
+/// assert_eq!(fibonacci.get(90), 2880067194370816120);
+/// ```
+pub struct Memo<'f, K, V> {
+    cache: HashMap<K, V>,
+    function: Rc<MemoFn<'f, K, V>>
+}
+
+impl<'f, K, V> Memo<'f, K, V>
+where K: Clone + Eq + Hash
+    , V: Clone
+{
+    pub fn new(function: impl Fn(&mut Memo<'f, K, V>, K) -> V + 'f) -> Memo<'f, K, V> {
+        Memo { cache: HashMap::default(), function: Rc::new(function) }
+    }
+
+    pub fn get(&mut self, key: K) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let function = Rc::clone(&self.function);
+        let value = function(self, key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_calls_once_per_key() {
+        let calls = Cell::new(0);
+        let mut paths = Memo::new(|memo: &mut Memo<(u32, u32), u64>, (x, y): (u32, u32)| {
+            calls.set(calls.get() + 1);
+            if x == 0 || y == 0 { 1 } else { memo.get((x - 1, y)) + memo.get((x, y - 1)) }
+        });
+
+        assert_eq!(paths.get((16, 16)), 601080390);
+        assert_eq!(calls.get(), 17 * 17 - 1);
+        assert_eq!(paths.len(), 17 * 17 - 1);
+
+        paths.get((3, 3));
+        assert_eq!(calls.get(), 17 * 17 - 1);
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut double = Memo::new(|_: &mut Memo<u32, u32>, n: u32| n * 2);
+        assert_eq!(double.get(21), 42);
+        assert!(!double.is_empty());
+        double.clear();
+        assert!(double.is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("Unsupported glyph height: {0}")]
+    UnsupportedHeight(usize),
+    #[error("Unrecognized glyph at letter {0}")]
+    UnknownGlyph(usize)
+}
+
+/// Capital letters of a fixed size, drawn next to each other `spacing` blank columns apart.
+struct Font {
+    width: usize,
+    height: usize,
+    spacing: usize,
+    glyphs: &'static [(char, &'static str)]
+}
+
+// The 4x6 capital letters drawn by the CRT/laser puzzles. Not every letter of the
+// alphabet shows up in those puzzles, so only the known ones are listed.
+const SMALL: Font = Font {
+    width: 4,
+    height: 6,
+    spacing: 1,
+    glyphs: &[
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Z', "####...#..#..#..#...####")
+    ]
+};
+
+// The 6x10 capital letters the stars align into, likewise only the known ones.
+const LARGE: Font = Font {
+    width: 6,
+    height: 10,
+    spacing: 2,
+    glyphs: &[
+        ('A', "..##...#..#.#....##....##....########....##....##....##....#"),
+        ('B', "#####.#....##....##....######.#....##....##....##....######."),
+        ('C', ".####.#....##.....#.....#.....#.....#.....#.....#....#.####."),
+        ('E', "#######.....#.....#.....#####.#.....#.....#.....#.....######"),
+        ('F', "#######.....#.....#.....#####.#.....#.....#.....#.....#....."),
+        ('G', ".####.#....##.....#.....#.....#..####....##....##...##.###.#"),
+        ('H', "#....##....##....##....########....##....##....##....##....#"),
+        ('J', "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###.."),
+        ('K', "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#"),
+        ('L', "#.....#.....#.....#.....#.....#.....#.....#.....#.....######"),
+        ('N', "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#"),
+        ('P', "#####.#....##....##....######.#.....#.....#.....#.....#....."),
+        ('R', "#####.#....##....##....######.#..#..#...#.#...#.#....##....#"),
+        ('X', "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#"),
+        ('Z', "######.....#.....#....#....#....#....#....#.....#.....######")
+    ]
+};
+
+fn glyph_key(pixels: &[Vec<bool>], column: usize, width: usize) -> String {
+    pixels
+        .iter()
+        .flat_map(|row| (column..column + width).map(|x| row.get(x).copied().unwrap_or(false)))
+        .map(|lit| if lit { '#' } else { '.' })
+        .collect()
+}
+
+/// Decodes a raster of lit pixels (one `Vec` per row) drawn with the 4x6 font, where letters are
+/// 4 pixels wide and separated by one blank column, or with the 6x10 one, where they are 6 wide
+/// and separated by two. Its height tells which.
+pub fn read_letters(pixels: &[Vec<bool>]) -> Result<String, Error> {
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| font.height == pixels.len())
+        .ok_or(Error::UnsupportedHeight(pixels.len()))?;
+
+    let glyphs: HashMap<&str, char> = font.glyphs.iter().map(|(letter, glyph)| (*glyph, *letter)).collect();
+    let width = pixels.iter().map(|row| row.len()).max().unwrap_or(0);
+    let letters_count = (width + font.spacing) / (font.width + font.spacing);
+
+    (0..letters_count)
+        .map(|idx| {
+            let key = glyph_key(pixels, idx * (font.width + font.spacing), font.width);
+            glyphs.get(key.as_str()).copied().ok_or(Error::UnknownGlyph(idx))
+        })
+        .collect()
+}
+
+/// Same as `read_letters`, but reads the raster from text where `#` is a lit pixel.
+pub fn read_letters_from_str(raster: &str) -> Result<String, Error> {
+    let pixels: Vec<Vec<bool>> = raster
+        .lines()
+        .map(|line| line.chars().map(|ch| ch == '#').collect())
+        .collect();
+
+    read_letters(&pixels)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_font_is_well_formed() {
+        for font in [SMALL, LARGE] {
+            for (letter, glyph) in font.glyphs {
+                assert_eq!(glyph.len(), font.width * font.height, "Glyph for {letter}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_letters() {
+        let raster = [
+            "###..#..#.#....",
+            "#..#.#..#.#....",
+            "#..#.####.#....",
+            "###..#..#.#....",
+            "#.#..#..#.#....",
+            "#..#.#..#.####."
+        ].join("\n");
+
+        assert_eq!(read_letters_from_str(&raster).unwrap(), "RHL");
+    }
+
+    #[test]
+    fn test_missing_trailing_spacing() {
+        let raster = [
+            ".##..####",
+            "#..#....#",
+            "#......#.",
+            "#.....#..",
+            "#..#.#...",
+            ".##..####"
+        ].join("\n");
+
+        assert_eq!(read_letters_from_str(&raster).unwrap(), "CZ");
+    }
+
+    #[test]
+    fn test_large_letters() {
+        let raster = [
+            "#....#..######",
+            "#....#.......#",
+            ".#..#........#",
+            ".#..#.......#.",
+            "..##.......#..",
+            "..##......#...",
+            ".#..#....#....",
+            ".#..#...#.....",
+            "#....#..#.....",
+            "#....#..######"
+        ].join("\n");
+
+        assert_eq!(read_letters_from_str(&raster).unwrap(), "XZ");
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let raster = ["####"; 6].join("\n");
+        assert_eq!(read_letters_from_str(&raster), Err(Error::UnknownGlyph(0)));
+    }
+
+    #[test]
+    fn test_unsupported_height() {
+        assert_eq!(read_letters_from_str("#..#\n#..#"), Err(Error::UnsupportedHeight(2)));
+    }
+}
@@ -0,0 +1,119 @@
+//! nom combinators for the shapes inputs keep coming in: `label: values` lines, bracketed lists
+//! and sections of lines under a header, separated by blank lines. `parse` runs them over a whole
+//! input. nom itself is re-exported, for days to combine them with its own parsers.
+
+use crate::privacy::Excerpt;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{ char, i64, line_ending, space0, space1 },
+    combinator::all_consuming,
+    multi::{ many1, separated_list0, separated_list1 },
+    sequence::{ delimited, preceded, separated_pair, terminated },
+    Parser
+};
+
+pub use nom;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Unexpected input on line {line}: {excerpt}")]
+    UnexpectedInput { line: usize, excerpt: Excerpt },
+    #[error("Unexpected end of input")]
+    UnexpectedEnd
+}
+
+/// A parser of `&str` with nom's default error, as all of those here are.
+pub trait StrParser<'a, O>: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>> {}
+
+impl<'a, O, P> StrParser<'a, O> for P
+where P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>
+{}
+
+/// Runs `parser` over the whole of `input`, but for its trailing whitespace. Errors point at
+/// the line where the parser stopped.
+pub fn parse<'a, O>(parser: impl StrParser<'a, O>, input: &'a str) -> Result<O, Error> {
+    let input = input.trim_end();
+    match all_consuming(parser).parse(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            let line = input[..input.len() - error.input.len()].matches('\n').count() + 1;
+            let excerpt = error.input.lines().next().unwrap_or_default();
+            Err(Error::UnexpectedInput { line, excerpt: excerpt.into() })
+        },
+        Err(nom::Err::Incomplete(_)) => Err(Error::UnexpectedEnd)
+    }
+}
+
+/// Numbers separated by spaces, e.g. `79 14 -55`.
+pub fn numbers<'a>() -> impl StrParser<'a, Vec<i64>> {
+    separated_list1(space1, i64)
+}
+
+/// `value` after `label` and any spaces, e.g. `seeds: 79 14` for the label `seeds:`.
+pub fn labeled<'a, O>(label: &'a str, value: impl StrParser<'a, O>) -> impl StrParser<'a, O> {
+    preceded((tag(label), space0), value)
+}
+
+/// `key: value`, with any spaces after the colon.
+pub fn key_value<'a, K, V>(key: impl StrParser<'a, K>, value: impl StrParser<'a, V>) -> impl StrParser<'a, (K, V)> {
+    separated_pair(key, (char(':'), space0), value)
+}
+
+/// `item` between the `open` and `close` brackets.
+pub fn bracketed<'a, O>(open: char, item: impl StrParser<'a, O>, close: char) -> impl StrParser<'a, O> {
+    delimited(char(open), item, char(close))
+}
+
+/// Any number of `item`s separated by `separator` and any spaces, e.g. `1, 2, 3`.
+pub fn list<'a, O>(separator: char, item: impl StrParser<'a, O>) -> impl StrParser<'a, Vec<O>> {
+    separated_list0((char(separator), space0), item)
+}
+
+/// One or more blank lines, ending the line before them.
+pub fn blank_lines<'a>() -> impl StrParser<'a, ()> {
+    (line_ending, many1(line_ending)).map(|_| ())
+}
+
+/// A `header` line and the `line`s under it.
+pub fn section<'a, H, O>(header: impl StrParser<'a, H>, line: impl StrParser<'a, O>) -> impl StrParser<'a, (H, Vec<O>)> {
+    (terminated(header, line_ending), separated_list0(line_ending, line))
+}
+
+/// `section`s separated by blank lines.
+pub fn sections<'a, O>(section: impl StrParser<'a, O>) -> impl StrParser<'a, Vec<O>> {
+    separated_list1(blank_lines(), section)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nom::character::complete::{ alpha1, u32 };
+
+    #[test]
+    fn test_labeled() {
+        assert_eq!(parse(labeled("seeds:", numbers()), "seeds: 79 14 -55\n").unwrap(), vec![79, 14, -55]);
+        assert_eq!(parse(key_value(alpha1, u32), "red:  12").unwrap(), ("red", 12));
+    }
+
+    #[test]
+    fn test_bracketed() {
+        let packet = bracketed('[', list(',', u32), ']');
+        assert_eq!(parse(packet, "[1, 2,3]").unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse(bracketed('[', list(',', u32), ']'), "[]").unwrap(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "a:\n1 2\n3\n\n\nb:\n4\n";
+        let header = terminated(alpha1, char(':'));
+        let parsed = parse(sections(section(header, numbers())), input).unwrap();
+        assert_eq!(parsed, vec![("a", vec![vec![1, 2], vec![3]]), ("b", vec![vec![4]])]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = parse(sections(section(alpha1, numbers())), "a\n1 2\n3 x\n").unwrap_err();
+        assert_eq!(error.to_string(), "Unexpected input on line 3:  x");
+        assert!(matches!(parse(numbers(), "a"), Err(Error::UnexpectedInput { line: 1, .. })));
+    }
+}
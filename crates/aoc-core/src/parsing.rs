@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+/// Splits `input` into blocks of consecutive lines separated by one or more blank lines.
+/// The returned blocks don't include the trailing line break.
+pub fn split_blocks(input: &str) -> Vec<&str> {
+    blocks(input).collect()
+}
+
+/// The blocks of `split_blocks`, found one at a time as they're consumed.
+pub fn blocks(input: &str) -> Blocks<'_> {
+    Blocks { input, offset: 0 }
+}
+
+/// The iterator of `blocks`.
+pub struct Blocks<'a> {
+    input: &'a str,
+    offset: usize
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.input;
+        let mut block_start: Option<usize> = None;
+        let mut block_end = 0;
+
+        for line in input[self.offset..].split_inclusive('\n') {
+            let offset = self.offset;
+            self.offset += line.len();
+            if line.trim().is_empty() {
+                if block_start.is_some() {
+                    break;
+                }
+            }
+            else {
+                block_start.get_or_insert(offset);
+                block_end = offset + line.trim_end_matches(['\r', '\n']).len();
+            }
+        }
+
+        block_start.map(|start| &input[start..block_end])
+    }
+}
+
+/// All the integers in `text`, whatever separates them, e.g. `[2, 18, -2]` out of `Sensor at
+/// x=2, y=18: beacon at x=-2`. A `-` is a sign unless it follows a letter or a digit, so `2-4`
+/// gives `[2, 4]`. Numbers that don't fit in `T` are left out.
+pub fn ints<T: FromStr>(text: &str) -> Vec<T> {
+    numbers(text, true)
+}
+
+/// Like `ints`, with every `-` taken as a separator.
+pub fn uints<T: FromStr>(text: &str) -> Vec<T> {
+    numbers(text, false)
+}
+
+fn numbers<T: FromStr>(text: &str, signed: bool) -> Vec<T> {
+    let bytes = text.as_bytes();
+    let mut numbers = vec![];
+    let mut end = 0;
+    while let Some(offset) = bytes[end..].iter().position(u8::is_ascii_digit) {
+        let mut start = end + offset;
+        end = start + bytes[start..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+
+        let sign = start.checked_sub(1).filter(|minus| bytes[*minus] == b'-');
+        if signed && sign.is_some_and(|minus| minus == 0 || !bytes[minus - 1].is_ascii_alphanumeric()) {
+            start -= 1;
+        }
+
+        numbers.extend(text[start..end].parse().ok());
+    }
+
+    numbers
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_blocks() {
+        assert_eq!(split_blocks("a\nb\n\nc\n"), vec!["a\nb", "c"]);
+    }
+
+    #[test]
+    fn test_multiple_blank_lines() {
+        assert_eq!(split_blocks("\n\na\n\n  \n\nb\nc"), vec!["a", "b\nc"]);
+    }
+
+    #[test]
+    fn test_crlf() {
+        assert_eq!(split_blocks("a\r\nb\r\n\r\nc\r\n"), vec!["a\r\nb", "c"]);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let mut blocks = blocks("a\n\nb\nc\n\n\nd");
+        assert_eq!(blocks.next(), Some("a"));
+        assert_eq!(blocks.next(), Some("b\nc"));
+        assert_eq!(blocks.next(), Some("d"));
+        assert_eq!(blocks.next(), None);
+        assert_eq!(blocks.next(), None);
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i64>("Sensor at x=2, y=18: closest beacon is at x=-2, y=-15"), vec![2, 18, -2, -15]);
+        assert_eq!(ints::<i32>("-3 -4,-5"), vec![-3, -4, -5]);
+        assert_eq!(ints::<i32>("2-4,6-8 a-1"), vec![2, 4, 6, 8, 1]);
+        assert_eq!(ints::<u8>("1 300 -2 3"), vec![1, 3]);
+        assert!(ints::<i64>("no numbers - here").is_empty());
+    }
+
+    #[test]
+    fn test_uints() {
+        assert_eq!(uints::<u64>("seeds: 79 14 55 13"), vec![79, 14, 55, 13]);
+        assert_eq!(uints::<u32>("x=-2,y=-15"), vec![2, 15]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(split_blocks("").is_empty());
+        assert!(split_blocks("\n\n").is_empty());
+    }
+}
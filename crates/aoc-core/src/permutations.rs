@@ -0,0 +1,165 @@
+//! Orderings and subsets of a few items, built one item at a time so that the hopeless ones are
+//! cut short, e.g. the order to visit cities in or the seating around a table.
+
+use alloc::{ vec, vec::Vec };
+
+/// Calls `visit` with every permutation of `items`. `prune` is first given every partial
+/// permutation, the empty one included, and cuts all of those it starts by returning `true`.
+pub fn for_each_permutation<'a, T>(items: &'a [T], mut prune: impl FnMut(&[&'a T]) -> bool, mut visit: impl FnMut(&[&'a T])) {
+    permute(items, &mut vec![false; items.len()], &mut vec![], &mut |prefix| {
+        if prune(prefix) {
+            return false;
+        }
+
+        if prefix.len() == items.len() {
+            visit(prefix);
+        }
+
+        true
+    });
+}
+
+/// Calls `visit` with every subset of `size` of the `items`, in the order they're in. `prune`
+/// works as for `for_each_permutation`.
+pub fn for_each_combination<'a, T>(items: &'a [T], size: usize, mut prune: impl FnMut(&[&'a T]) -> bool, mut visit: impl FnMut(&[&'a T])) {
+    choose(items, size, 0, &mut vec![], &mut |prefix| {
+        if prune(prefix) {
+            return false;
+        }
+
+        if prefix.len() == size {
+            visit(prefix);
+        }
+
+        true
+    });
+}
+
+/// The permutation of `items` of least `cost`, with that cost. `cost` is also given the partial
+/// permutations, and those already costing as much as the best one so far are cut, so adding an
+/// item must never lower the cost, as with the length of a route.
+pub fn min_permutation<T, C: Ord>(items: &[T], mut cost: impl FnMut(&[&T]) -> C) -> Option<(C, Vec<&T>)> {
+    let mut best: Option<(C, Vec<&T>)> = None;
+    permute(items, &mut vec![false; items.len()], &mut vec![], &mut |prefix| {
+        let cost = cost(prefix);
+        if best.as_ref().is_some_and(|(best, _)| cost >= *best) {
+            return false;
+        }
+
+        if prefix.len() == items.len() {
+            best = Some((cost, prefix.to_vec()));
+        }
+
+        true
+    });
+
+    best
+}
+
+/// The permutation of `items` of highest `cost`, with that cost. Nothing bounds the cost of
+/// what a partial permutation could become, so `cost` is only given whole ones, all of them.
+pub fn max_permutation<T, C: Ord>(items: &[T], mut cost: impl FnMut(&[&T]) -> C) -> Option<(C, Vec<&T>)> {
+    let mut best: Option<(C, Vec<&T>)> = None;
+    for_each_permutation(items, |_| false, |permutation| {
+        let cost = cost(permutation);
+        if best.as_ref().is_none_or(|(best, _)| cost > *best) {
+            best = Some((cost, permutation.to_vec()));
+        }
+    });
+
+    best
+}
+
+/// Extends `prefix` with every item not `used` yet, depth first, as long as `explore` returns
+/// `true` for it.
+fn permute<'a, T>(items: &'a [T], used: &mut [bool], prefix: &mut Vec<&'a T>, explore: &mut impl FnMut(&[&'a T]) -> bool) {
+    if !explore(prefix) || prefix.len() == items.len() {
+        return;
+    }
+
+    for index in 0..items.len() {
+        if !used[index] {
+            used[index] = true;
+            prefix.push(&items[index]);
+            permute(items, used, prefix, explore);
+            prefix.pop();
+            used[index] = false;
+        }
+    }
+}
+
+/// Extends `prefix` with the items from `from` on, as long as enough are left to reach `size`.
+fn choose<'a, T>(items: &'a [T], size: usize, from: usize, prefix: &mut Vec<&'a T>, explore: &mut impl FnMut(&[&'a T]) -> bool) {
+    if !explore(prefix) || prefix.len() == size {
+        return;
+    }
+
+    for index in from..items.len() {
+        if items.len() - index < size - prefix.len() {
+            break;
+        }
+
+        prefix.push(&items[index]);
+        choose(items, size, index + 1, prefix, explore);
+        prefix.pop();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn test_permutations() {
+        let mut all = vec![];
+        for_each_permutation(&[1, 2, 3], |_| false, |permutation| all.push(permutation.to_vec()));
+        assert_eq!(all, vec![[&1, &2, &3], [&1, &3, &2], [&2, &1, &3], [&2, &3, &1], [&3, &1, &2], [&3, &2, &1]]);
+
+        // No two neighbours next to each other
+        let items = [1, 2, 3, 4, 5];
+        let apart = |order: &[&i32]| order.windows(2).all(|pair| pair[0].abs_diff(*pair[1]) > 1);
+        let (mut count, mut prefixes) = (0, 0);
+        for_each_permutation(&items, |prefix| {
+            prefixes += 1;
+            !apart(prefix)
+        }, |_| count += 1);
+        assert_eq!(count, 14);
+        assert!(prefixes < 120);
+
+        let mut empty = 0;
+        for_each_permutation(&[] as &[i32], |_| false, |permutation| empty += permutation.len() + 1);
+        assert_eq!(empty, 1);
+    }
+
+    #[test]
+    fn test_combinations() {
+        let mut all = vec![];
+        for_each_combination(&['a', 'b', 'c', 'd'], 2, |_| false, |combination| all.push(combination.iter().copied().collect::<String>()));
+        assert_eq!(all, vec!["ab", "ac", "ad", "bc", "bd", "cd"]);
+
+        let mut small = 0;
+        for_each_combination(&[1, 2, 3, 4, 5], 2, |prefix| prefix.iter().copied().sum::<i32>() > 5, |_| small += 1);
+        assert_eq!(small, 4);
+
+        for_each_combination(&[1, 2], 3, |_| false, |_| panic!("Not enough items"));
+    }
+
+    #[test]
+    fn test_min_max_permutation() {
+        // Routes through every city, from any to any
+        let distances = [[0, 7, 2, 9], [7, 0, 3, 4], [2, 3, 0, 8], [9, 4, 8, 0]];
+        let length = |route: &[&usize]| route.windows(2).map(|leg| distances[*leg[0]][*leg[1]]).sum::<u32>();
+        let cities = [0, 1, 2, 3];
+
+        let (shortest, route) = min_permutation(&cities, length).unwrap();
+        assert_eq!(shortest, 9);
+        assert_eq!(length(&route), shortest);
+
+        let (longest, route) = max_permutation(&cities, length).unwrap();
+        assert_eq!(longest, 24);
+        assert_eq!(length(&route), longest);
+
+        assert_eq!(min_permutation(&[] as &[usize], length), Some((0, vec![])));
+    }
+}
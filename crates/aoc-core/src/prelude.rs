@@ -0,0 +1,16 @@
+//! What most days need, so a day can start with `use crate::prelude::*;` and only import the
+//! helpers specific to it on top.
+
+pub use crate::answer::Answer;
+pub use crate::collections::{ HashMap, HashSet };
+pub use crate::counter::Counter;
+pub use crate::geometry::{ Diamond, Direction, Direction8, Hex, HexDirection, Rect, Segment, Vec2, Vec3, Walker };
+pub use crate::grid::{ self, Grid, PrefixSum2D };
+pub use crate::intervals::{ Interval, IntervalMap, IntervalSet };
+pub use crate::memo::Memo;
+pub use crate::parsing::{ blocks, ints, split_blocks, uints };
+pub use crate::privacy::Excerpt;
+pub use crate::solution::{ self, solve_parts, Answers, Error as SolutionError, Metadata, Parts, RuntimeTier, Solution, Solver, Stages };
+pub use itertools::Itertools;
+pub use lazy_static::lazy_static;
+pub use regex::Regex;
@@ -0,0 +1,91 @@
+//! Keeping puzzle inputs out of what the tooling prints. Inputs aren't meant to be
+//! redistributed, so with privacy on, reports only name an input by its hash, and the pieces of
+//! input quoted in errors are anonymized. What's printed is then safe to share publicly.
+
+use crate::cache;
+use std::fmt::{ self, Debug, Display };
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns privacy on or off for the whole process. It's off by default.
+pub fn enable(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Replaces letters with `x` or `X` and digits with `0`, keeping everything else. The shape of
+/// the text survives, which is usually enough to tell why it didn't parse.
+pub fn anonymize(text: &str) -> String {
+    text.chars()
+        .map(|ch| match ch {
+            'a'..='z' => 'x',
+            'A'..='Z' => 'X',
+            '0'..='9' => '0',
+            ch => ch
+        })
+        .collect()
+}
+
+/// How an input is named in reports: the hash the caches key it by.
+pub fn input_reference(input: &str) -> String {
+    format!("input {:016x}", cache::input_hash(input))
+}
+
+/// A piece of the puzzle input quoted by an error, displayed anonymized when privacy is on.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Excerpt(String);
+
+impl Excerpt {
+    /// The excerpt as it was in the input, regardless of privacy.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Excerpt {
+    fn from(text: &str) -> Excerpt {
+        Excerpt(text.to_string())
+    }
+}
+
+impl From<String> for Excerpt {
+    fn from(text: String) -> Excerpt {
+        Excerpt(text)
+    }
+}
+
+impl Display for Excerpt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match is_enabled() {
+            true => write!(f, "{}", anonymize(&self.0)),
+            false => write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl Debug for Excerpt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Excerpt({:?})", self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_anonymize() {
+        assert_eq!(anonymize("Valve AA has flow rate=13; tunnels lead to valves DD, II"), "Xxxxx XX xxx xxxx xxxx=00; xxxxxxx xxxx xx xxxxxx XX, XX");
+        assert_eq!(anonymize("[[1],[2,3]] -> 498,4"), "[[0],[0,0]] -> 000,0");
+    }
+
+    #[test]
+    fn test_input_reference() {
+        assert_eq!(input_reference("abc"), format!("input {:016x}", cache::input_hash("abc")));
+        assert!(!input_reference("secret").contains("secret"));
+    }
+}
@@ -0,0 +1,117 @@
+/// A cursor over a string for hand-written tokenizers. Every method only consumes input when it
+/// succeeds, and `attempt` rewinds a whole failed sequence, so there's no backtracking beyond
+/// what the caller asks for.
+#[derive(Clone, Debug)]
+pub struct Scanner<'a> {
+    input: &'a str,
+    position: usize
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a str) -> Scanner<'a> {
+        Scanner { input, position: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.position >= self.input.len()
+    }
+
+    /// The input that hasn't been consumed yet.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    /// Consumes and returns the next character.
+    pub fn next_char(&mut self) -> Option<char> {
+        let ch = self.rest().chars().next()?;
+        self.position += ch.len_utf8();
+        Some(ch)
+    }
+
+    /// Consumes `literal` if the input continues with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        let matches = self.rest().starts_with(literal);
+        if matches {
+            self.position += literal.len();
+        }
+
+        matches
+    }
+
+    /// Consumes an unsigned number of 1 to `max_digits` digits. Fails if there are no digits or
+    /// more than `max_digits` of them.
+    pub fn number(&mut self, max_digits: usize) -> Option<u64> {
+        let digits = self.rest().bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 || digits > max_digits {
+            return None;
+        }
+
+        let value = self.rest()[..digits].parse().ok()?;
+        self.position += digits;
+        Some(value)
+    }
+
+    /// Runs `parse` and keeps what it consumed only if it succeeds.
+    pub fn attempt<T>(&mut self, parse: impl FnOnce(&mut Scanner<'a>) -> Option<T>) -> Option<T> {
+        let start = self.position;
+        let result = parse(self);
+        if result.is_none() {
+            self.position = start;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_eat() {
+        let mut scanner = Scanner::new("abc");
+        assert!(!scanner.eat("b"));
+        assert!(scanner.eat("ab"));
+        assert_eq!(scanner.rest(), "c");
+        assert_eq!(scanner.next_char(), Some('c'));
+        assert!(scanner.is_at_end());
+        assert_eq!(scanner.next_char(), None);
+    }
+
+    #[test]
+    fn test_number() {
+        let mut scanner = Scanner::new("1234,12x");
+        assert_eq!(scanner.number(3), None);
+        assert_eq!(scanner.position(), 0);
+        assert_eq!(scanner.number(4), Some(1234));
+        assert_eq!(scanner.number(4), None);
+        assert!(scanner.eat(","));
+        assert_eq!(scanner.number(2), Some(12));
+    }
+
+    #[test]
+    fn test_attempt_rewinds() {
+        let mut scanner = Scanner::new("add(1,x)");
+        let pair = |scanner: &mut Scanner| {
+            scanner.eat("add(").then_some(())?;
+            let left = scanner.number(3)?;
+            scanner.eat(",").then_some(())?;
+            Some((left, scanner.number(3)?))
+        };
+
+        assert_eq!(scanner.attempt(pair), None);
+        assert_eq!(scanner.position(), 0);
+        assert_eq!(Scanner::new("add(1,2)").attempt(pair), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_multibyte() {
+        let mut scanner = Scanner::new("é1");
+        assert_eq!(scanner.next_char(), Some('é'));
+        assert_eq!(scanner.number(1), Some(1));
+    }
+}
@@ -0,0 +1,69 @@
+/// Returns the lexicographically largest subsequence of `items` with exactly `length` elements,
+/// keeping their original relative order. Returns None if `items` has less than `length` elements.
+///
+/// Uses the classic monotonic stack approach, so it runs in O(n) regardless of `length`:
+/// an element is popped whenever a larger one comes along and there are still enough
+/// elements left to fill the subsequence.
+pub fn largest_subsequence<T>(items: &[T], length: usize) -> Option<Vec<T>>
+where T: Ord + Copy
+{
+    if items.len() < length {
+        return None;
+    }
+
+    let mut removable = items.len() - length;
+    let mut stack: Vec<T> = Vec::with_capacity(items.len());
+
+    for &item in items {
+        while removable > 0 && stack.last().is_some_and(|&top| top < item) {
+            stack.pop();
+            removable -= 1;
+        }
+
+        stack.push(item);
+    }
+
+    stack.truncate(length);
+    Some(stack)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_takes_largest_digits() {
+        let digits = [9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1];
+        assert_eq!(largest_subsequence(&digits, 2).unwrap(), vec![9, 8]);
+    }
+
+    #[test]
+    fn test_keeps_order() {
+        let digits = [8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9];
+        assert_eq!(largest_subsequence(&digits, 2).unwrap(), vec![8, 9]);
+    }
+
+    #[test]
+    fn test_leaves_room_for_remaining_elements() {
+        let digits = [2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8];
+        assert_eq!(largest_subsequence(&digits, 12).unwrap(), vec![4, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8]);
+    }
+
+    #[test]
+    fn test_equal_elements() {
+        let digits = [1, 1, 1];
+        assert_eq!(largest_subsequence(&digits, 2).unwrap(), vec![1, 1]);
+    }
+
+    #[test]
+    fn test_whole_and_empty() {
+        let digits = [3, 1, 2];
+        assert_eq!(largest_subsequence(&digits, 3).unwrap(), vec![3, 1, 2]);
+        assert!(largest_subsequence(&digits, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert!(largest_subsequence(&[1, 2], 3).is_none());
+    }
+}
@@ -0,0 +1,362 @@
+use crate::{ answer::Answer, date::PuzzleDate };
+use std::any::Any;
+use std::sync::{ atomic::{ AtomicBool, Ordering }, Arc, Mutex };
+
+/// Errors are specific to each day, so solutions report them boxed.
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// The answers to both parts of a puzzle.
+pub type Answers = (Answer, Answer);
+
+/// A rough idea of how long a solution takes on a real input, in release mode.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RuntimeTier {
+    /// Under 10 milliseconds
+    Instant,
+    /// Under a second
+    Fast,
+    /// A second or more
+    Slow
+}
+
+/// Solutions that can spread their work over several cores offer it as this strategy, next to
+/// `SERIAL`, so that the runner can pick one.
+pub const PARALLEL: &str = "parallel";
+pub const SERIAL: &str = "serial";
+
+/// Everything there is to know about a solution, short of running it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Metadata {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub tags: &'static [&'static str],
+    pub runtime: RuntimeTier,
+    /// Alternative algorithms that can be selected with `solve_with_strategy`. The first one is
+    /// what `solve` uses.
+    pub strategies: &'static [&'static str]
+}
+
+impl Metadata {
+    /// The date of the puzzle. Solutions must be for a valid date, which registering checks.
+    pub fn date(&self) -> PuzzleDate {
+        PuzzleDate::new(self.year, self.day).expect("Solutions have a valid date")
+    }
+}
+
+/// Asks a running solution to stop early. Clones share the same request.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Fails once cancelled, for solutions to bail out with `?`.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        match self.is_cancelled() {
+            true => Err(Cancelled),
+            false => Ok(())
+        }
+    }
+}
+
+/// The error of a solution that stopped because its `CancellationToken` was cancelled.
+#[derive(thiserror::Error, Debug)]
+#[error("Cancelled")]
+pub struct Cancelled;
+
+/// The best answers a solution found so far while it's still running, and whether it should
+/// stop. See `solve_anytime`.
+#[derive(Default)]
+pub struct Progress {
+    best: Mutex<[Option<Answer>; 2]>,
+    cancellation: CancellationToken
+}
+
+impl Progress {
+    pub fn new(cancellation: CancellationToken) -> Progress {
+        Progress { best: Mutex::default(), cancellation }
+    }
+
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Records a better answer to `part`, 1 or 2, replacing the previous best.
+    pub fn improve(&self, part: usize, answer: impl Into<Answer>) {
+        self.best.lock().unwrap()[part - 1] = Some(answer.into());
+    }
+
+    pub fn best(&self) -> [Option<Answer>; 2] {
+        self.best.lock().unwrap().clone()
+    }
+}
+
+pub trait Solution {
+    fn metadata(&self) -> Metadata;
+
+    fn solve(&self, input: &str) -> Result<Answers, Error>;
+
+    fn solve_with_strategy(&self, input: &str, strategy: &str) -> Result<Answers, Error> {
+        match self.metadata().strategies.first() {
+            Some(default) if *default == strategy => self.solve(input),
+            _ => Err(format!("Unknown strategy: {strategy}").into())
+        }
+    }
+
+    /// Solves like `solve`, recording the answers in `progress` as they improve, so that a run
+    /// cut short still has something to show. Long running and heuristic solutions override
+    /// this, and stop with `Cancelled` once `progress.cancellation()` asks them to. The default
+    /// only knows the answers at the end, and can't be cancelled.
+    fn solve_anytime(&self, input: &str, _progress: &Progress) -> Result<Answers, Error> {
+        self.solve(input)
+    }
+
+    /// Solves an input that is still being read, one line at a time. Solutions that can parse
+    /// line by line override this to overlap their work with the IO, the default just waits
+    /// for the whole input.
+    fn solve_lines(&self, lines: &mut dyn Iterator<Item = String>) -> Result<Answers, Error> {
+        let input: String = lines.flat_map(|line| [line, "\n".to_string()]).collect();
+        self.solve(&input)
+    }
+
+    /// The parsing and the two parts, to be run on their own, e.g. to time them separately.
+    /// `Solver`s return themselves, other solutions can't be split.
+    fn stages(&self) -> Option<&dyn Stages> {
+        None
+    }
+}
+
+/// Where the crate of each year adds its solutions, i.e. the registry of the runner.
+pub trait Register {
+    /// Adds a solution, replacing any previous one for the same date.
+    fn register(&mut self, solution: Box<dyn Solution + Send + Sync>);
+}
+
+/// A solution split into its two parts, for puzzles where part 2 builds on what part 1 found,
+/// e.g. the parsed input or a first pass over it. Part 1 hands that over as `Shared`, so part
+/// 2 doesn't compute it again. `Solution::solve` then just calls `solve_parts`.
+pub trait Parts {
+    /// What part 1 computes on the way that part 2 needs too
+    type Shared;
+
+    fn part_1(&self, input: &str) -> Result<(Answer, Self::Shared), Error>;
+
+    fn part_2(&self, input: &str, shared: Self::Shared) -> Result<Answer, Error>;
+}
+
+/// Solves both parts, part 2 reusing what part 1 shared.
+pub fn solve_parts(parts: &impl Parts, input: &str) -> Result<Answers, Error> {
+    let (solution_1, shared) = parts.part_1(input)?;
+    Ok((solution_1, parts.part_2(input, shared)?))
+}
+
+/// The shape most solutions have: the input is parsed once, then each part is answered from
+/// what was parsed. Solvers are `Parts` sharing the parsed input, so `Solution::solve` can
+/// just call `solve_parts`.
+pub trait Solver {
+    type Parsed;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, Error>;
+
+    fn part_1(&self, parsed: &Self::Parsed) -> Result<Answer, Error>;
+
+    fn part_2(&self, parsed: &Self::Parsed) -> Result<Answer, Error>;
+}
+
+impl<S> Parts for S
+where S: Solver
+{
+    type Shared = S::Parsed;
+
+    fn part_1(&self, input: &str) -> Result<(Answer, Self::Shared), Error> {
+        let parsed = self.parse(input)?;
+        Ok((Solver::part_1(self, &parsed)?, parsed))
+    }
+
+    fn part_2(&self, _input: &str, parsed: Self::Shared) -> Result<Answer, Error> {
+        Solver::part_2(self, &parsed)
+    }
+}
+
+/// A `Solver` with the parsed input type-erased, so that its stages can be reached through
+/// `Solution::stages`.
+pub trait Stages {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error>;
+
+    /// Fails if `parsed` didn't come from `parse`.
+    fn part_1(&self, parsed: &dyn Any) -> Result<Answer, Error>;
+
+    fn part_2(&self, parsed: &dyn Any) -> Result<Answer, Error>;
+}
+
+impl<S> Stages for S
+where S: Solver, S::Parsed: 'static
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, Error> {
+        Ok(Box::new(Solver::parse(self, input)?))
+    }
+
+    fn part_1(&self, parsed: &dyn Any) -> Result<Answer, Error> {
+        Solver::part_1(self, downcast_parsed::<S>(parsed)?)
+    }
+
+    fn part_2(&self, parsed: &dyn Any) -> Result<Answer, Error> {
+        Solver::part_2(self, downcast_parsed::<S>(parsed)?)
+    }
+}
+
+fn downcast_parsed<S>(parsed: &dyn Any) -> Result<&S::Parsed, Error>
+where S: Solver, S::Parsed: 'static
+{
+    parsed.downcast_ref().ok_or_else(|| "The parsed input is from another solution".into())
+}
+
+/// Answers part 1 alone. Solutions that can be run stage by stage skip part 2, the others
+/// solve both parts anyway.
+pub fn solve_part_1(solution: &dyn Solution, input: &str) -> Result<Answer, Error> {
+    match solution.stages() {
+        Some(stages) => stages.part_1(stages.parse(input)?.as_ref()),
+        None => Ok(solution.solve(input)?.0)
+    }
+}
+
+/// Answers part 2 alone, see `solve_part_1`.
+pub fn solve_part_2(solution: &dyn Solution, input: &str) -> Result<Answer, Error> {
+    match solution.stages() {
+        Some(stages) => stages.part_2(stages.parse(input)?.as_ref()),
+        None => Ok(solution.solve(input)?.1)
+    }
+}
+
+/// Solves the puzzle for the input at `path`, printing both answers or the error, and exiting
+/// with status 1 on error. With the `download` feature a missing input is downloaded to `path`
+/// first. `--input <path>` on the command line solves another file instead, e.g. an example.
+#[cfg(feature = "fs")]
+pub fn run_from_file(solution: &dyn Solution, path: &str) {
+    let answers = read_input(solution, path, std::env::args().skip(1)).and_then(|input| solution.solve(&input));
+
+    match answers {
+        Ok((solution_1, solution_2)) => {
+            println!("Solution 1: {solution_1}");
+            println!("Solution 2: {solution_2}");
+        },
+        Err(err) => {
+            println!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// The input `run_from_file` solves, for the day binaries that do more than solving: the file
+/// given with `--input` among the `arguments`, or else the one at `path`.
+#[cfg(feature = "fs")]
+#[cfg_attr(not(feature = "download"), allow(unused_variables))]
+pub fn read_input(solution: &dyn Solution, path: &str, arguments: impl Iterator<Item = String>) -> Result<String, Error> {
+    match input_argument(arguments)? {
+        Some(other) => std::fs::read_to_string(&other).map_err(|err| Error::from(format!("Failed to read {other}: {err}"))),
+        None => match std::fs::read_to_string(path) {
+            #[cfg(feature = "download")]
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => crate::inputs::fetch_to(solution.metadata().date(), path.as_ref()),
+            input => input.map_err(|err| Error::from(format!("Failed to read input: {err}")))
+        }
+    }
+}
+
+/// The path given with `--input <path>` or `--input=<path>` among the `arguments` of a day's
+/// binary, if any.
+#[cfg(feature = "fs")]
+fn input_argument(mut arguments: impl Iterator<Item = String>) -> Result<Option<String>, Error> {
+    match arguments.next().as_deref() {
+        None => Ok(None),
+        Some("--input") => match (arguments.next(), arguments.next()) {
+            (Some(path), None) => Ok(Some(path)),
+            (None, _) => Err("--input needs a path".into()),
+            (Some(_), Some(extra)) => Err(format!("Unexpected argument: {extra}").into())
+        },
+        Some(argument) => match (argument.strip_prefix("--input="), arguments.next()) {
+            (Some(path), None) => Ok(Some(path.to_string())),
+            (Some(_), Some(extra)) => Err(format!("Unexpected argument: {extra}").into()),
+            (None, _) => Err(format!("Unexpected argument: {argument}, the only one is --input <path>").into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Sums the numbers of the input, and can't do part 2.
+    struct Sum;
+
+    impl Solver for Sum {
+        type Parsed = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Self::Parsed, Error> {
+            Ok(input.split_whitespace().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part_1(&self, parsed: &Self::Parsed) -> Result<Answer, Error> {
+            Ok(parsed.iter().sum::<i64>().into())
+        }
+
+        fn part_2(&self, _parsed: &Self::Parsed) -> Result<Answer, Error> {
+            Err("Part 2 isn't solved".into())
+        }
+    }
+
+    impl Solution for Sum {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2022, day: 1, title: "Sum", tags: &[], runtime: RuntimeTier::Instant, strategies: &[] }
+        }
+
+        fn solve(&self, input: &str) -> Result<Answers, Error> {
+            solve_parts(self, input)
+        }
+
+        fn stages(&self) -> Option<&dyn Stages> {
+            Some(self)
+        }
+    }
+
+    #[test]
+    fn test_solve_part() {
+        assert_eq!(solve_part_1(&Sum, "1 2 39\n").unwrap(), 42.into());
+        assert!(solve_part_2(&Sum, "1 2 39\n").is_err());
+        assert!(Sum.solve("1 2 39\n").is_err());
+    }
+
+    #[cfg(feature = "fs")]
+    fn arguments(arguments: &[&str]) -> impl Iterator<Item = String> {
+        arguments.iter().map(|argument| argument.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_input_argument() {
+        assert_eq!(input_argument(arguments(&[])).unwrap(), None);
+        assert_eq!(input_argument(arguments(&["--input", "example.txt"])).unwrap(), Some("example.txt".to_string()));
+        assert_eq!(input_argument(arguments(&["--input=example.txt"])).unwrap(), Some("example.txt".to_string()));
+        assert!(input_argument(arguments(&["--input"])).is_err());
+        assert!(input_argument(arguments(&["--input", "example.txt", "other.txt"])).is_err());
+        assert!(input_argument(arguments(&["example.txt"])).is_err());
+    }
+
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_read_input() {
+        let path = std::env::temp_dir().join(format!("aoc_read_input_{}.txt", std::process::id()));
+        std::fs::write(&path, "1 2 39\n").unwrap();
+        let other = path.display().to_string();
+        assert_eq!(read_input(&Sum, "missing.txt", arguments(&["--input", &other])).unwrap(), "1 2 39\n");
+        assert_eq!(read_input(&Sum, &other, arguments(&[])).unwrap(), "1 2 39\n");
+        assert!(read_input(&Sum, &other, arguments(&["--visualize"])).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,173 @@
+use crate::collections::{ hash_map, HashMap };
+use crate::geometry::{ Direction, Direction8, Vec2 };
+
+/// An unbounded grid that only stores the occupied cells. The bounding box of every
+/// position ever inserted is tracked, so that the grid can be rendered.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2, T>,
+    bounds: Option<(Vec2, Vec2)>
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::default(), bounds: None }
+    }
+
+    /// The cells of a map where `parse_cell` returns a value, with rows growing downwards as in
+    /// `Grid`. The bounds cover the whole map, so that it renders back as it was.
+    pub fn parse(input: &str, mut parse_cell: impl FnMut(char) -> Option<T>) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        for (y, row) in input.lines().enumerate() {
+            for (x, ch) in row.trim_end().chars().enumerate() {
+                let position = Vec2::new(x as i64, y as i64);
+                grid.include(position);
+                if let Some(value) = parse_cell(ch) {
+                    grid.cells.insert(position, value);
+                }
+            }
+        }
+
+        grid
+    }
+
+    pub fn insert(&mut self, position: Vec2, value: T) -> Option<T> {
+        self.include(position);
+        self.cells.insert(position, value)
+    }
+
+    /// Removes the value at `position`. Bounds are not shrunk.
+    pub fn remove(&mut self, position: Vec2) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    /// Grows the bounds so that they include `position`, without inserting anything.
+    pub fn include(&mut self, position: Vec2) {
+        self.bounds = Some(match self.bounds {
+            None => (position, position),
+            Some((min, max)) => (
+                Vec2::new(min.x.min(position.x), min.y.min(position.y)),
+                Vec2::new(max.x.max(position.x), max.y.max(position.y))
+            )
+        });
+    }
+
+    pub fn get(&self, position: Vec2) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: Vec2) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    pub fn contains(&self, position: Vec2) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The inclusive `(min, max)` corners of the bounding box, if anything was ever inserted.
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, Vec2, T> {
+        self.cells.iter()
+    }
+
+    /// The four orthogonally adjacent positions, occupied or not.
+    pub fn neighbors(position: Vec2) -> impl Iterator<Item = Vec2> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| position + direction.offset())
+    }
+
+    /// The eight adjacent positions, diagonals included, occupied or not.
+    pub fn neighbors8(position: Vec2) -> impl Iterator<Item = Vec2> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| position + direction.offset())
+    }
+
+    /// Renders the bounding box row by row, converting every position with `draw`.
+    pub fn render(&self, mut draw: impl FnMut(Vec2, Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds
+        else {
+            return String::new();
+        };
+
+        let mut output = String::new();
+        for y in min.y..=max.y {
+            output.extend((min.x..=max.x).map(|x| draw(Vec2::new(x, y), self.get(Vec2::new(x, y)))));
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+impl<T> FromIterator<(Vec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (position, value) in iter {
+            grid.insert(position, value);
+        }
+
+        grid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert(Vec2::new(2, 3), 'a');
+        grid.insert(Vec2::new(-1, 5), 'b');
+        assert_eq!(grid.bounds(), Some((Vec2::new(-1, 3), Vec2::new(2, 5))));
+
+        assert_eq!(grid.remove(Vec2::new(-1, 5)), Some('b'));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.bounds(), Some((Vec2::new(-1, 3), Vec2::new(2, 5))));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Vec2::new(0, 0), '#');
+        grid.insert(Vec2::new(2, 1), '#');
+        assert_eq!(grid.render(|_, cell| cell.copied().unwrap_or('.')), "#..\n..#\n");
+    }
+
+    #[test]
+    fn test_parse() {
+        let elves = SparseGrid::parse("..#\n#..\n...\n", |ch| (ch == '#').then_some(()));
+        assert_eq!(elves.len(), 2);
+        assert!(elves.contains(Vec2::new(2, 0)) && elves.contains(Vec2::new(0, 1)));
+        assert_eq!(elves.bounds(), Some((Vec2::ZERO, Vec2::new(2, 2))));
+        assert_eq!(elves.render(|_, elf| if elf.is_some() { '#' } else { '.' }), "..#\n#..\n...\n");
+
+        let collected: SparseGrid<()> = elves.iter().map(|(position, _)| (*position, ())).collect();
+        assert_eq!(collected.bounds(), Some((Vec2::new(0, 0), Vec2::new(2, 1))));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let neighbors: Vec<_> = SparseGrid::<()>::neighbors(Vec2::ZERO).collect();
+        assert_eq!(neighbors, vec![Vec2::new(0, -1), Vec2::new(1, 0), Vec2::new(0, 1), Vec2::new(-1, 0)]);
+
+        let neighbors: Vec<_> = SparseGrid::<()>::neighbors8(Vec2::new(1, 1)).collect();
+        assert_eq!(neighbors.len(), 8);
+        assert!(neighbors.iter().all(|neighbor| neighbor.chebyshev_distance(Vec2::new(1, 1)) == 1));
+    }
+}
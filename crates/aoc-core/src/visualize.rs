@@ -0,0 +1,97 @@
+use crate::solution::CancellationToken;
+use std::{ io::{ self, Write }, thread, time::Duration };
+
+/// A simulation that advances one discrete step at a time.
+pub trait Simulation {
+    /// Advances the simulation by one step. Returns `false` once nothing changes anymore.
+    fn step(&mut self) -> bool;
+
+    /// Steps until the simulation is over, returning the number of steps that made progress.
+    fn run(&mut self) -> usize {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+
+        steps
+    }
+
+    /// The state after every step that made progress, e.g. to draw them at leisure.
+    fn states(&mut self) -> impl Iterator<Item = Self> + '_
+    where Self: Clone + Sized
+    {
+        std::iter::from_fn(move || self.step().then(|| self.clone()))
+    }
+}
+
+/// Something that can draw its current state as a text frame.
+pub trait Visualize {
+    fn render(&self) -> String;
+}
+
+/// Runs `simulation` to completion, redrawing a frame on `output` after every step. Stops early
+/// once `cancellation` is cancelled, leaving the last complete frame on `output`.
+pub fn animate<S>(simulation: &mut S, output: &mut impl Write, frame_delay: Duration, cancellation: &CancellationToken) -> io::Result<usize>
+where S: Simulation + Visualize
+{
+    const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+    let mut steps = 0;
+    write!(output, "{CLEAR_SCREEN}{}", simulation.render())?;
+    output.flush()?;
+    while !cancellation.is_cancelled() && simulation.step() {
+        steps += 1;
+        write!(output, "{CLEAR_SCREEN}{}", simulation.render())?;
+        output.flush()?;
+        thread::sleep(frame_delay);
+    }
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Countdown(usize);
+
+    impl Simulation for Countdown {
+        fn step(&mut self) -> bool {
+            if self.0 == 0 {
+                return false;
+            }
+
+            self.0 -= 1;
+            true
+        }
+    }
+
+    impl Visualize for Countdown {
+        fn render(&self) -> String {
+            format!("{}\n", self.0)
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(Countdown(3).run(), 3);
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut output = vec![];
+        assert_eq!(animate(&mut Countdown(2), &mut output, Duration::ZERO, &CancellationToken::default()).unwrap(), 2);
+
+        let frames = String::from_utf8(output).unwrap();
+        assert_eq!(frames.matches("\x1b[2J").count(), 3);
+        assert!(frames.ends_with("0\n"));
+    }
+
+    #[test]
+    fn test_animate_cancelled() {
+        let (mut output, cancellation) = (vec![], CancellationToken::default());
+        cancellation.cancel();
+        assert_eq!(animate(&mut Countdown(2), &mut output, Duration::ZERO, &cancellation).unwrap(), 0);
+        assert!(String::from_utf8(output).unwrap().ends_with("2\n"));
+    }
+}
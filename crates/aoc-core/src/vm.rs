@@ -0,0 +1,353 @@
+use crate::privacy::Excerpt;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("Unknown instruction at line {0}: {1}")]
+    UnknownInstruction(usize, Excerpt)
+}
+
+pub type Register = usize;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Registers {
+    values: Vec<i64>
+}
+
+impl Registers {
+    pub fn new(count: usize) -> Registers {
+        Registers { values: vec![0; count] }
+    }
+
+    pub fn get(&self, register: Register) -> i64 {
+        self.values[register]
+    }
+
+    pub fn set(&mut self, register: Register, value: i64) {
+        self.values[register] = value;
+    }
+
+    pub fn get_mut(&mut self, register: Register) -> &mut i64 {
+        &mut self.values[register]
+    }
+
+    pub fn as_slice(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+/// An instruction argument that is either read from a register or given inline.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Immediate(i64)
+}
+
+impl Operand {
+    pub fn value(&self, registers: &Registers) -> i64 {
+        match self {
+            Operand::Register(register) => registers.get(*register),
+            Operand::Immediate(value) => *value
+        }
+    }
+}
+
+/// What the machine should do after executing an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Effect {
+    Next,
+    Jump(i64),
+    Output(i64),
+    Halt
+}
+
+pub trait Instruction {
+    fn execute(&self, registers: &mut Registers) -> Effect;
+
+    /// How many clock cycles the instruction takes to complete.
+    fn cycles(&self) -> usize { 1 }
+}
+
+pub trait Decode: Sized {
+    fn decode(line: &str) -> Option<Self>;
+}
+
+/// Decodes one instruction per non-empty line of `source`.
+pub fn decode_program<I: Decode>(source: &str) -> Result<Vec<I>, Error> {
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| I::decode(line.trim()).ok_or(Error::UnknownInstruction(idx + 1, line.into())))
+        .collect()
+}
+
+/// Machine state observed by trace hooks. `registers` hold the values *during* `cycle`,
+/// i.e. before the instruction at `pc` has completed.
+pub struct TraceEvent<'a> {
+    pub cycle: usize,
+    pub pc: usize,
+    pub registers: &'a Registers
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    Output(i64),
+    Halted
+}
+
+pub struct Machine<I> {
+    program: Vec<I>,
+    registers: Registers,
+    pc: usize,
+    cycle: usize,
+    output: Vec<i64>,
+    break_on_output: bool,
+    halted: bool
+}
+
+impl<I: Instruction> Machine<I> {
+    pub fn new(program: Vec<I>, register_count: usize) -> Machine<I> {
+        Machine {
+            program,
+            registers: Registers::new(register_count),
+            pc: 0,
+            cycle: 0,
+            output: vec![],
+            break_on_output: false,
+            halted: false
+        }
+    }
+
+    /// When enabled, `run` returns as soon as an instruction produces an output.
+    pub fn with_break_on_output(mut self, enabled: bool) -> Machine<I> {
+        self.break_on_output = enabled;
+        self
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut Registers {
+        &mut self.registers
+    }
+
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn output(&self) -> &[i64] {
+        &self.output
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn step(&mut self) -> Status {
+        self.step_traced(|_| {})
+    }
+
+    /// Executes a single instruction, calling `trace` once for every clock cycle it takes.
+    pub fn step_traced(&mut self, mut trace: impl FnMut(&TraceEvent)) -> Status {
+        let instruction = match self.program.get(self.pc) {
+            Some(instruction) if !self.halted => instruction,
+            _ => {
+                self.halted = true;
+                return Status::Halted;
+            }
+        };
+
+        for _ in 0..instruction.cycles() {
+            self.cycle += 1;
+            trace(&TraceEvent { cycle: self.cycle, pc: self.pc, registers: &self.registers });
+        }
+
+        let (next_pc, status) = match instruction.execute(&mut self.registers) {
+            Effect::Next => (self.pc.checked_add(1), Status::Running),
+            Effect::Jump(offset) => (self.pc.checked_add_signed(offset as isize), Status::Running),
+            Effect::Output(value) => {
+                self.output.push(value);
+                (self.pc.checked_add(1), Status::Output(value))
+            },
+            Effect::Halt => (None, Status::Halted)
+        };
+
+        match next_pc {
+            Some(next_pc) if next_pc < self.program.len() => {
+                self.pc = next_pc;
+                status
+            },
+            _ => {
+                self.halted = true;
+                match status {
+                    Status::Output(value) => Status::Output(value),
+                    _ => Status::Halted
+                }
+            }
+        }
+    }
+
+    pub fn run(&mut self) -> Status {
+        self.run_traced(|_| {})
+    }
+
+    /// Runs until the program halts or, if enabled, until the next output breakpoint.
+    pub fn run_traced(&mut self, mut trace: impl FnMut(&TraceEvent)) -> Status {
+        loop {
+            match self.step_traced(&mut trace) {
+                Status::Running => continue,
+                Status::Output(value) if self.break_on_output => return Status::Output(value),
+                Status::Output(_) if !self.halted => continue,
+                _ => return Status::Halted
+            }
+        }
+    }
+
+    /// Runs until the program halts or `stop` holds before the next instruction, e.g. once it
+    /// comes back to one it already ran, for programs that loop forever. Returns `Running` if
+    /// stopped. Outputs don't stop it.
+    pub fn run_until(&mut self, mut stop: impl FnMut(&Machine<I>) -> bool) -> Status {
+        while !self.halted {
+            if stop(self) {
+                return Status::Running;
+            }
+
+            self.step();
+        }
+
+        Status::Halted
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    enum Toy { Inc(Register), Dec(Register), Jnz(Register, i64), Out(Operand), Slow, Hlt }
+
+    fn register(name: &str) -> Option<Register> {
+        match name {
+            "a" => Some(0),
+            "b" => Some(1),
+            _ => None
+        }
+    }
+
+    impl Decode for Toy {
+        fn decode(line: &str) -> Option<Toy> {
+            let mut tokens = line.split_whitespace();
+            match (tokens.next()?, tokens.next(), tokens.next()) {
+                ("inc", Some(r), None) => Some(Toy::Inc(register(r)?)),
+                ("dec", Some(r), None) => Some(Toy::Dec(register(r)?)),
+                ("jnz", Some(r), Some(offset)) => Some(Toy::Jnz(register(r)?, offset.parse().ok()?)),
+                ("out", Some(value), None) => match register(value) {
+                    Some(r) => Some(Toy::Out(Operand::Register(r))),
+                    None => Some(Toy::Out(Operand::Immediate(value.parse().ok()?)))
+                },
+                ("slow", None, None) => Some(Toy::Slow),
+                ("hlt", None, None) => Some(Toy::Hlt),
+                _ => None
+            }
+        }
+    }
+
+    impl Instruction for Toy {
+        fn execute(&self, registers: &mut Registers) -> Effect {
+            match self {
+                Toy::Inc(r) => { *registers.get_mut(*r) += 1; Effect::Next },
+                Toy::Dec(r) => { *registers.get_mut(*r) -= 1; Effect::Next },
+                Toy::Jnz(r, offset) => if registers.get(*r) != 0 { Effect::Jump(*offset) } else { Effect::Next },
+                Toy::Out(operand) => Effect::Output(operand.value(registers)),
+                Toy::Slow => Effect::Next,
+                Toy::Hlt => Effect::Halt
+            }
+        }
+
+        fn cycles(&self) -> usize {
+            match self {
+                Toy::Slow => 3,
+                _ => 1
+            }
+        }
+    }
+
+    fn machine(source: &str) -> Machine<Toy> {
+        Machine::new(decode_program(source).unwrap(), 2)
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let result = decode_program::<Toy>("inc a\nfoo b");
+        assert_eq!(result.err(), Some(Error::UnknownInstruction(2, "foo b".into())));
+    }
+
+    #[test]
+    fn test_run_until_end_of_program() {
+        let mut machine = machine("inc a\ninc a\ninc b");
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.registers().as_slice(), &[2, 1]);
+        assert!(machine.is_halted());
+    }
+
+    #[test]
+    fn test_jumps() {
+        // Moves a into b
+        let mut machine = machine("inc a\ninc a\ninc a\ninc b\ndec a\njnz a -2\nhlt\ninc b");
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.registers().as_slice(), &[0, 3]);
+    }
+
+    #[test]
+    fn test_outputs() {
+        let mut machine = machine("out 1\ninc a\nout a\nout 5");
+        assert_eq!(machine.run(), Status::Halted);
+        assert_eq!(machine.output(), &[1, 1, 5]);
+    }
+
+    #[test]
+    fn test_break_on_output() {
+        let mut machine = machine("out 1\ninc a\nout a").with_break_on_output(true);
+        assert_eq!(machine.run(), Status::Output(1));
+        assert_eq!(machine.run(), Status::Output(1));
+        assert!(machine.is_halted());
+        assert_eq!(machine.run(), Status::Halted);
+    }
+
+    #[test]
+    fn test_trace_cycles() {
+        let mut machine = machine("inc a\nslow\ninc a");
+        let mut trace = vec![];
+        machine.run_traced(|event| trace.push((event.cycle, event.pc, event.registers.get(0))));
+        assert_eq!(trace, vec![(1, 0, 0), (2, 1, 1), (3, 1, 1), (4, 1, 1), (5, 2, 1)]);
+        assert_eq!(machine.cycle(), 5);
+    }
+
+    #[test]
+    fn test_run_until() {
+        // Stops once back at an instruction already run
+        let mut looping = machine("inc a\njnz a -1\nout a");
+        let mut seen = [false; 3];
+        assert_eq!(looping.run_until(|machine| std::mem::replace(&mut seen[machine.pc()], true)), Status::Running);
+        assert_eq!((looping.pc(), looping.registers().get(0)), (0, 1));
+
+        let mut ending = machine("out 1\ninc a\nout a");
+        assert_eq!(ending.run_until(|machine| machine.registers().get(0) > 1), Status::Halted);
+        assert_eq!(ending.output(), &[1, 1]);
+    }
+
+    #[test]
+    fn test_step() {
+        let mut machine = machine("inc a\nhlt\ninc a");
+        assert_eq!(machine.step(), Status::Running);
+        assert_eq!(machine.pc(), 1);
+        assert_eq!(machine.step(), Status::Halted);
+        assert_eq!(machine.step(), Status::Halted);
+        assert_eq!(machine.registers().get(0), 1);
+    }
+}
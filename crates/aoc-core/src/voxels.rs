@@ -0,0 +1,82 @@
+//! Shapes made of unit cubes in space, given as the set of their positions, such as a droplet of
+//! lava scanned cube by cube.
+
+use crate::collections::HashSet;
+use crate::geometry::Vec3;
+use crate::graph::flood_fill;
+
+/// The inclusive `(min, max)` corners of the box around `cubes`, if there are any.
+pub fn bounding_box<'a>(cubes: impl IntoIterator<Item = &'a Vec3>) -> Option<(Vec3, Vec3)> {
+    cubes.into_iter().fold(None, |bounds, cube| Some(match bounds {
+        None => (*cube, *cube),
+        Some((min, max)) => (
+            Vec3::new(min.x.min(cube.x), min.y.min(cube.y), min.z.min(cube.z)),
+            Vec3::new(max.x.max(cube.x), max.y.max(cube.y), max.z.max(cube.z))
+        )
+    }))
+}
+
+/// The number of faces of `cubes` that don't touch another cube, pockets inside included.
+pub fn surface_area(cubes: &HashSet<Vec3>) -> usize {
+    cubes.iter().flat_map(Vec3::neighbors).filter(|neighbor| !cubes.contains(neighbor)).count()
+}
+
+/// The empty positions connected to the outside of `cubes`, within their bounding box grown by
+/// one so that the outside wraps around them. Empty positions missing from it are trapped in
+/// pockets.
+pub fn exterior(cubes: &HashSet<Vec3>) -> HashSet<Vec3> {
+    let Some((min, max)) = bounding_box(cubes)
+    else {
+        return HashSet::default();
+    };
+
+    let (min, max) = (min - Vec3::new(1, 1, 1), max + Vec3::new(1, 1, 1));
+    let inside = |position: &Vec3| {
+        (min.x..=max.x).contains(&position.x) && (min.y..=max.y).contains(&position.y) && (min.z..=max.z).contains(&position.z)
+    };
+
+    flood_fill(min, Vec3::neighbors, |position| inside(position) && !cubes.contains(position)).cells
+}
+
+/// The number of faces of `cubes` that can be reached from the outside.
+pub fn exterior_surface_area(cubes: &HashSet<Vec3>) -> usize {
+    let exterior = exterior(cubes);
+    cubes.iter().flat_map(Vec3::neighbors).filter(|neighbor| exterior.contains(neighbor)).count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DROPLET: [(i64, i64, i64); 13] = [
+        (2, 2, 2), (1, 2, 2), (3, 2, 2), (2, 1, 2), (2, 3, 2), (2, 2, 1), (2, 2, 3),
+        (2, 2, 4), (2, 2, 6), (1, 2, 5), (3, 2, 5), (2, 1, 5), (2, 3, 5)
+    ];
+
+    fn droplet() -> HashSet<Vec3> {
+        DROPLET.iter().map(|(x, y, z)| Vec3::new(*x, *y, *z)).collect()
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        assert_eq!(bounding_box(&droplet()), Some((Vec3::new(1, 1, 1), Vec3::new(3, 3, 6))));
+        assert_eq!(bounding_box(&HashSet::default()), None);
+    }
+
+    #[test]
+    fn test_surface_area() {
+        let pair = HashSet::from_iter([Vec3::new(1, 1, 1), Vec3::new(2, 1, 1)]);
+        assert_eq!(surface_area(&pair), 10);
+        assert_eq!(surface_area(&droplet()), 64);
+    }
+
+    #[test]
+    fn test_exterior() {
+        let droplet = droplet();
+        assert_eq!(exterior_surface_area(&droplet), 58);
+        // The only pocket is the single empty cube in the middle of the droplet
+        assert!(!exterior(&droplet).contains(&Vec3::new(2, 2, 5)));
+        assert_eq!(exterior(&droplet).len(), 5 * 5 * 8 - droplet.len() - 1);
+        assert!(exterior(&HashSet::default()).is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+//! Timing solutions over several runs, and comparing the timings with another git revision.
+
+use crate::{ cache, date::PuzzleDate, inputs, registry::Registry, solution::{ Error, Solution } };
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::time::{ Duration, Instant };
+
+/// Where the other revision is checked out and built. The target folder is kept around, so
+/// comparing against the same revision again only rebuilds what changed.
+const COMPARE_WORKTREE: &str = "target/bench-compare/worktree";
+const COMPARE_TARGET: &str = "target/bench-compare/target";
+
+/// The median time to solve `input` over `iterations` runs.
+pub fn measure(solution: &dyn Solution, input: &str, iterations: usize) -> Result<Duration, Error> {
+    let mut times = Vec::with_capacity(iterations);
+    for _ in 0..iterations.max(1) {
+        let start = Instant::now();
+        solution.solve(input)?;
+        times.push(start.elapsed());
+    }
+
+    times.sort();
+    Ok(times[times.len() / 2])
+}
+
+/// Measures every date whose input is on disk. Dates that fail are reported and left out.
+pub fn measure_all(registry: &Registry, dates: &[PuzzleDate], iterations: usize) -> HashMap<PuzzleDate, Duration> {
+    dates
+        .iter()
+        .filter_map(|date| {
+            let solution = registry.get(*date)?;
+            let metadata = solution.metadata();
+            let time = inputs::read(&metadata)
+                .map_err(|err| format!("Failed to read input: {err}").into())
+                .and_then(|input| measure(solution, &input, iterations));
+
+            match time {
+                Ok(time) => Some((*date, time)),
+                Err(err) => {
+                    eprintln!("{date}: {}: {err}", metadata.title);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn run_command(command: &mut Command) -> Result<String, Error> {
+    let output = command.output()?;
+    if !output.status.success() {
+        return Err(format!("{command:?} failed: {}", String::from_utf8_lossy(&output.stderr).trim()).into());
+    }
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Builds `revision` in release mode in a separate worktree and benchmarks it on the inputs of
+/// the current checkout, with the same `aoc bench` arguments. The revision must have
+/// `aoc bench --tsv` itself.
+pub fn measure_revision(revision: &str, bench_arguments: &[String]) -> Result<HashMap<PuzzleDate, Duration>, Error> {
+    let worktree = Path::new(COMPARE_WORKTREE);
+    if worktree.exists() {
+        run_command(Command::new("git").args(["-C", COMPARE_WORKTREE, "checkout", "--quiet", "--detach", revision]))?;
+    }
+    else {
+        run_command(Command::new("git").args(["worktree", "add", "--quiet", "--detach", COMPARE_WORKTREE, revision]))?;
+    }
+
+    let target = std::env::current_dir()?.join(COMPARE_TARGET);
+    run_command(
+        Command::new("cargo")
+            .args(["build", "--quiet", "--release", "--bin", "aoc"])
+            .current_dir(worktree)
+            .env("CARGO_TARGET_DIR", &target)
+    )?;
+
+    let tsv = run_command(Command::new(target.join("release/aoc")).arg("bench").args(bench_arguments).arg("--tsv"))?;
+    Ok(cache::timings_from_tsv(&tsv))
+}
+
+/// A table of the timings, one row per date.
+pub fn timing_table(registry: &Registry, times: &HashMap<PuzzleDate, Duration>) -> String {
+    let mut dates: Vec<_> = times.keys().copied().collect();
+    dates.sort();
+
+    let mut table = format!("{:<40} {:>12}\n", "Puzzle", "Median");
+    for date in dates {
+        let puzzle = format!("{date}: {}", title(registry, date));
+        table += &format!("{puzzle:<40} {:>12}\n", format!("{:.2?}", times[&date]));
+    }
+
+    table
+}
+
+fn title(registry: &Registry, date: PuzzleDate) -> &str {
+    registry.get(date).map(|solution| solution.metadata().title).unwrap_or("?")
+}
+
+/// A table of the timings of both builds, one row per date that both could measure.
+pub fn comparison_table(registry: &Registry, before: &HashMap<PuzzleDate, Duration>, after: &HashMap<PuzzleDate, Duration>) -> String {
+    let mut dates: Vec<_> = after.keys().filter(|date| before.contains_key(date)).copied().collect();
+    dates.sort();
+
+    let mut table = format!("{:<40} {:>12} {:>12} {:>9}\n", "Puzzle", "Before", "After", "Speedup");
+    for date in dates {
+        let (before, after) = (before[&date], after[&date]);
+        let speedup = before.as_secs_f64() / after.as_secs_f64().max(f64::MIN_POSITIVE);
+        let puzzle = format!("{date}: {}", title(registry, date));
+        table += &format!("{puzzle:<40} {:>12} {:>12} {speedup:>8.2}x\n", format!("{before:.2?}"), format!("{after:.2?}"));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::y2023::d06::WaitForIt;
+
+    fn date(year: u16, day: u8) -> PuzzleDate {
+        PuzzleDate::new(year, day).unwrap()
+    }
+
+    #[test]
+    fn test_comparison_table() {
+        let registry = Registry::builtin();
+        let before = HashMap::from([(date(2023, 6), Duration::from_millis(3)), (date(2023, 7), Duration::from_millis(1))]);
+        let after = HashMap::from([(date(2023, 6), Duration::from_millis(1))]);
+        let table = comparison_table(&registry, &before, &after);
+        let rows: Vec<_> = table.lines().collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[1].starts_with("2023 day 6: Wait For It"));
+        assert!(rows[1].ends_with("3.00x"));
+    }
+
+    #[test]
+    fn test_measure() {
+        assert!(measure(&WaitForIt, "Time: 7\nDistance: 9\n", 3).is_ok());
+        assert!(measure(&WaitForIt, "nonsense", 3).is_err());
+    }
+}
@@ -0,0 +1,22 @@
+//! The solutions of each year, from the crate of the year, e.g. `calendar::y2023::d05` is
+//! `aoc_2023::d05`. Each year is behind its `yearXXXX` feature.
+
+use crate::registry::Registry;
+
+#[cfg(feature = "year2022")]
+pub use aoc_2022 as y2022;
+#[cfg(feature = "year2023")]
+pub use aoc_2023 as y2023;
+#[cfg(feature = "year2024")]
+pub use aoc_2024 as y2024;
+
+// With no year enabled there's nothing to register
+#[cfg_attr(not(any(feature = "year2022", feature = "year2023", feature = "year2024")), allow(unused_variables))]
+pub fn register_all(registry: &mut Registry) {
+    #[cfg(feature = "year2022")]
+    y2022::register(registry);
+    #[cfg(feature = "year2023")]
+    y2023::register(registry);
+    #[cfg(feature = "year2024")]
+    y2024::register(registry);
+}
@@ -0,0 +1,689 @@
+//! The `aoc` command line tool.
+
+#[cfg(unix)]
+use crate::daemon;
+#[cfg(feature = "download")]
+use crate::puzzles;
+use crate::{ bench, cache::{ self, AnswerCache, SourceHashes, Stars, Timings }, date::PuzzleDate, examples, inputs, privacy, profile, registry::Registry, runner::{ self, Parallelism, Report }, scaffold, stats, verify::{ ExpectedAnswers, Verdict } };
+use clap::{ Args, Parser, Subcommand };
+use std::path::PathBuf;
+use std::sync::atomic::{ AtomicBool, Ordering };
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    /// Keep the inputs out of the output, so that it can be shared: inputs are only named by
+    /// their hash, and the parts of them quoted by errors are anonymized
+    #[arg(long, global = true)]
+    private: bool,
+    #[command(subcommand)]
+    command: Command
+}
+
+/// Which puzzles a command applies to: a whole year, a single day, or everything.
+#[derive(Args)]
+struct Selection {
+    #[arg(required_unless_present = "all")]
+    year: Option<u16>,
+    day: Option<u8>,
+    #[arg(long, conflicts_with = "year")]
+    all: bool
+}
+
+impl Selection {
+    fn dates(&self, registry: &Registry) -> Vec<PuzzleDate> {
+        registry
+            .dates()
+            .into_iter()
+            .filter(|date| self.year.is_none_or(|year| date.year() == year) && self.day.is_none_or(|day| date.day() == day))
+            .collect()
+    }
+
+    /// The arguments that select the same puzzles, for running another `aoc`.
+    fn to_arguments(&self) -> Vec<String> {
+        match (self.year, self.day) {
+            (Some(year), Some(day)) => vec![year.to_string(), day.to_string()],
+            (Some(year), None) => vec![year.to_string()],
+            _ => vec!["--all".to_string()]
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves the puzzles of a year or a single day, or every puzzle with --all. Running more
+    /// than one ends with a table of the answers and how long each part took
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Report the answers of a previous run on the same input instead of solving again
+        #[arg(long)]
+        cached: bool,
+        /// Skip the days whose sources and input didn't change since their last run with the expected answers
+        #[arg(long)]
+        incremental: bool,
+        /// Cache parsed inputs on disk, for the days that support it
+        #[arg(long)]
+        cache_parsed: bool,
+        /// Read the input on a separate thread while solving, for very large inputs
+        #[arg(long)]
+        pipelined: bool,
+        /// Solve this file instead of the puzzle's input, e.g. an example or a stress test
+        #[arg(long, value_name = "PATH", requires = "day")]
+        input: Option<PathBuf>,
+        /// Stop the days that support it after this long, reporting their best answers so far
+        #[arg(long, value_name = "SECONDS")]
+        max_time: Option<f64>,
+        /// Give up on the days still running after this long and move on to the next, e.g. to
+        /// keep a hanging day from blocking the others. Also catches the days that panic
+        #[arg(long, value_name = "SECONDS", conflicts_with = "pipelined")]
+        time_limit: Option<f64>,
+        /// Use the parallel strategy of the days that have one, even when they're quick
+        #[arg(long, conflicts_with = "serial")]
+        parallel: bool,
+        /// Use the serial strategy of the days that have one, e.g. to compare timings
+        #[arg(long)]
+        serial: bool,
+        /// Download the inputs that aren't on disk yet, while solving the others
+        #[cfg(feature = "async")]
+        #[arg(long)]
+        fetch_missing: bool
+    },
+    /// Times the solutions, reporting the median of several runs
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        #[arg(long, default_value_t = 5)]
+        iterations: usize,
+        /// Also benchmark this git revision, e.g. `main` or `HEAD~3`, and compare the timings.
+        /// The revision is built in release mode, so run this from a release build too
+        #[arg(long)]
+        compare: Option<String>,
+        /// Print one tab-separated `year day nanoseconds` line per puzzle
+        #[arg(long, hide = true)]
+        tsv: bool
+    },
+    /// Solves the puzzles once, breaking down where the time went
+    Profile {
+        #[command(flatten)]
+        selection: Selection,
+        /// Show every span, e.g. the parsing and each part of the days that mark them, instead
+        /// of only reading and solving
+        #[arg(long)]
+        spans: bool
+    },
+    /// Solves the puzzles several times, reporting those whose answers change between runs,
+    /// e.g. because of state kept across runs or threads racing each other
+    Determinism {
+        #[command(flatten)]
+        selection: Selection,
+        #[arg(long, default_value_t = 3)]
+        runs: usize
+    },
+    /// Downloads the inputs of the puzzles, skipping those already downloaded
+    #[cfg(feature = "download")]
+    Fetch {
+        #[command(flatten)]
+        selection: Selection,
+        /// Download the inputs again even when they look fine, replacing any edited by hand
+        #[arg(long)]
+        force: bool
+    },
+    /// Solves the puzzles and checks the answers against those in `inputs/answers.tsv`, failing
+    /// if any is wrong
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+        /// Save the answers of the puzzles that don't have expected answers yet
+        #[arg(long)]
+        record: bool,
+        /// Fail the days still running after this long and move on to the next, see `aoc run`
+        #[arg(long, value_name = "SECONDS")]
+        time_limit: Option<f64>
+    },
+    /// Solves the samples of the puzzle descriptions kept in `examples/<year>/<day>/`, checking
+    /// the answers listed in their `answers.tsv`
+    TestExamples {
+        #[command(flatten)]
+        selection: Selection
+    },
+    /// Solves the puzzles and writes their answers and runtimes as a Markdown table, giving an
+    /// overview of the repository
+    Report {
+        #[command(flatten)]
+        selection: Selection,
+        #[arg(long, default_value = "RESULTS.md")]
+        output: PathBuf
+    },
+    /// Starts the solution of a new day: its source files, its binary in Cargo.toml and the
+    /// folder for its input
+    New {
+        year: u16,
+        day: u8,
+        /// The title of the puzzle, e.g. "Guard Gallivant", which also names the files
+        title: String
+    },
+    /// Prints the description of a puzzle as Markdown, downloading it into `puzzles/` the first
+    /// time. Part 2 is only there once part 1 is solved, and with `AOC_SESSION` set
+    #[cfg(feature = "download")]
+    #[command(alias = "open")]
+    Puzzle {
+        year: u16,
+        day: u8,
+        /// Download the description again, e.g. to get part 2
+        #[arg(long)]
+        refresh: bool
+    },
+    /// Shows which days of each year are solved, which of those have expected answers for
+    /// `aoc verify`, and which are missing
+    Status,
+    /// Summarizes each year: days solved, stars, runtimes of the last runs and tags
+    Stats {
+        /// Fetch the stars of each year from adventofcode.com first
+        #[cfg(feature = "download")]
+        #[arg(long)]
+        sync_stars: bool
+    },
+    /// Shows a private leaderboard: the score of each member, and their stars day by day
+    #[cfg(feature = "download")]
+    Leaderboard {
+        /// The number at the end of the leaderboard's URL
+        id: u64,
+        /// The event, by default the latest
+        #[arg(long)]
+        year: Option<u16>
+    },
+    /// Keeps running and answers requests over a unix socket, see `src/daemon.rs`
+    #[cfg(unix)]
+    Daemon {
+        #[arg(long, default_value = daemon::DEFAULT_SOCKET)]
+        socket: std::path::PathBuf
+    }
+}
+
+/// Parses the command line and runs the `aoc` tool over the solutions of `registry`. Crates
+/// with solutions of their own register them next to the builtin ones and call this from
+/// their `main`:
+///
+/// ```no_run
+/// # use advent_of_code::{ cli, registry::Registry };
+/// # let my_solution = advent_of_code::calendar::y2023::d06::WaitForIt;
+/// let mut registry = Registry::builtin();
+/// registry.register(Box::new(my_solution));
+/// cli::main(registry);
+/// ```
+pub fn main(registry: Registry) {
+    let cli = Cli::parse();
+    privacy::enable(cli.private);
+
+    match cli.command {
+        Command::Run {
+            selection,
+            cached,
+            incremental,
+            cache_parsed,
+            pipelined,
+            input,
+            max_time,
+            time_limit,
+            parallel,
+            serial,
+            #[cfg(feature = "async")]
+            fetch_missing
+        } => {
+            let dates = selection.dates(&registry);
+            if dates.is_empty() {
+                println!("No solution to run");
+                return;
+            }
+
+            cache::enable_parsed(cache_parsed);
+            // The first Ctrl+C asks the day being solved to stop and skips the others, so the
+            // caches still get saved. Days that ignore it are cut short by the second one,
+            // showing what they found so far rather than losing all of it
+            ctrlc::set_handler(|| {
+                if !INTERRUPTED.swap(true, Ordering::Relaxed) {
+                    runner::cancel_all();
+                    return;
+                }
+
+                for interrupted in runner::interrupted() {
+                    println!("{interrupted}");
+                }
+                std::process::exit(130);
+            })
+            .expect("Failed to handle Ctrl+C");
+
+            #[cfg(feature = "async")]
+            if fetch_missing {
+                let runtime = tokio::runtime::Runtime::new().expect("Failed to start the async runtime");
+                runtime.block_on(crate::pipeline::run(registry.into(), dates, true, |report| println!("{report}")));
+                return;
+            }
+
+            let parallelism = match (parallel, serial) {
+                (true, _) => Parallelism::Parallel,
+                (_, true) => Parallelism::Serial,
+                _ => Parallelism::Auto
+            };
+            let settings = runner::Settings { max_time: max_time.map(Duration::from_secs_f64), parallelism };
+            let time_limit = time_limit.map(Duration::from_secs_f64);
+            run(leak(registry), &dates, RunOptions { cached, incremental, pipelined, input, settings, time_limit });
+        },
+        Command::Bench { selection, iterations, compare, tsv } => {
+            let after = bench::measure_all(&registry, &selection.dates(&registry), iterations);
+            if tsv {
+                print!("{}", cache::timings_to_tsv(&after));
+                return;
+            }
+
+            let Some(revision) = compare else {
+                print!("{}", bench::timing_table(&registry, &after));
+                return;
+            };
+
+            let mut arguments = selection.to_arguments();
+            arguments.extend(["--iterations".to_string(), iterations.to_string()]);
+            match bench::measure_revision(&revision, &arguments) {
+                Ok(before) => print!("{}", bench::comparison_table(&registry, &before, &after)),
+                Err(err) => println!("Failed to benchmark {revision}: {err}")
+            }
+        },
+        Command::Profile { selection, spans } => {
+            for date in selection.dates(&registry) {
+                let solution = registry.get(date).unwrap();
+                let metadata = solution.metadata();
+                let (report, entries) = profile::record(|| match inputs::load(&metadata) {
+                    Ok(input) => runner::run(solution, &input),
+                    Err(err) => Report::failed(metadata, err)
+                });
+
+                println!("{report}");
+                print!("{}", profile::tree(&entries, if spans { usize::MAX } else { 1 }));
+            }
+        },
+        Command::Determinism { selection, runs } => {
+            for date in selection.dates(&registry) {
+                let solution = registry.get(date).unwrap();
+                let metadata = solution.metadata();
+                let outcome = inputs::load(&metadata)
+                    .and_then(|input| runner::check_determinism(solution, &input, runs));
+
+                match outcome {
+                    Ok(_) => println!("{date}: {}: same answers over {runs} runs", metadata.title),
+                    Err(err) => println!("{date}: {}: {err}", metadata.title)
+                }
+            }
+        },
+        Command::Verify { selection, record, time_limit } => {
+            let dates = selection.dates(&registry);
+            if !verify(leak(registry), &dates, record, time_limit.map(Duration::from_secs_f64)) {
+                std::process::exit(1);
+            }
+        },
+        #[cfg(feature = "download")]
+        Command::Fetch { selection, force } => fetch(&registry, &selection.dates(&registry), force),
+        Command::TestExamples { selection } => {
+            if !test_examples(&registry, &selection.dates(&registry)) {
+                std::process::exit(1);
+            }
+        },
+        Command::Report { selection, output } => {
+            let reports: Vec<Report> = selection
+                .dates(&registry)
+                .into_iter()
+                .map(|date| {
+                    let solution = registry.get(date).unwrap();
+                    let report = match inputs::load(&solution.metadata()) {
+                        Ok(input) => runner::run(solution, &input),
+                        Err(err) => Report::failed(solution.metadata(), err)
+                    };
+                    println!("{report}");
+                    report
+                })
+                .collect();
+
+            match std::fs::write(&output, stats::results_markdown(&reports)) {
+                Ok(_) => println!("Wrote the results of {} puzzles to {}", reports.len(), output.display()),
+                Err(err) => println!("Failed to write {}: {err}", output.display())
+            }
+        },
+        Command::New { year, day, title } => {
+            let created = PuzzleDate::new(year, day)
+                .map_err(|err| err.to_string())
+                .and_then(|date| scaffold::create(".".as_ref(), date, &title).map_err(|err| err.to_string()));
+
+            match created {
+                Ok(paths) => paths.iter().for_each(|path| println!("Wrote {}", path.display())),
+                Err(err) => println!("Failed to create {year} day {day}: {err}")
+            }
+        },
+        #[cfg(feature = "download")]
+        Command::Puzzle { year, day, refresh } => {
+            let markdown = PuzzleDate::new(year, day)
+                .map_err(|err| err.to_string())
+                .and_then(|date| puzzles::load(puzzles::DEFAULT_DIRECTORY.as_ref(), date, refresh).map_err(|err| err.to_string()));
+
+            match markdown {
+                Ok(markdown) => print!("{markdown}"),
+                Err(err) => println!("Failed to get the puzzle of {year} day {day}: {err}")
+            }
+        },
+        Command::Status => match ExpectedAnswers::open(ExpectedAnswers::DEFAULT_PATH) {
+            Ok(expected) => print!("{}\n* verified, o solved, . missing\n", stats::status_table(&stats::status(&registry, &expected))),
+            Err(err) => println!("Failed to read the expected answers: {err}")
+        },
+        Command::Stats {
+            #[cfg(feature = "download")]
+            sync_stars
+        } => {
+            #[cfg(feature = "download")]
+            if sync_stars {
+                sync(&registry);
+            }
+
+            let (timings, stars) = match (Timings::open(Timings::DEFAULT_PATH), Stars::open(Stars::DEFAULT_PATH)) {
+                (Ok(timings), Ok(stars)) => (timings, stars),
+                (Err(err), _) | (_, Err(err)) => return println!("Failed to read the cache: {err}")
+            };
+
+            print!("{}", stats::table(&stats::collect(&registry, &timings, &stars)));
+        },
+        #[cfg(feature = "download")]
+        Command::Leaderboard { id, year } => {
+            let year = year.unwrap_or_else(PuzzleDate::latest_year);
+            match crate::leaderboard::fetch(year, id) {
+                Ok(leaderboard) => print!("{}", crate::leaderboard::table(&leaderboard)),
+                Err(err) => println!("Failed to get leaderboard {id}: {err}")
+            }
+        },
+        #[cfg(unix)]
+        Command::Daemon { socket } => {
+            println!("Listening on {}", socket.display());
+            if let Err(err) = daemon::serve(&registry, &socket) {
+                println!("Daemon stopped: {err}");
+            }
+        }
+    }
+}
+
+/// The registry for the rest of the process, so that the days given up on by `--time-limit` can
+/// keep running in the background.
+fn leak(registry: Registry) -> &'static Registry {
+    Box::leak(Box::new(registry))
+}
+
+/// Checks the answers of each puzzle against the expected ones, returning whether none was wrong
+/// or failed. Puzzles without an input are skipped. With `record`, the answers of puzzles without expected answers become expected.
+/// Wrong answers are remembered in the answer cache, for `aoc run` to warn when they come back.
+fn verify(registry: &'static Registry, dates: &[PuzzleDate], record: bool, time_limit: Option<Duration>) -> bool {
+    let mut expected = match ExpectedAnswers::open(ExpectedAnswers::DEFAULT_PATH) {
+        Ok(expected) => expected,
+        Err(err) => {
+            println!("Failed to read the expected answers: {err}");
+            return false;
+        }
+    };
+
+    let mut answer_cache = AnswerCache::open(AnswerCache::DEFAULT_PATH)
+        .inspect_err(|err| println!("Not remembering wrong answers, the answer cache is unreadable: {err}"))
+        .ok();
+
+    let (mut correct, mut unknown, mut failed, mut skipped) = (0, 0, 0, 0);
+    for date in dates {
+        let solution = registry.get(*date).unwrap();
+        let metadata = solution.metadata();
+        // Without its input a puzzle can't regress, so it doesn't fail the check
+        let input = match inputs::load(&metadata) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{date}: {}: skipped, {err}", metadata.title);
+                skipped += 1;
+                continue;
+            }
+        };
+
+        let answers = match time_limit {
+            Some(time_limit) => runner::run_with_time_limit(solution, &input, &runner::Settings::default(), time_limit).answers,
+            None => solution.solve(&input)
+        };
+        let verdict = Verdict::new(expected.get(*date), answers);
+        println!("{date}: {}: {verdict}", metadata.title);
+
+        match verdict {
+            Verdict::Correct => correct += 1,
+            Verdict::Unknown(answers) => {
+                unknown += 1;
+                if record {
+                    expected.set(*date, answers);
+                }
+            },
+            Verdict::Wrong(wrong) => {
+                failed += 1;
+                if let Some(answer_cache) = answer_cache.as_mut() {
+                    for (part, _, answer) in wrong {
+                        answer_cache.reject(&metadata, &input, part, &answer);
+                    }
+                }
+            },
+            Verdict::Failed(_) => failed += 1
+        }
+    }
+
+    println!("{correct} correct, {failed} wrong or failed, {unknown} without expected answers, {skipped} without input");
+    if record && unknown > 0 {
+        if let Err(err) = expected.save() {
+            println!("Failed to save the expected answers: {err}");
+        }
+    }
+
+    if let Some(Err(err)) = answer_cache.map(|answer_cache| answer_cache.save()) {
+        println!("Failed to save the answer cache: {err}");
+    }
+
+    failed == 0
+}
+
+/// Checks each puzzle against its examples, returning whether none was wrong or failed.
+fn test_examples(registry: &Registry, dates: &[PuzzleDate]) -> bool {
+    let (mut correct, mut failed, mut without) = (0, 0, 0);
+    for date in dates {
+        let solution = registry.get(*date).unwrap();
+        let title = solution.metadata().title;
+        let examples = match examples::load(examples::DEFAULT_DIRECTORY.as_ref(), *date) {
+            Ok(examples) => examples,
+            Err(err) => {
+                println!("{date}: {title}: failed to read the examples: {err}");
+                failed += 1;
+                continue;
+            }
+        };
+
+        if examples.is_empty() {
+            without += 1;
+            continue;
+        }
+
+        for example in examples {
+            let verdict = examples::check(solution, &example);
+            println!("{date}: {title}: {}: {verdict}", example.path.display());
+            match verdict {
+                Verdict::Correct => correct += 1,
+                _ => failed += 1
+            }
+        }
+    }
+
+    println!("{correct} examples correct, {failed} wrong or failed, {without} puzzles without examples");
+    failed == 0
+}
+
+/// Downloads the inputs that are missing or were saved as a web page, and with `force` those
+/// already downloaded or edited too.
+#[cfg(feature = "download")]
+fn fetch(registry: &Registry, dates: &[PuzzleDate], force: bool) {
+    use inputs::Freshness;
+
+    let downloads = match cache::Downloads::open(cache::Downloads::DEFAULT_PATH) {
+        Ok(downloads) => downloads,
+        Err(err) => return println!("Failed to read the downloads: {err}")
+    };
+
+    for date in dates {
+        let metadata = registry.get(*date).unwrap().metadata();
+        let skip_reason = match inputs::freshness(&metadata, &downloads) {
+            Ok(Freshness::Downloaded(at)) if !force => Some(format!("already downloaded {}", age(at))),
+            Ok(Freshness::Modified) if !force => Some("on disk, but not as downloaded by aoc, --force replaces it".to_string()),
+            Err(err) => Some(format!("failed to check the input: {err}")),
+            _ => None
+        };
+
+        match skip_reason {
+            Some(reason) => println!("{date}: {}: {reason}", metadata.title),
+            None => match inputs::fetch(&metadata) {
+                Ok(_) => println!("{date}: {}: downloaded to {}", metadata.title, inputs::path(&metadata).display()),
+                Err(err) => println!("{date}: {}: {err}", metadata.title)
+            }
+        }
+    }
+}
+
+/// How long ago `at` was, in days.
+#[cfg(feature = "download")]
+fn age(at: std::time::SystemTime) -> String {
+    let days = at.elapsed().unwrap_or_default().as_secs() / (24 * 60 * 60);
+    match days {
+        0 => "today".to_string(),
+        1 => "yesterday".to_string(),
+        days => format!("{days} days ago")
+    }
+}
+
+#[cfg(feature = "download")]
+/// Fetches the stars of every year with solutions and saves them in the cache.
+fn sync(registry: &Registry) {
+    let (session, mut stars) = match (crate::download::session(), Stars::open(Stars::DEFAULT_PATH)) {
+        (Ok(session), Ok(stars)) => (session, stars),
+        (Err(err), _) => return println!("{err}"),
+        (_, Err(err)) => return println!("Failed to read the stars: {err}")
+    };
+
+    for year in registry.years() {
+        match crate::download::stars(year, &session) {
+            Ok(count) => stars.set(year, count),
+            Err(err) => println!("Failed to sync the stars of {year}: {err}")
+        }
+    }
+
+    if let Err(err) = stars.save() {
+        println!("Failed to save the stars: {err}");
+    }
+}
+
+/// Set by the first Ctrl+C during `aoc run`
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+struct RunOptions {
+    cached: bool,
+    incremental: bool,
+    pipelined: bool,
+    /// Replaces the input of the puzzle
+    input: Option<PathBuf>,
+    settings: runner::Settings,
+    /// Solves on another thread, given up on after this long
+    time_limit: Option<Duration>
+}
+
+fn run(registry: &'static Registry, dates: &[PuzzleDate], options: RunOptions) {
+    let mut answer_cache = AnswerCache::open(AnswerCache::DEFAULT_PATH)
+        .inspect_err(|err| println!("Not caching answers, the cache is unreadable: {err}"))
+        .ok();
+    let mut source_hashes = SourceHashes::open(SourceHashes::DEFAULT_PATH)
+        .inspect_err(|err| println!("Not running incrementally, the source hashes are unreadable: {err}"))
+        .ok();
+    let mut timings = Timings::open(Timings::DEFAULT_PATH)
+        .inspect_err(|err| println!("Not recording timings, they are unreadable: {err}"))
+        .ok();
+    let expected = ExpectedAnswers::open(ExpectedAnswers::DEFAULT_PATH)
+        .inspect_err(|err| println!("Not running incrementally, the expected answers are unreadable: {err}"))
+        .ok();
+
+    let mut reports = vec![];
+    for date in dates {
+        let solution = registry.get(*date).unwrap();
+        let metadata = solution.metadata();
+        if options.pipelined {
+            let path = options.input.clone().unwrap_or_else(|| inputs::path(&metadata));
+            let report = runner::run_pipelined(solution, &path);
+            println!("{report}");
+            reports.push(report);
+            continue;
+        }
+
+        let input = match &options.input {
+            Some(path) => std::fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {err}", path.display()).into()),
+            None => inputs::load(&metadata)
+        };
+
+        let report = match input {
+            Ok(input) => match answer_cache.as_ref().and_then(|answer_cache| answer_cache.answers(&metadata, &input)) {
+                Some(answers) if options.cached => Report::cached(metadata, answers.clone()),
+                Some(answers) if options.incremental && source_hashes.as_ref().is_some_and(|hashes| hashes.is_unchanged(&metadata)) => {
+                    Report::cached(metadata, answers.clone())
+                },
+                _ => {
+                    let report = match options.time_limit {
+                        Some(time_limit) => runner::run_with_time_limit(solution, &input, &options.settings, time_limit),
+                        None => runner::run_with(solution, &input, &options.settings)
+                    };
+                    // The timings are those of the puzzle's input, which other inputs would skew
+                    if let (Some(timings), Ok(_), None) = (timings.as_mut(), &report.answers, &options.input) {
+                        timings.record(&metadata, report.elapsed);
+                    }
+                    if let (Some(answer_cache), Ok(answers)) = (answer_cache.as_mut(), &report.answers) {
+                        for (part, answer) in [(1, &answers.0), (2, &answers.1)] {
+                            if answer_cache.is_rejected(&metadata, &input, part, answer) {
+                                println!("{date}: solution {part} is {answer} again, which aoc verify found wrong before");
+                            }
+                        }
+
+                        answer_cache.store(&metadata, &input, answers);
+                    }
+                    // Only answers known to be right are worth skipping the day for next time
+                    let expected_answers = expected.as_ref().and_then(|expected| expected.get(*date));
+                    let verified = options.input.is_none()
+                        && report.answers.as_ref().is_ok_and(|answers| matches!(Verdict::new(expected_answers, Ok(answers.clone())), Verdict::Correct));
+                    if let (Some(source_hashes), true) = (source_hashes.as_mut(), verified) {
+                        source_hashes.record(&metadata);
+                    }
+
+                    report
+                }
+            },
+            Err(err) => Report::failed(metadata, err)
+        };
+
+        println!("{report}");
+        reports.push(report);
+        if INTERRUPTED.load(Ordering::Relaxed) {
+            break;
+        }
+    }
+
+    if reports.len() > 1 {
+        print!("\n{}", runner::summary_table(&reports));
+    }
+
+    if let Some(Err(err)) = answer_cache.map(|answer_cache| answer_cache.save()) {
+        println!("Failed to save the answer cache: {err}");
+    }
+
+    if let Some(Err(err)) = source_hashes.map(|source_hashes| source_hashes.save()) {
+        println!("Failed to save the source hashes: {err}");
+    }
+
+    if let Some(Err(err)) = timings.map(|timings| timings.save()) {
+        println!("Failed to save the timings: {err}");
+    }
+}
@@ -0,0 +1,164 @@
+//! A long running `aoc` that answers requests over a unix socket, so editors and scripts don't
+//! pay for process startup, and unchanged inputs aren't read or solved twice. Each connection
+//! sends a single line and gets the response back before the daemon hangs up, e.g.
+//! `echo "solve 2022 16" | nc -U .cache/aoc.sock`.
+//!
+//! Requests:
+//! - `solve <year> <day> [input path]`, solving the default input of the day if no path is given
+//! - `ping`, answered with `pong`
+
+use crate::{ cache::input_hash, date::PuzzleDate, inputs, registry::Registry, runner::{ self, Report }, solution::Answers };
+use std::collections::HashMap;
+use std::io::{ self, BufRead, BufReader, Write };
+use std::os::unix::net::UnixListener;
+use std::path::Path;
+use std::time::Duration;
+
+pub const DEFAULT_SOCKET: &str = ".cache/aoc.sock";
+
+/// How long a client has to send its request, so that one that never does can't hold up the rest.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What the daemon remembers between requests.
+#[derive(Default)]
+struct State {
+    /// Answers by date and input hash, which stay valid as long as the daemon runs the same code
+    answers: HashMap<(PuzzleDate, u64), Answers>
+}
+
+/// Serves requests on `socket` until the process is killed.
+pub fn serve(registry: &Registry, socket: &Path) -> io::Result<()> {
+    // A socket file left over by a previous daemon would make binding fail
+    match std::fs::remove_file(socket) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => ()
+    }
+
+    if let Some(directory) = socket.parent() {
+        std::fs::create_dir_all(directory)?;
+    }
+
+    let listener = UnixListener::bind(socket)?;
+    let mut state = State::default();
+    for stream in listener.incoming() {
+        let mut stream = stream?;
+        let mut request = String::new();
+        // A misbehaving client only loses its own request
+        if stream.set_read_timeout(Some(REQUEST_TIMEOUT)).is_ok() && BufReader::new(&stream).read_line(&mut request).is_ok() {
+            let _ = writeln!(stream, "{}", handle(registry, &mut state, request.trim()));
+        }
+    }
+
+    Ok(())
+}
+
+fn handle(registry: &Registry, state: &mut State, request: &str) -> String {
+    let arguments: Vec<&str> = request.split_whitespace().collect();
+    match arguments[..] {
+        ["ping"] => "pong".to_string(),
+        ["solve", year, day, ref path @ ..] if path.len() <= 1 => {
+            let date = match (year.parse(), day.parse()) {
+                (Ok(year), Ok(day)) => PuzzleDate::new(year, day).map_err(|err| err.to_string()),
+                _ => Err(format!("{year} {day}"))
+            };
+            let date = match date {
+                Ok(date) => date,
+                Err(err) => return format!("Invalid date: {err}")
+            };
+
+            let Some(solution) = registry.get(date) else {
+                return format!("No solution for {date}");
+            };
+
+            let metadata = solution.metadata();
+            let input = match path.first() {
+                Some(path) => std::fs::read_to_string(path),
+                None => inputs::read(&metadata)
+            };
+
+            let report = match input {
+                Ok(input) => match state.answers.get(&(date, input_hash(&input))) {
+                    Some(answers) => Report::cached(metadata, answers.clone()),
+                    None => {
+                        // One bad input mustn't take the daemon down
+                        let report = runner::run_catching_panics(solution, &input);
+                        if let Ok(answers) = &report.answers {
+                            state.answers.insert((date, input_hash(&input)), answers.clone());
+                        }
+
+                        report
+                    }
+                },
+                Err(err) => Report::failed(metadata, format!("Failed to read input: {err}").into())
+            };
+
+            report.to_string()
+        },
+        _ => format!("Unknown request: {request}")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::{ Error, Metadata, RuntimeTier, Solution };
+
+    /// Panics whatever the input.
+    struct Panics;
+
+    impl Solution for Panics {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2015, day: 1, title: "Panics", tags: &[], runtime: RuntimeTier::Instant, strategies: &[] }
+        }
+
+        fn solve(&self, _input: &str) -> Result<Answers, Error> {
+            panic!("Out of patience")
+        }
+    }
+
+    #[test]
+    fn test_handle() {
+        let path = std::env::temp_dir().join(format!("aoc_daemon_{}.txt", std::process::id()));
+        std::fs::write(&path, "Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+        let request = format!("solve 2023 6 {}", path.display());
+
+        let (registry, mut state) = (Registry::builtin(), State::default());
+        assert_eq!(handle(&registry, &mut state, "ping"), "pong");
+        assert!(handle(&registry, &mut state, &request).ends_with("  Solution 1: 288\n  Solution 2: 71503"));
+        assert!(handle(&registry, &mut state, &request).starts_with("2023 day 6: Wait For It (cached)"));
+        assert_eq!(handle(&registry, &mut state, "solve 2015 1"), "No solution for 2015 day 1");
+        assert_eq!(handle(&registry, &mut state, "solve 2023 x"), "Invalid date: 2023 x");
+        assert_eq!(handle(&registry, &mut state, "solve 2023 26"), "Invalid date: There is no day 26, puzzles go from day 1 to 25");
+        assert_eq!(handle(&registry, &mut state, "verify"), "Unknown request: verify");
+
+        let mut panicking = Registry::new();
+        panicking.register(Box::new(Panics));
+        let response = handle(&panicking, &mut State::default(), &format!("solve 2015 1 {}", path.display()));
+        assert!(response.contains("Error: The solution panicked: Out of patience"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_silent_client() {
+        let socket = std::env::temp_dir().join(format!("aoc_daemon_{}.sock", std::process::id()));
+        let registry: &'static Registry = Box::leak(Box::new(Registry::builtin()));
+        let served = socket.clone();
+        std::thread::spawn(move || serve(registry, &served));
+
+        let connect = || loop {
+            match std::os::unix::net::UnixStream::connect(&socket) {
+                Ok(stream) => return stream,
+                Err(_) => std::thread::sleep(Duration::from_millis(10))
+            }
+        };
+
+        // Served once the first client times out
+        let _silent = connect();
+        let mut client = connect();
+        writeln!(client, "ping").unwrap();
+        let mut response = String::new();
+        BufReader::new(&client).read_line(&mut response).unwrap();
+        assert_eq!(response, "pong\n");
+        std::fs::remove_file(&socket).unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+//! The sample inputs given in the puzzle descriptions, with the answers the descriptions work
+//! out for them, for `aoc test-examples`. Each puzzle keeps them in `examples/<year>/<day>/`,
+//! next to an `answers.tsv` listing tab-separated `file part answer` lines. Samples often only
+//! make sense for one of the parts, so each lists the parts it has an answer for.
+
+use crate::{ answer::Answer, cache, date::PuzzleDate, solution::{ Error, Solution }, verify::Verdict };
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+pub const DEFAULT_DIRECTORY: &str = "examples";
+
+/// A sample input, and the answers expected for some of its parts.
+#[derive(Debug)]
+pub struct Example {
+    pub path: PathBuf,
+    pub input: String,
+    pub expected: [Option<Answer>; 2]
+}
+
+/// Where the examples of the puzzle are, under `directory`.
+pub fn path(directory: &Path, date: PuzzleDate) -> PathBuf {
+    directory.join(format!("{}/{:02}", date.year(), date.day()))
+}
+
+/// Loads the examples of the puzzle, in the order of their files. None if it has no
+/// `answers.tsv`.
+pub fn load(directory: &Path, date: PuzzleDate) -> io::Result<Vec<Example>> {
+    let directory = path(directory, date);
+    let mut expected: BTreeMap<String, [Option<Answer>; 2]> = BTreeMap::new();
+    for line in cache::read_or_empty(&directory.join("answers.tsv"))?.lines() {
+        let [file, part, answer] = line.split('\t').collect::<Vec<_>>()[..] else { continue };
+        let Ok(part @ 1..=2) = part.parse::<usize>() else { continue };
+        expected.entry(file.to_string()).or_default()[part - 1] = Some(cache::unescape(answer).parse().unwrap());
+    }
+
+    expected
+        .into_iter()
+        .map(|(file, expected)| {
+            let path = directory.join(file);
+            let input = std::fs::read_to_string(&path)?;
+            Ok(Example { path, input, expected })
+        })
+        .collect()
+}
+
+/// Solves `example`, checking the parts it has answers for. Solutions that can be run stage by
+/// stage only run those parts, so that a sample meant for one part can't fail the other.
+pub fn check(solution: &dyn Solution, example: &Example) -> Verdict {
+    let answers: Result<[Option<Answer>; 2], Error> = match solution.stages() {
+        Some(stages) => stages.parse(&example.input).and_then(|parsed| {
+            let [expected_1, expected_2] = &example.expected;
+            let solution_1 = expected_1.as_ref().map(|_| stages.part_1(parsed.as_ref())).transpose()?;
+            let solution_2 = expected_2.as_ref().map(|_| stages.part_2(parsed.as_ref())).transpose()?;
+            Ok([solution_1, solution_2])
+        }),
+        None => solution.solve(&example.input).map(|(solution_1, solution_2)| [Some(solution_1), Some(solution_2)])
+    };
+
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(err) => return Verdict::Failed(err)
+    };
+
+    let wrong: Vec<_> = example
+        .expected
+        .iter()
+        .zip(answers)
+        .zip(1..)
+        .filter_map(|((expected, answer), part)| match (expected, answer) {
+            (Some(expected), Some(answer)) if *expected != answer => Some((part, expected.clone(), answer)),
+            _ => None
+        })
+        .collect();
+
+    match wrong.is_empty() {
+        true => Verdict::Correct,
+        false => Verdict::Wrong(wrong)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::{ y2023::d01::Trebuchet, y2023::d06::WaitForIt };
+
+    #[test]
+    fn test_load_and_check() {
+        let directory = std::env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        let date = PuzzleDate::new(2023, 1).unwrap();
+        std::fs::create_dir_all(path(&directory, date)).unwrap();
+        std::fs::write(path(&directory, date).join("example_1.txt"), "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n").unwrap();
+        std::fs::write(path(&directory, date).join("example_2.txt"), "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen\n").unwrap();
+        std::fs::write(path(&directory, date).join("answers.tsv"), "example_1.txt\t1\t142\nexample_2.txt\t2\t281\n").unwrap();
+
+        let examples = load(&directory, date).unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].expected, [Some(142.into()), None]);
+        assert!(examples.iter().all(|example| matches!(check(&Trebuchet, example), Verdict::Correct)));
+
+        let wrong = Example { path: PathBuf::new(), input: "Time: 7\nDistance: 9\n".to_string(), expected: [Some(4.into()), Some(1.into())] };
+        assert!(matches!(check(&WaitForIt, &wrong), Verdict::Wrong(wrong) if wrong.len() == 1 && wrong[0].0 == 2));
+
+        assert!(load(&directory, PuzzleDate::new(2023, 2).unwrap()).unwrap().is_empty());
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+//! A C interface to the solutions, for embedding them from other languages. A header could read:
+//!
+//! ```c
+//! typedef struct { char *solution_1; char *solution_2; char *error; } AocAnswers;
+//! AocAnswers aoc_solve(uint16_t year, uint8_t day, const char *input, size_t input_len);
+//! void aoc_free_answers(AocAnswers answers);
+//! ```
+
+use crate::{ date::PuzzleDate, registry::Registry, runner::panic_message };
+use lazy_static::lazy_static;
+use std::ffi::{ c_char, CString };
+use std::panic::{ self, AssertUnwindSafe };
+use std::ptr::null_mut;
+
+lazy_static! {
+    static ref REGISTRY: Registry = Registry::builtin();
+}
+
+/// The outcome of `aoc_solve`. Either both solutions are set and `error` is null, or the other
+/// way around. All strings are NUL-terminated and owned by this library.
+#[repr(C)]
+pub struct AocAnswers {
+    pub solution_1: *mut c_char,
+    pub solution_2: *mut c_char,
+    pub error: *mut c_char
+}
+
+fn into_c_string(string: String) -> *mut c_char {
+    // Answers and error messages never contain NUL in practice, but don't trust it
+    CString::new(string.replace('\0', "\u{FFFD}")).unwrap().into_raw()
+}
+
+/// Solves the puzzle of the given date for the UTF-8 input of `input_len` bytes at `input`.
+/// The result must be released with `aoc_free_answers`. A panicking solution can't unwind into
+/// the caller, so it fails with the panic message instead.
+///
+/// # Safety
+///
+/// `input` must point to at least `input_len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(year: u16, day: u8, input: *const u8, input_len: usize) -> AocAnswers {
+    let bytes = if input.is_null() { &[] } else { std::slice::from_raw_parts(input, input_len) };
+    solve(&REGISTRY, year, day, bytes)
+}
+
+/// `aoc_solve` with the solutions of `registry`.
+fn solve(registry: &Registry, year: u16, day: u8, bytes: &[u8]) -> AocAnswers {
+    let answers = std::str::from_utf8(bytes)
+        .map_err(|err| format!("Input is not valid UTF-8: {err}").into())
+        .and_then(|input| {
+            panic::catch_unwind(AssertUnwindSafe(|| registry.solve(PuzzleDate::new(year, day)?, input)))
+                .unwrap_or_else(|payload| Err(panic_message(payload).into()))
+        });
+
+    match answers {
+        Ok((solution_1, solution_2)) => AocAnswers {
+            solution_1: into_c_string(solution_1.to_string()),
+            solution_2: into_c_string(solution_2.to_string()),
+            error: null_mut()
+        },
+        Err(err) => AocAnswers { solution_1: null_mut(), solution_2: null_mut(), error: into_c_string(err.to_string()) }
+    }
+}
+
+/// Releases the strings of a result of `aoc_solve`.
+///
+/// # Safety
+///
+/// `answers` must come from `aoc_solve` and must not have been freed already.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_answers(answers: AocAnswers) {
+    for string in [answers.solution_1, answers.solution_2, answers.error] {
+        if !string.is_null() {
+            drop(CString::from_raw(string));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::{ Answers, Error, Metadata, RuntimeTier, Solution };
+    use std::ffi::CStr;
+
+    unsafe fn read(string: *mut c_char) -> Option<String> {
+        (!string.is_null()).then(|| CStr::from_ptr(string).to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+        unsafe {
+            let answers = aoc_solve(2023, 6, input.as_ptr(), input.len());
+            assert_eq!(read(answers.solution_1), Some("288".to_string()));
+            assert_eq!(read(answers.solution_2), Some("71503".to_string()));
+            assert_eq!(read(answers.error), None);
+            aoc_free_answers(answers);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        unsafe {
+            let answers = aoc_solve(2015, 1, std::ptr::null(), 0);
+            assert_eq!(read(answers.solution_1), None);
+            assert_eq!(read(answers.error), Some("No solution for 2015 day 1".to_string()));
+            aoc_free_answers(answers);
+
+            let answers = aoc_solve(2023, 6, [0xff].as_ptr(), 1);
+            assert!(read(answers.error).unwrap().starts_with("Input is not valid UTF-8"));
+            aoc_free_answers(answers);
+        }
+    }
+
+    /// Panics whatever the input.
+    struct Panics;
+
+    impl Solution for Panics {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2015, day: 1, title: "Panics", tags: &[], runtime: RuntimeTier::Instant, strategies: &[] }
+        }
+
+        fn solve(&self, _input: &str) -> Result<Answers, Error> {
+            panic!("Out of patience")
+        }
+    }
+
+    #[test]
+    fn test_panic() {
+        let mut registry = Registry::new();
+        registry.register(Box::new(Panics));
+        let answers = solve(&registry, 2015, 1, b"input");
+        unsafe {
+            assert_eq!(read(answers.solution_1), None);
+            assert_eq!(read(answers.error), Some("The solution panicked: Out of patience".to_string()));
+            aoc_free_answers(answers);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Private leaderboards for `aoc leaderboard`. The site asks for them to be fetched at most every
+//! 15 minutes, so in between the copy kept in `.cache/leaderboards/` is shown instead.
+
+use crate::download::{ self, Leaderboard, LEADERBOARD_REFRESH };
+use std::path::Path;
+
+const CACHE_DIRECTORY: &str = ".cache/leaderboards";
+
+/// Leaderboard `id` of the event of `year`, downloaded unless the cached copy is recent enough.
+pub fn fetch(year: u16, id: u64) -> Result<Leaderboard, download::Error> {
+    let path = Path::new(CACHE_DIRECTORY).join(format!("{year}-{id}.json"));
+    let age = std::fs::metadata(&path).and_then(|metadata| metadata.modified()).ok().and_then(|modified| modified.elapsed().ok());
+    if let (Some(age), Ok(json)) = (age, std::fs::read_to_string(&path)) {
+        if age < LEADERBOARD_REFRESH {
+            return Leaderboard::parse(&json);
+        }
+    }
+
+    let json = download::leaderboard(year, id, &download::session()?)?;
+    let leaderboard = Leaderboard::parse(&json)?;
+    std::fs::create_dir_all(CACHE_DIRECTORY)?;
+    std::fs::write(&path, json)?;
+    Ok(leaderboard)
+}
+
+/// A row per member, the best first, with the stars of each day: `*` for both parts, `+` for the
+/// first only.
+pub fn table(leaderboard: &Leaderboard) -> String {
+    let mut members: Vec<_> = leaderboard.members.values().collect();
+    // Ties go to the most stars, then to whoever got there first
+    members.sort_by_key(|member| (std::cmp::Reverse((member.local_score, member.stars)), member.last_star_ts, member.id));
+
+    let days = 1..=25u8;
+    let tens: String = days.clone().map(|day| if day < 10 { ' ' } else { char::from(b'0' + day / 10) }).collect();
+    let units: String = days.clone().map(|day| char::from(b'0' + day % 10)).collect();
+    let mut table = format!("{:20}{tens}\n{:>4} {:>6} {:>6}  {units}  Name\n", "", "#", "Score", "Stars");
+    for (rank, member) in members.iter().enumerate() {
+        let stars: String = days
+            .clone()
+            .map(|day| match member.stars_of(day) {
+                0 => '.',
+                1 => '+',
+                _ => '*'
+            })
+            .collect();
+        table += &format!("{:>4} {:>6} {:>6}  {stars}  {}\n", rank + 1, member.local_score, member.stars, member.display_name());
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table() {
+        let json = r#"{"event":"2023","owner_id":1,"members":{
+            "1":{"id":1,"name":"Alice","stars":3,"local_score":10,"last_star_ts":1701500000,"completion_day_level":{"1":{"1":{},"2":{}},"2":{"1":{}}}},
+            "2":{"id":2,"name":null,"stars":0,"local_score":0,"last_star_ts":0,"completion_day_level":{}},
+            "3":{"id":3,"name":"Bob","stars":4,"local_score":10,"last_star_ts":1701600000,"completion_day_level":{"1":{"1":{},"2":{}},"10":{"1":{},"2":{}}}}}}"#;
+        let table = table(&Leaderboard::parse(json).unwrap());
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], format!("{:20}         1111111111222222", ""));
+        assert_eq!(lines[1], "   #  Score  Stars  1234567890123456789012345  Name");
+        assert_eq!(lines[2], "   1     10      4  *........*...............  Bob");
+        assert_eq!(lines[3], "   2     10      3  *+.......................  Alice");
+        assert_eq!(lines[4], "   3      0      0  .........................  (anonymous user #2)");
+    }
+}
@@ -1,5 +1,61 @@
-pub fn clamp<T>(num: T, min: T, max: T) -> T
-where T: Ord
-{
-    std::cmp::max(std::cmp::min(num, max), min)
-}
\ No newline at end of file
+//! Advent of Code solutions, and the tools to run them.
+//!
+//! The shared code lives in the `aoc-core` crate and the solutions in one crate per year, e.g.
+//! `aoc-2023`, behind features such as `year2023`. This crate re-exports them, and adds the
+//! runner: the registry of solutions, the `aoc` command line tool and the bindings. Without the
+//! default `std` feature only the algorithms and data structures of `aoc-core` are built, on
+//! top of `core` and `alloc`, but only as an `rlib`, e.g. with
+//! `cargo rustc --lib --no-default-features --crate-type rlib`. The `cdylib` and `staticlib`
+//! the C interface needs are final artifacts, which need the panic handler and allocator of std,
+//! so a plain `cargo build --no-default-features` fails. `aoc-core` alone builds either way.
+//!
+//! Each day is a unit struct implementing `Solution`, under the module of its year and day, so
+//! that tests, benchmarks and other crates can solve puzzles without going through a binary:
+//!
+//! ```
+//! use advent_of_code::{ solution::{ self, Solution }, y2023::d06::WaitForIt };
+//!
+//! let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+//! assert_eq!(WaitForIt.solve(input).unwrap(), (288.into(), 71503.into()));
+//! assert_eq!(solution::solve_part_2(&WaitForIt, input).unwrap(), 71503.into());
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use aoc_core::*;
+
+#[cfg(feature = "fs")]
+pub mod bench;
+#[cfg(feature = "std")]
+pub mod calendar;
+#[cfg(feature = "fs")]
+pub mod cli;
+#[cfg(all(unix, feature = "fs"))]
+pub mod daemon;
+#[cfg(feature = "fs")]
+pub mod examples;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "download")]
+pub mod leaderboard;
+#[cfg(feature = "async")]
+pub mod pipeline;
+#[cfg(feature = "fs")]
+pub mod profile;
+#[cfg(feature = "download")]
+pub mod puzzles;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "fs")]
+pub mod scaffold;
+#[cfg(feature = "fs")]
+pub mod stats;
+#[cfg(feature = "fs")]
+pub mod verify;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "std")]
+pub use calendar::*;
@@ -0,0 +1,5 @@
+use advent_of_code::{ cli, registry::Registry };
+
+fn main() {
+    cli::main(Registry::builtin());
+}
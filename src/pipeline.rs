@@ -0,0 +1,52 @@
+//! Running many puzzles while their missing inputs are downloaded in the background. Solving
+//! happens one puzzle at a time, so timings aren't skewed, in the order inputs become available.
+
+use crate::{ date::PuzzleDate, inputs, registry::Registry, runner::{ self, Report } };
+use std::sync::Arc;
+use tokio::sync::{ mpsc, Semaphore };
+use tokio::task::spawn_blocking;
+
+/// How many inputs are downloaded at once, to stay polite with the site.
+const MAX_DOWNLOADS: usize = 2;
+
+/// Solves the puzzles of `dates`, handing each report to `on_report` as soon as it's ready.
+/// With `fetch_missing`, inputs not on disk are downloaded and saved first.
+pub async fn run(registry: Arc<Registry>, dates: Vec<PuzzleDate>, fetch_missing: bool, mut on_report: impl FnMut(Report)) {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let downloads = Arc::new(Semaphore::new(MAX_DOWNLOADS));
+
+    for date in dates {
+        let (registry, sender, downloads) = (registry.clone(), sender.clone(), downloads.clone());
+        tokio::spawn(async move {
+            let Some(metadata) = registry.get(date).map(|solution| solution.metadata()) else {
+                return;
+            };
+
+            let input = match spawn_blocking(move || inputs::read(&metadata)).await.unwrap() {
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound && fetch_missing => {
+                    let _permit = downloads.acquire().await.unwrap();
+                    spawn_blocking(move || inputs::fetch(&metadata)).await.unwrap()
+                },
+                input => input.map_err(|err| format!("Failed to read input: {err}").into())
+            };
+
+            // The receiver only goes away once every sender is done
+            let _ = sender.send((date, input));
+        });
+    }
+
+    drop(sender);
+    while let Some((date, input)) = receiver.recv().await {
+        let registry = registry.clone();
+        let report = spawn_blocking(move || {
+            let solution = registry.get(date).unwrap();
+            match input {
+                Ok(input) => runner::run(solution, &input),
+                Err(err) => Report::failed(solution.metadata(), err)
+            }
+        });
+
+        on_report(report.await.unwrap());
+    }
+}
+
@@ -0,0 +1,141 @@
+//! Lightweight profiling without an external profiler, for `aoc profile`. The runner, the shared
+//! helpers and some of the days open `tracing` spans around their phases, and this records how
+//! long each of them took, nested under the spans that were open around it.
+
+use std::sync::{ Arc, Mutex };
+use std::time::{ Duration, Instant };
+use tracing::{ span, Subscriber };
+use tracing_subscriber::{ layer::{ Context, SubscriberExt }, registry::LookupSpan, Layer };
+
+/// The time spent in all the spans with the same name under the same ancestors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// The names of the ancestors of the spans, outermost first, then their own
+    pub path: Vec<&'static str>,
+    pub total: Duration,
+    pub calls: usize
+}
+
+/// Kept in the extensions of each span until it closes.
+struct Started {
+    path: Vec<&'static str>,
+    start: Instant
+}
+
+#[derive(Clone, Default)]
+struct Recorder {
+    /// In the order each path was first seen, so parents come before their children
+    entries: Arc<Mutex<Vec<Entry>>>
+}
+
+impl<S> Layer<S> for Recorder
+where S: Subscriber + for<'a> LookupSpan<'a>
+{
+    fn on_new_span(&self, attributes: &span::Attributes<'_>, id: &span::Id, context: Context<'_, S>) {
+        let Some(span) = context.span(id) else { return };
+        let mut path = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<Started>().map(|started| started.path.clone()))
+            .unwrap_or_default();
+        path.push(attributes.metadata().name());
+
+        let mut entries = self.entries.lock().unwrap();
+        if !entries.iter().any(|entry| entry.path == path) {
+            entries.push(Entry { path: path.clone(), total: Duration::ZERO, calls: 0 });
+        }
+
+        span.extensions_mut().insert(Started { path, start: Instant::now() });
+    }
+
+    fn on_close(&self, id: span::Id, context: Context<'_, S>) {
+        let Some(span) = context.span(&id) else { return };
+        let extensions = span.extensions();
+        let Some(started) = extensions.get::<Started>() else { return };
+
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(entry) = entries.iter_mut().find(|entry| entry.path == started.path) {
+            entry.total += started.start.elapsed();
+            entry.calls += 1;
+        }
+    }
+}
+
+/// Runs `f`, recording the spans it opens. Only spans on the calling thread are recorded, so
+/// the work a day hands to other threads only counts towards the span waiting for it.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Entry>) {
+    let recorder = Recorder::default();
+    let result = tracing::subscriber::with_default(tracing_subscriber::registry().with(recorder.clone()), f);
+    let entries = recorder.entries.lock().unwrap().clone();
+
+    // Spans first opened later under an earlier parent still belong right below that parent
+    let first_seen = |path: &[&str]| entries.iter().position(|entry| entry.path == path).unwrap_or(usize::MAX);
+    let mut keyed: Vec<(Vec<usize>, Entry)> = entries
+        .iter()
+        .map(|entry| ((1..=entry.path.len()).map(|len| first_seen(&entry.path[..len])).collect(), entry.clone()))
+        .collect();
+    keyed.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    (result, keyed.into_iter().map(|(_, entry)| entry).collect())
+}
+
+/// The entries indented by depth, down to `max_depth` levels, with their total time and the
+/// number of calls when there were several.
+pub fn tree(entries: &[Entry], max_depth: usize) -> String {
+    let mut tree = String::new();
+    for entry in entries.iter().filter(|entry| entry.path.len() <= max_depth) {
+        let label = format!("{}{}", "  ".repeat(entry.path.len()), entry.path.last().unwrap());
+        let calls = match entry.calls {
+            1 => String::new(),
+            calls => format!(" x{calls}")
+        };
+        tree += &format!("{label:<40} {:>12}{calls}\n", format!("{:.2?}", entry.total));
+    }
+
+    tree
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tracing::info_span;
+
+    #[test]
+    fn test_record() {
+        let (answer, entries) = record(|| {
+            let outer = info_span!("outer").entered();
+            for _ in 0..3 {
+                let _inner = info_span!("inner").entered();
+            }
+            drop(outer);
+
+            let _other = info_span!("other").entered();
+            42
+        });
+
+        assert_eq!(answer, 42);
+        let summary: Vec<_> = entries.iter().map(|entry| (entry.path.join("/"), entry.calls)).collect();
+        assert_eq!(summary, vec![("outer".to_string(), 1), ("outer/inner".to_string(), 3), ("other".to_string(), 1)]);
+
+        let tree = tree(&entries, 1);
+        assert_eq!(tree.lines().count(), 2);
+        assert!(tree.starts_with("  outer"));
+    }
+
+    #[test]
+    fn test_children_stay_under_their_parent() {
+        let (_, entries) = record(|| {
+            {
+                let _parent = info_span!("parent").entered();
+                let _first = info_span!("first").entered();
+            }
+            {
+                let _sibling = info_span!("sibling").entered();
+            }
+            let _parent = info_span!("parent").entered();
+            let _second = info_span!("second").entered();
+        });
+
+        let paths: Vec<_> = entries.iter().map(|entry| entry.path.join("/")).collect();
+        assert_eq!(paths, vec!["parent", "parent/first", "parent/second", "sibling"]);
+    }
+}
@@ -0,0 +1,206 @@
+//! The puzzle descriptions for `aoc puzzle`, converted to Markdown and kept next to the inputs in
+//! `puzzles/<year>/<day>.md`, so they can be read offline.
+
+use crate::{ date::PuzzleDate, download };
+use std::path::{ Path, PathBuf };
+
+pub const DEFAULT_DIRECTORY: &str = "puzzles";
+
+/// Where the description of the puzzle is kept, under `directory`.
+pub fn path(directory: &Path, date: PuzzleDate) -> PathBuf {
+    directory.join(format!("{}/{:02}.md", date.year(), date.day()))
+}
+
+/// The description of the puzzle, downloaded unless it's kept under `directory` already, or
+/// again with `refresh`, e.g. to get part 2 once part 1 is solved.
+pub fn load(directory: &Path, date: PuzzleDate, refresh: bool) -> Result<String, download::Error> {
+    let path = path(directory, date);
+    if !refresh {
+        if let Ok(markdown) = std::fs::read_to_string(&path) {
+            return Ok(markdown);
+        }
+    }
+
+    // Without a session only part 1 can be seen, which is still worth having
+    let html = download::puzzle(date, &download::session().unwrap_or_default())?;
+    let markdown = to_markdown(&html);
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, &markdown)?;
+    Ok(markdown)
+}
+
+/// The Markdown of the parts of a puzzle page, i.e. its `<article>`s. Only knows the handful of
+/// tags the descriptions use.
+pub fn to_markdown(html: &str) -> String {
+    let articles: Vec<String> = html
+        .split("<article")
+        .skip(1)
+        .map(|article| {
+            // Past the rest of the opening tag
+            let article = article.split_once('>').map_or("", |(_, article)| article);
+            convert(article.split("</article>").next().unwrap_or_default())
+        })
+        .collect();
+
+    articles.join("\n\n") + "\n"
+}
+
+fn convert(html: &str) -> String {
+    let mut markdown = String::new();
+    let (mut pre, mut code) = (false, false);
+    let mut links = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        push_text(&mut markdown, &rest[..start], pre);
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else { break };
+        let tag = &rest[start + 1..end];
+        rest = &rest[end + 1..];
+
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag)
+        };
+        let name = tag.split_whitespace().next().unwrap_or_default().trim_end_matches('/');
+        match (name, closing) {
+            ("h2", false) => markdown += "## ",
+            ("h2", true) | ("p", true) | ("ul", true) => markdown += "\n\n",
+            ("pre", false) => {
+                pre = true;
+                markdown += "```\n";
+            },
+            ("pre", true) => {
+                pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown += "```\n\n";
+            },
+            ("code", opening) if !pre => {
+                code = !opening;
+                markdown.push('`');
+            },
+            // Markers would show as they are inside code
+            ("em", _) if !pre && !code => markdown.push('*'),
+            ("li", false) => markdown += "- ",
+            ("li", true) => markdown.push('\n'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                markdown.push('[');
+            },
+            ("a", true) => markdown += &format!("]({})", absolute(links.pop().unwrap_or_default())),
+            _ => {}
+        }
+    }
+    push_text(&mut markdown, rest, pre);
+
+    let mut lines: Vec<String> = markdown
+        .trim()
+        .lines()
+        .map(|line| match line.strip_prefix("## ") {
+            // `--- Day 6: Wait For It ---` and `--- Part Two ---`
+            Some(title) => format!("## {}", title.trim_matches(|char: char| char == '-' || char.is_whitespace())),
+            None => line.trim_end().to_string()
+        })
+        .collect();
+    lines.dedup_by(|line, previous| line.is_empty() && previous.is_empty());
+    lines.join("\n")
+}
+
+/// Adds text between tags. Outside of `<pre>` its whitespace collapses like in a browser.
+fn push_text(markdown: &mut String, html: &str, pre: bool) {
+    let text = decode_entities(html);
+    if pre {
+        *markdown += &text;
+        return;
+    }
+
+    let mut text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if html.starts_with(char::is_whitespace) && !markdown.is_empty() && !markdown.ends_with(char::is_whitespace) {
+        text.insert(0, ' ');
+    }
+    if html.ends_with(char::is_whitespace) && !text.trim().is_empty() {
+        text.push(' ');
+    }
+    *markdown += &text;
+}
+
+fn decode_entities(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// The value of the attribute `name` of a tag, e.g. the `href` of `a href="/2023/day/5"`.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    Some(&tag[start..start + tag[start..].find('"')?])
+}
+
+/// Links to other pages of the site are relative.
+fn absolute(link: &str) -> String {
+    match link.starts_with('/') {
+        true => format!("https://adventofcode.com{link}"),
+        false => link.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 6: Wait For It ---</h2><p>The ferry quickly brings you across <a href="/2023/day/5">Island Island</a>. After asking around, you discover that there is indeed normally a large pile of sand <em>somewhere</em> near here.</p>
+<ul>
+<li>The first race lasts <code>7</code> milliseconds.</li>
+<li>So, <code><em>4</em></code> ways &amp; more.</li>
+</ul>
+<pre><code>Time:      7  15   30
+Distance:  9  40  200
+</code></pre>
+<p>Determine the number of ways you could beat the record in each race; <em>what do you get if you multiply these numbers together?</em></p>
+</article>
+<p>Your puzzle answer was <code>1159152</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>As the race is about to start, you realize the &lt;fine&gt; print was wrong.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        let expected = "\
+## Day 6: Wait For It
+
+The ferry quickly brings you across [Island Island](https://adventofcode.com/2023/day/5). After asking around, you discover that there is indeed normally a large pile of sand *somewhere* near here.
+
+- The first race lasts `7` milliseconds.
+- So, `4` ways & more.
+
+```
+Time:      7  15   30
+Distance:  9  40  200
+```
+
+Determine the number of ways you could beat the record in each race; *what do you get if you multiply these numbers together?*
+
+## Part Two
+
+As the race is about to start, you realize the <fine> print was wrong.
+";
+        assert_eq!(to_markdown(PAGE), expected);
+    }
+
+    #[test]
+    fn test_load_cached() {
+        let directory = std::env::temp_dir().join(format!("aoc_puzzles_{}", std::process::id()));
+        let date = PuzzleDate::new(2023, 6).unwrap();
+        std::fs::create_dir_all(path(&directory, date).parent().unwrap()).unwrap();
+        std::fs::write(path(&directory, date), "## Day 6: Wait For It\n").unwrap();
+
+        assert_eq!(path(&directory, date), directory.join("2023/06.md"));
+        assert_eq!(load(&directory, date, false).unwrap(), "## Day 6: Wait For It\n");
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
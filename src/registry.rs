@@ -0,0 +1,127 @@
+use crate::{ calendar, date::PuzzleDate, solution::{ Answers, Error, Register, Solution } };
+
+/// The known solutions, looked up by date. Solutions can come from other crates too: anything
+/// implementing `Solution` can be registered, replacing the builtin one for its date if any.
+#[derive(Default)]
+pub struct Registry {
+    solutions: Vec<Box<dyn Solution + Send + Sync>>
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry { solutions: vec![] }
+    }
+
+    /// A registry with every solution in this crate.
+    pub fn builtin() -> Registry {
+        let mut registry = Registry::new();
+        calendar::register_all(&mut registry);
+        registry
+    }
+
+    /// Adds a solution, replacing any previous one for the same date. Panics if the solution
+    /// isn't for a valid date.
+    pub fn register(&mut self, solution: Box<dyn Solution + Send + Sync>) {
+        let date = solution.metadata().date();
+        self.solutions.retain(|other| other.metadata().date() != date);
+
+        let idx = self.solutions.partition_point(|other| other.metadata().date() < date);
+        self.solutions.insert(idx, solution);
+    }
+
+    pub fn get(&self, date: PuzzleDate) -> Option<&(dyn Solution + Send + Sync)> {
+        self.iter().find(|solution| solution.metadata().date() == date)
+    }
+
+    /// Solves the puzzle of the given date, if there is a solution for it.
+    pub fn solve(&self, date: PuzzleDate, input: &str) -> Result<Answers, Error> {
+        self.get(date).ok_or_else(|| format!("No solution for {date}"))?.solve(input)
+    }
+
+    /// All solutions, sorted by date.
+    pub fn iter(&self) -> impl Iterator<Item = &(dyn Solution + Send + Sync)> {
+        self.solutions.iter().map(|solution| solution.as_ref())
+    }
+
+    /// The dates with a solution, in order.
+    pub fn dates(&self) -> Vec<PuzzleDate> {
+        self.iter().map(|solution| solution.metadata().date()).collect()
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.iter().map(|solution| solution.metadata().year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+impl Register for Registry {
+    fn register(&mut self, solution: Box<dyn Solution + Send + Sync>) {
+        Registry::register(self, solution);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::solution::{ Answers, Error, Metadata, RuntimeTier };
+
+    struct Fake(u16, u8, &'static str);
+
+    impl Solution for Fake {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: self.0, day: self.1, title: self.2, tags: &[], runtime: RuntimeTier::Instant, strategies: &[] }
+        }
+
+        fn solve(&self, input: &str) -> Result<Answers, Error> {
+            Ok((input.len().into(), self.2.into()))
+        }
+    }
+
+    fn date(year: u16, day: u8) -> PuzzleDate {
+        PuzzleDate::new(year, day).unwrap()
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = Registry::new();
+        registry.register(Box::new(Fake(2023, 5, "B")));
+        registry.register(Box::new(Fake(2022, 10, "A")));
+        registry.register(Box::new(Fake(2023, 1, "C")));
+        registry.register(Box::new(Fake(2023, 5, "D")));
+
+        let titles: Vec<_> = registry.iter().map(|solution| solution.metadata().title).collect();
+        assert_eq!(titles, vec!["A", "C", "D"]);
+        assert_eq!(registry.years(), vec![2022, 2023]);
+        assert_eq!(registry.dates(), vec![date(2022, 10), date(2023, 1), date(2023, 5)]);
+        assert_eq!(registry.get(date(2023, 5)).unwrap().solve("abc").unwrap(), (3.into(), "D".into()));
+        assert!(registry.get(date(2023, 6)).is_none());
+        assert_eq!(registry.solve(date(2022, 10), "ab").unwrap(), (2.into(), "A".into()));
+        assert_eq!(registry.solve(date(2023, 6), "ab").unwrap_err().to_string(), "No solution for 2023 day 6");
+    }
+
+    #[test]
+    #[should_panic(expected = "Solutions have a valid date")]
+    fn test_register_invalid_date() {
+        Registry::new().register(Box::new(Fake(2023, 26, "Boxing Day")));
+    }
+
+    #[test]
+    fn test_builtin_metadata() {
+        let registry = Registry::builtin();
+        assert!(!registry.is_empty());
+        for solution in registry.iter() {
+            let metadata = solution.metadata();
+            assert!(PuzzleDate::new(metadata.year, metadata.day).is_ok(), "{metadata:?}");
+            assert!(!metadata.title.is_empty(), "{metadata:?}");
+        }
+    }
+}
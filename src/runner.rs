@@ -0,0 +1,607 @@
+use crate::{ answer::Answer, privacy, solution::{ self, Answers, CancellationToken, Error, Metadata, Progress, RuntimeTier, Solution, Stages } };
+use lazy_static::lazy_static;
+use std::any::Any;
+use std::fmt::{ self, Display };
+use std::panic::{ self, AssertUnwindSafe };
+use std::sync::{ mpsc::{ self, RecvTimeoutError }, Arc, Mutex };
+use std::thread::{ self, ThreadId };
+use std::time::{ Duration, Instant };
+
+/// A solution `run` is running, for `interrupted` and `cancel_all` to find.
+struct Running {
+    thread: ThreadId,
+    metadata: Metadata,
+    start: Instant,
+    progress: Arc<Progress>
+}
+
+lazy_static! {
+    static ref RUNNING: Mutex<Vec<Running>> = Mutex::new(vec![]);
+}
+
+/// The outcome of solving one puzzle.
+pub struct Report {
+    pub metadata: Metadata,
+    pub answers: Result<Answers, Error>,
+    pub elapsed: Duration,
+    /// The answers were remembered from a previous run rather than computed
+    pub cached: bool,
+    /// The input that was solved, by `privacy::input_reference`
+    pub input: Option<String>,
+    /// The strategy the runner picked, if it had to
+    pub strategy: Option<&'static str>,
+    /// How long each stage took, for solutions that were run one stage at a time
+    pub stages: Option<StageTimes>
+}
+
+impl Report {
+    /// A report for a puzzle that couldn't even be attempted, e.g. because its input is missing.
+    pub fn failed(metadata: Metadata, error: Error) -> Report {
+        Report { metadata, answers: Err(error), elapsed: Duration::ZERO, cached: false, input: None, strategy: None, stages: None }
+    }
+
+    pub fn cached(metadata: Metadata, answers: Answers) -> Report {
+        Report { metadata, answers: Ok(answers), elapsed: Duration::ZERO, cached: true, input: None, strategy: None, stages: None }
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, title) = (self.metadata.date(), self.metadata.title);
+        // With privacy on, the input is named so that shared reports can still be told apart
+        let title = match (&self.input, privacy::is_enabled()) {
+            (Some(input), true) => format!("{title} [{input}]"),
+            _ => title.to_string()
+        };
+
+        match &self.answers {
+            Ok((solution_1, solution_2)) => {
+                match self.cached {
+                    true => writeln!(f, "{date}: {title} (cached)")?,
+                    false => match self.strategy {
+                        Some(strategy) => writeln!(f, "{date}: {title} ({:.2?}, {strategy})", self.elapsed)?,
+                        None => writeln!(f, "{date}: {title} ({:.2?})", self.elapsed)?
+                    }
+                }
+                writeln!(f, "  Solution 1: {solution_1}")?;
+                write!(f, "  Solution 2: {solution_2}")
+            },
+            Err(err) => match err.downcast_ref::<Interrupted>() {
+                Some(interrupted) => write!(f, "{interrupted}"),
+                None => write!(f, "{date}: {title}\n  Error: {err}")
+            }
+        }
+    }
+}
+
+/// How long each stage of a solution took, see `Solution::stages`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StageTimes {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration
+}
+
+/// Answers longer than this are cut short in `summary_table`.
+const SUMMARY_ANSWER_WIDTH: usize = 16;
+
+/// A table of the answers of each report and how long it took, stage by stage for the solutions
+/// that were run that way, with the total times on the last row.
+pub fn summary_table(reports: &[Report]) -> String {
+    let time = |duration: Option<Duration>| duration.map_or("-".to_string(), |duration| format!("{duration:.2?}"));
+    let answer = |answer: &Answer| {
+        let answer = answer.to_string();
+        match answer.contains('\n') {
+            // A picture OCR couldn't read
+            true => "(picture)".to_string(),
+            false => shorten(&answer, SUMMARY_ANSWER_WIDTH)
+        }
+    };
+
+    let mut table = format!("{:<40} {:<16} {:<16} {:>10} {:>10} {:>10} {:>10}\n", "Puzzle", "Solution 1", "Solution 2", "Parse", "Part 1", "Part 2", "Total");
+    let mut totals = StageTimes::default();
+    for report in reports {
+        let puzzle = shorten(&format!("{}: {}", report.metadata.date(), report.metadata.title), 40);
+        let (solution_1, solution_2) = match &report.answers {
+            Ok((solution_1, solution_2)) => (answer(solution_1), answer(solution_2)),
+            Err(err) if err.is::<TimedOut>() => ("timed out".to_string(), String::new()),
+            Err(_) => ("failed".to_string(), String::new())
+        };
+        let total = match (report.cached, &report.answers) {
+            (true, _) => "cached".to_string(),
+            // Never attempted, e.g. for lack of an input
+            (false, Err(_)) if report.elapsed.is_zero() => time(None),
+            (false, _) => time(Some(report.elapsed))
+        };
+
+        let stages = report.stages;
+        if let Some(stages) = stages {
+            totals.parse += stages.parse;
+            totals.part_1 += stages.part_1;
+            totals.part_2 += stages.part_2;
+        }
+        table += &format!(
+            "{puzzle:<40} {solution_1:<16} {solution_2:<16} {:>10} {:>10} {:>10} {total:>10}\n",
+            time(stages.map(|stages| stages.parse)),
+            time(stages.map(|stages| stages.part_1)),
+            time(stages.map(|stages| stages.part_2))
+        );
+    }
+
+    let total: Duration = reports.iter().map(|report| report.elapsed).sum();
+    let solved = reports.iter().filter(|report| report.answers.is_ok()).count();
+    table += &format!(
+        "{:<74} {:>10} {:>10} {:>10} {:>10}\n",
+        format!("Total, {solved} of {} puzzles solved", reports.len()),
+        time(Some(totals.parse)),
+        time(Some(totals.part_1)),
+        time(Some(totals.part_2)),
+        time(Some(total))
+    );
+
+    table
+}
+
+/// `text` cut to `width` characters, ending with an ellipsis if it was longer.
+fn shorten(text: &str, width: usize) -> String {
+    match text.chars().count() > width {
+        true => text.chars().take(width - 1).chain(['…']).collect(),
+        false => text.to_string()
+    }
+}
+
+/// What a solution had found when its run was cut short. Runs that are cancelled fail with it.
+#[derive(Debug)]
+pub struct Interrupted {
+    pub metadata: Metadata,
+    pub best: [Option<Answer>; 2],
+    pub elapsed: Duration
+}
+
+impl Display for Interrupted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, title) = (self.metadata.date(), self.metadata.title);
+        write!(f, "{date}: {title} (stopped after {:.2?})", self.elapsed)?;
+        for (part, best) in self.best.iter().enumerate() {
+            match best {
+                Some(answer) => write!(f, "\n  Solution {}, best so far: {answer}", part + 1)?,
+                None => write!(f, "\n  Solution {}: unknown", part + 1)?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for Interrupted {}
+
+/// The error of a solution `run_with_time_limit` gave up on.
+#[derive(Debug)]
+pub struct TimedOut {
+    pub time_limit: Duration
+}
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Exceeded the time limit of {:.2?}", self.time_limit)
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Whether to solve with the `PARALLEL` or the `SERIAL` strategy, for solutions that have both.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Parallelism {
+    /// Decided from the runtime tier of the solution, the size of the input and the cores
+    #[default]
+    Auto,
+    Parallel,
+    Serial
+}
+
+/// Inputs at least this large are worth spreading over several cores, however quick the solution.
+const LARGE_INPUT: usize = 64 * 1024;
+
+/// The strategy to solve with, or `None` for the default one if the solution has no choice.
+pub fn choose_strategy(metadata: &Metadata, input_len: usize, cores: usize, parallelism: Parallelism) -> Option<&'static str> {
+    if !metadata.strategies.contains(&solution::PARALLEL) || !metadata.strategies.contains(&solution::SERIAL) {
+        return None;
+    }
+
+    let parallel = match parallelism {
+        Parallelism::Parallel => true,
+        Parallelism::Serial => false,
+        // Starting the thread pool costs more than an instant solution would save
+        Parallelism::Auto => cores > 1 && (metadata.runtime > RuntimeTier::Instant || input_len >= LARGE_INPUT)
+    };
+
+    Some(if parallel { solution::PARALLEL } else { solution::SERIAL })
+}
+
+/// How `run_with` runs a solution.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Settings {
+    /// Cancels the solution once it's been running this long. Solutions that stop on
+    /// cancellation, see `Solution::solve_anytime`, then fail with `Interrupted`
+    pub max_time: Option<Duration>,
+    pub parallelism: Parallelism
+}
+
+/// Solves `input`, timing how long it takes.
+pub fn run(solution: &dyn Solution, input: &str) -> Report {
+    run_with(solution, input, &Settings::default())
+}
+
+/// Like `run`, but a solution that panics fails with the panic message instead of unwinding into
+/// the caller, e.g. to keep a long running process up.
+pub fn run_catching_panics(solution: &dyn Solution, input: &str) -> Report {
+    run_with_catching_panics(solution, input, &Settings::default())
+}
+
+/// Like `run_with`, catching panics the way `run_catching_panics` does.
+fn run_with_catching_panics(solution: &dyn Solution, input: &str, settings: &Settings) -> Report {
+    panic::catch_unwind(AssertUnwindSafe(|| run_with(solution, input, settings))).unwrap_or_else(|payload| {
+        // `run_with` didn't get to forget about the solution
+        let thread = thread::current().id();
+        RUNNING.lock().unwrap().retain(|running| running.thread != thread);
+        Report::failed(solution.metadata(), panic_message(payload).into())
+    })
+}
+
+/// What a caught panic was about, from the payload of `catch_unwind`.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned());
+
+    match message {
+        Some(message) => format!("The solution panicked: {message}"),
+        None => "The solution panicked".to_string()
+    }
+}
+
+/// Like `run`, with more control over how.
+pub fn run_with(solution: &dyn Solution, input: &str, settings: &Settings) -> Report {
+    let metadata = solution.metadata();
+    let cores = thread::available_parallelism().map_or(1, |cores| cores.get());
+    let strategy = choose_strategy(&metadata, input.len(), cores, settings.parallelism);
+    let max_time = settings.max_time;
+    let cancellation = CancellationToken::default();
+    let progress = Arc::new(Progress::new(cancellation.clone()));
+    let start = Instant::now();
+    let thread = thread::current().id();
+    RUNNING.lock().unwrap().push(Running { thread, metadata, start, progress: progress.clone() });
+
+    let span = tracing::info_span!("solve");
+    let answers = thread::scope(|scope| {
+        // Dropping `done` when the solution returns lets the timer go without cancelling
+        let (done, timer) = mpsc::channel::<()>();
+        if let Some(max_time) = max_time {
+            scope.spawn(move || {
+                if timer.recv_timeout(max_time) == Err(RecvTimeoutError::Timeout) {
+                    cancellation.cancel();
+                }
+            });
+        }
+
+        let answers = span.in_scope(|| match (strategy, solution.stages()) {
+            (Some(strategy), _) => solution.solve_with_strategy(input, strategy).map(|answers| (answers, None)),
+            (None, Some(stages)) => run_stages(stages, input).map(|(answers, times)| (answers, Some(times))),
+            (None, None) => solution.solve_anytime(input, &progress).map(|answers| (answers, None))
+        });
+        drop(done);
+        answers
+    });
+
+    let elapsed = start.elapsed();
+    RUNNING.lock().unwrap().retain(|running| running.thread != thread);
+
+    let (answers, stages) = match answers {
+        Ok((answers, stages)) => (Ok(answers), stages),
+        Err(_) if progress.cancellation().is_cancelled() => (Err(Interrupted { metadata, best: progress.best(), elapsed }.into()), None),
+        Err(err) => (Err(err), None)
+    };
+
+    Report { metadata, answers, elapsed, cached: false, input: Some(privacy::input_reference(input)), strategy, stages }
+}
+
+/// Like `run_with`, on a thread of its own that's given up on once it's been running for
+/// `time_limit`, failing with `TimedOut`. Threads can't be killed, so it's cancelled and left to
+/// finish in the background, which only stops the solutions that check for cancellation: one that
+/// doesn't keeps its thread busy for as long as it takes, with no limit on how many pile up. A
+/// solution that panics fails with the panic message, rather than taking the caller down with it.
+pub fn run_with_time_limit(solution: &'static (dyn Solution + Send + Sync), input: &str, settings: &Settings, time_limit: Duration) -> Report {
+    let metadata = solution.metadata();
+    let reference = privacy::input_reference(input);
+    let (input, settings) = (input.to_string(), *settings);
+    let (finished, report) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        // Nobody is waiting for the report anymore if the time limit is up
+        let _ = finished.send(run_with_catching_panics(solution, &input, &settings));
+    });
+
+    let answers = match report.recv_timeout(time_limit) {
+        Ok(report) => return report,
+        Err(RecvTimeoutError::Timeout) => {
+            cancel(worker.thread().id());
+            Err(TimedOut { time_limit }.into())
+        },
+        Err(RecvTimeoutError::Disconnected) => Err("The solution panicked".into())
+    };
+
+    Report { metadata, answers, elapsed: time_limit, cached: false, input: Some(reference), strategy: None, stages: None }
+}
+
+/// Solves `input` one stage after the other, timing each. The stages show up as spans of their
+/// own when profiling.
+fn run_stages(stages: &dyn Stages, input: &str) -> Result<(Answers, StageTimes), Error> {
+    let start = Instant::now();
+    let parsed = tracing::info_span!("parse").in_scope(|| stages.parse(input))?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let solution_1 = tracing::info_span!("part_1").in_scope(|| stages.part_1(parsed.as_ref()))?;
+    let part_1 = start.elapsed();
+
+    let start = Instant::now();
+    let solution_2 = tracing::info_span!("part_2").in_scope(|| stages.part_2(parsed.as_ref()))?;
+    let part_2 = start.elapsed();
+
+    Ok(((solution_1, solution_2), StageTimes { parse, part_1, part_2 }))
+}
+
+/// Cancels every solution `run` is currently running.
+pub fn cancel_all() {
+    for running in RUNNING.lock().unwrap().iter() {
+        running.progress.cancellation().cancel();
+    }
+}
+
+/// Cancels the solution running on `thread`, if any.
+fn cancel(thread: ThreadId) {
+    for running in RUNNING.lock().unwrap().iter().filter(|running| running.thread == thread) {
+        running.progress.cancellation().cancel();
+    }
+}
+
+/// The progress of the solutions `run` is currently running. Meant for when the user gives up
+/// on them, e.g. with Ctrl+C, and they don't stop when cancelled.
+pub fn interrupted() -> Vec<Interrupted> {
+    RUNNING
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|running| Interrupted { metadata: running.metadata, best: running.progress.best(), elapsed: running.start.elapsed() })
+        .collect()
+}
+
+/// Solves `input` `runs` times, failing if the answers ever change, e.g. because of state kept
+/// in statics from one run to the next, or of threads racing each other. The hash maps of the
+/// days are FxHash ones, which iterate in the same order every run, so answers depending on that
+/// order don't show up here.
+pub fn check_determinism(solution: &dyn Solution, input: &str, runs: usize) -> Result<Answers, Error> {
+    let first = solution.solve(input)?;
+    for run in 2..=runs {
+        let answers = solution.solve(input)?;
+        if answers != first {
+            let (expected_1, expected_2) = &first;
+            let (solution_1, solution_2) = &answers;
+            return Err(format!("Run {run} answered {solution_1} and {solution_2} instead of {expected_1} and {expected_2}").into());
+        }
+    }
+
+    Ok(first)
+}
+
+/// Solves the input at `path` while it's still being read, see `Solution::solve_lines`.
+#[cfg(feature = "fs")]
+pub fn run_pipelined(solution: &dyn Solution, path: &std::path::Path) -> Report {
+    let start = Instant::now();
+    let read_error = |err: std::io::Error| Error::from(format!("Failed to read input: {err}"));
+    let answers = match crate::inputs::PipelinedLines::open(path) {
+        // A read error cut the input short, so it trumps whatever the solution made of it
+        Ok(mut lines) => {
+            let answers = solution.solve_lines(&mut lines);
+            lines.finish().map_err(read_error).and(answers)
+        },
+        Err(err) => Err(read_error(err))
+    };
+
+    Report { metadata: solution.metadata(), answers, elapsed: start.elapsed(), cached: false, input: None, strategy: None, stages: None }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::calendar::{ y2022::d01::CalorieCounting, y2023::d06::WaitForIt };
+    use crate::solution::RuntimeTier;
+    use std::collections::HashSet;
+
+    /// Answers with whatever number a std hash set, randomly seeded on each run, iterates first.
+    struct HashOrder;
+
+    impl Solution for HashOrder {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2015, day: 1, title: "Hash Order", tags: &[], runtime: RuntimeTier::Instant, strategies: &[] }
+        }
+
+        fn solve(&self, _input: &str) -> Result<Answers, Error> {
+            let numbers: HashSet<u32> = (0..1000).collect();
+            Ok((numbers.into_iter().next().unwrap().into(), 0.into()))
+        }
+    }
+
+    /// Gives its first answer, then waits for the test to interrupt it.
+    struct Stuck(std::sync::mpsc::Sender<()>, std::sync::Mutex<std::sync::mpsc::Receiver<()>>);
+
+    impl Solution for Stuck {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2015, day: 2, title: "Stuck", tags: &[], runtime: RuntimeTier::Slow, strategies: &[] }
+        }
+
+        fn solve(&self, _input: &str) -> Result<Answers, Error> {
+            Err("Only solves anytime".into())
+        }
+
+        fn solve_anytime(&self, _input: &str, progress: &Progress) -> Result<Answers, Error> {
+            progress.improve(1, 42);
+            self.0.send(()).unwrap();
+            self.1.lock().unwrap().recv().unwrap();
+            Err("Interrupted".into())
+        }
+    }
+
+    #[test]
+    fn test_interrupted() {
+        let (started_tx, started_rx) = std::sync::mpsc::channel();
+        let (resume_tx, resume_rx) = std::sync::mpsc::channel();
+        let stuck = Stuck(started_tx, resume_rx.into());
+        std::thread::scope(|scope| {
+            scope.spawn(|| run(&stuck, ""));
+            started_rx.recv().unwrap();
+
+            let interrupted = interrupted().into_iter().find(|interrupted| interrupted.metadata.title == "Stuck").unwrap();
+            assert_eq!(interrupted.best, [Some(42.into()), None]);
+            assert!(interrupted.to_string().ends_with("\n  Solution 1, best so far: 42\n  Solution 2: unknown"));
+            resume_tx.send(()).unwrap();
+        });
+    }
+
+    /// Keeps improving its first answer until cancelled.
+    struct Endless;
+
+    impl Solution for Endless {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2015, day: 3, title: "Endless", tags: &[], runtime: RuntimeTier::Slow, strategies: &[] }
+        }
+
+        fn solve(&self, input: &str) -> Result<Answers, Error> {
+            self.solve_anytime(input, &Progress::default())
+        }
+
+        fn solve_anytime(&self, _input: &str, progress: &Progress) -> Result<Answers, Error> {
+            let mut answer = 0;
+            loop {
+                progress.cancellation().check()?;
+                answer += 1;
+                progress.improve(1, answer);
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_with_max_time() {
+        let report = run_with(&Endless, "", &Settings { max_time: Some(Duration::from_millis(20)), ..Settings::default() });
+        let interrupted = report.answers.as_ref().unwrap_err().downcast_ref::<Interrupted>().unwrap();
+        assert!(matches!(interrupted.best, [Some(Answer::Number(_)), None]));
+        assert!(report.to_string().starts_with("2015 day 3: Endless (stopped after"));
+
+        let report = run_with(&WaitForIt, "Time: 7\nDistance: 9\n", &Settings { max_time: Some(Duration::from_secs(60)), ..Settings::default() });
+        assert!(report.answers.is_ok());
+    }
+
+    /// Takes its time, without ever checking for cancellation.
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2015, day: 5, title: "Sleepy", tags: &[], runtime: RuntimeTier::Slow, strategies: &[] }
+        }
+
+        fn solve(&self, _input: &str) -> Result<Answers, Error> {
+            std::thread::sleep(Duration::from_millis(500));
+            Ok((1.into(), 2.into()))
+        }
+    }
+
+    /// Gives up halfway through.
+    struct Panics;
+
+    impl Solution for Panics {
+        fn metadata(&self) -> Metadata {
+            Metadata { year: 2015, day: 6, title: "Panics", tags: &[], runtime: RuntimeTier::Instant, strategies: &[] }
+        }
+
+        fn solve(&self, _input: &str) -> Result<Answers, Error> {
+            panic!("Out of patience")
+        }
+    }
+
+    #[test]
+    fn test_run_with_time_limit() {
+        let report = run_with_time_limit(&Sleepy, "", &Settings::default(), Duration::from_millis(20));
+        assert!(report.answers.as_ref().is_err_and(|err| err.is::<TimedOut>()));
+        assert_eq!(report.to_string(), "2015 day 5: Sleepy\n  Error: Exceeded the time limit of 20.00ms");
+        assert!(summary_table(&[report]).lines().nth(1).unwrap().contains(" timed out "));
+
+        let report = run_with_time_limit(&WaitForIt, "Time: 7\nDistance: 9\n", &Settings::default(), Duration::from_secs(60));
+        assert_eq!(report.answers.unwrap(), (4.into(), 4.into()));
+
+        let report = run_with_time_limit(&Panics, "", &Settings::default(), Duration::from_secs(60));
+        assert_eq!(report.to_string(), "2015 day 6: Panics\n  Error: The solution panicked: Out of patience");
+    }
+
+    #[test]
+    fn test_choose_strategy() {
+        let both = Metadata { year: 2015, day: 4, title: "Both", tags: &[], runtime: RuntimeTier::Instant, strategies: &["parallel", "serial"] };
+        assert_eq!(choose_strategy(&both, 100, 8, Parallelism::Auto), Some("serial"));
+        assert_eq!(choose_strategy(&both, LARGE_INPUT, 8, Parallelism::Auto), Some("parallel"));
+        assert_eq!(choose_strategy(&both, LARGE_INPUT, 1, Parallelism::Auto), Some("serial"));
+        assert_eq!(choose_strategy(&both, 100, 1, Parallelism::Parallel), Some("parallel"));
+
+        let slow = Metadata { runtime: RuntimeTier::Fast, ..both };
+        assert_eq!(choose_strategy(&slow, 100, 8, Parallelism::Auto), Some("parallel"));
+        assert_eq!(choose_strategy(&slow, 100, 8, Parallelism::Serial), Some("serial"));
+
+        let neither = Metadata { strategies: &["quadratic", "brute_force"], ..both };
+        assert_eq!(choose_strategy(&neither, LARGE_INPUT, 8, Parallelism::Parallel), None);
+    }
+
+    #[test]
+    fn test_check_determinism() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+        assert_eq!(check_determinism(&WaitForIt, input, 3).unwrap(), (288.into(), 71503.into()));
+
+        let err = check_determinism(&HashOrder, "", 20).unwrap_err();
+        assert!(err.to_string().contains("instead of"));
+    }
+
+    #[test]
+    fn test_report() {
+        let report = run(&WaitForIt, "Time:      7  15   30\nDistance:  9  40  200\n");
+        let output = report.to_string();
+        assert!(output.starts_with("2023 day 6: Wait For It ("));
+        assert!(output.ends_with("  Solution 1: 288\n  Solution 2: 71503"));
+
+        let report = Report::failed(WaitForIt.metadata(), "No input".into());
+        assert_eq!(report.to_string(), "2023 day 6: Wait For It\n  Error: No input");
+
+        let report = Report::cached(WaitForIt.metadata(), (1.into(), 2.into()));
+        assert_eq!(report.to_string(), "2023 day 6: Wait For It (cached)\n  Solution 1: 1\n  Solution 2: 2");
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let staged = run(&CalorieCounting, "1000\n\n2000\n\n3000\n");
+        assert_eq!(staged.answers.as_ref().unwrap(), &(3000.into(), 6000.into()));
+        let stages = staged.stages.unwrap();
+        assert!(stages.parse + stages.part_1 + stages.part_2 <= staged.elapsed);
+
+        let whole = run(&WaitForIt, "Time:      7  15   30\nDistance:  9  40  200\n");
+        assert_eq!(whole.stages, None);
+
+        let long = Report::cached(WaitForIt.metadata(), ("x".repeat(20).into(), Answer::Grid(vec![vec![true; 3]; 2])));
+        let failed = Report::failed(CalorieCounting.metadata(), "No input".into());
+        let table = summary_table(&[staged, whole, long, failed]);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 6);
+        assert!(lines[1].starts_with("2022 day 1: Calorie Counting") && lines[1].contains(" 3000 ") && !lines[1].contains(" - "));
+        assert!(lines[2].starts_with("2023 day 6: Wait For It") && lines[2].contains(" 288 ") && lines[2].contains(" - "));
+        assert!(lines[3].contains(&format!(" {}… (picture) ", "x".repeat(15))) && lines[3].ends_with(" cached"));
+        assert!(lines[4].contains(" failed ") && lines[4].ends_with(" -"));
+        assert!(lines[5].starts_with("Total, 3 of 4 puzzles solved"));
+    }
+}
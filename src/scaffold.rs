@@ -0,0 +1,489 @@
+//! Starts the solution of a new day with `aoc new`: the source files with the usual layout in
+//! the crate of the year, their registration in it, the day's binary and the folder for its
+//! input. The crate of a new year is created and added to the calendar too.
+
+use crate::{ date::PuzzleDate, inputs };
+use std::io;
+use std::path::{ Path, PathBuf };
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("The title must have at least a letter or digit")]
+    InvalidTitle,
+    #[error("{0} already exists")]
+    AlreadyExists(PathBuf),
+    #[error("Didn't find where to add the new day in {0}")]
+    UnexpectedLayout(PathBuf),
+    #[error("{0}")]
+    IoError(#[from] io::Error)
+}
+
+const SOLUTION_TEMPLATE: &str = r#"use crate::prelude::*;
+
+#[derive(thiserror::Error, Debug)]
+enum Error {
+    #[error("Failed to parse line: {0}")]
+    ParsingError(Excerpt)
+}
+
+fn parse_input(input: &str) -> Result<Vec<String>, Error> {
+    input
+        .lines()
+        .map(|line| match line.trim() {
+            "" => Err(Error::ParsingError(line.into())),
+            line => Ok(line.to_string())
+        })
+        .collect()
+}
+
+pub struct $name;
+
+impl Solver for $name {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolutionError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, _parsed: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Err("Part 1 isn't solved yet".into())
+    }
+
+    fn part_2(&self, _parsed: &Self::Parsed) -> Result<Answer, SolutionError> {
+        Err("Part 2 isn't solved yet".into())
+    }
+}
+
+impl Solution for $name {
+    fn metadata(&self) -> Metadata {
+        Metadata {
+            year: $year,
+            day: $day,
+            title: $title,
+            tags: &[],
+            runtime: RuntimeTier::Instant,
+            strategies: &[]
+        }
+    }
+
+    fn solve(&self, input: &str) -> Result<Answers, SolutionError> {
+        solve_parts(self, input)
+    }
+
+    fn stages(&self) -> Option<&dyn Stages> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    fn test_parse() {
+        assert!(parse_input(EXAMPLE).is_ok());
+    }
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"use aoc_$year::d$padded_day::$name;
+use aoc_core::solution;
+
+fn main() {
+    solution::run_from_file(&$name, "$input");
+}
+"#;
+
+const YEAR_TEMPLATE: &str = "\
+//! The solutions of $year, a module per day.
+
+use aoc_core::solution::Register;
+// The days use the shared code as if it were part of this crate, e.g. `crate::prelude`
+use aoc_core::*;
+
+pub fn register(registry: &mut impl Register) {
+}
+";
+
+const CRATE_TEMPLATE: &str = r#"[package]
+name = "aoc-$year"
+version.workspace = true
+edition.workspace = true
+
+# A binary per day, solving its input
+
+[dependencies]
+aoc-core = { workspace = true, features = ["std"] }
+serde = { workspace = true, optional = true }
+thiserror = { workspace = true, features = ["std"] }
+
+[features]
+default = ["fs"]
+# Reading inputs from disk, for the binaries
+fs = ["aoc-core/fs"]
+# Serialize and Deserialize for the parsed puzzle inputs
+serde = ["dep:serde", "aoc-core/serde"]
+
+[lints]
+workspace = true
+"#;
+
+/// Creates the files of the puzzle titled `title` under the repository at `root`, and adds it
+/// to the crate of its year, creating the crate if needed. Returns the paths created or
+/// changed, relative to `root`. Nothing is written if the day already exists.
+pub fn create(root: &Path, date: PuzzleDate, title: &str) -> Result<Vec<PathBuf>, Error> {
+    let name = inputs::pascal_case(title);
+    if name.is_empty() {
+        return Err(Error::InvalidTitle);
+    }
+
+    let (year, day) = (date.year(), date.day());
+    let crate_directory = PathBuf::from(format!("crates/aoc-{year}"));
+    let year_directory = crate_directory.join("src");
+    let day_directory = format!("{day:02}_{name}");
+    let directory = year_directory.join(&day_directory);
+    if let Some(existing) = existing_day(&root.join(&year_directory), day)? {
+        return Err(Error::AlreadyExists(year_directory.join(existing)));
+    }
+
+    let input = inputs::path_for(date, title);
+    let fill = |template: &str| {
+        template
+            .replace("$name", &name)
+            .replace("$year", &year.to_string())
+            .replace("$padded_day", &format!("{day:02}"))
+            .replace("$day", &day.to_string())
+            .replace("$title", &format!("{title:?}"))
+            .replace("$input", &input.to_string_lossy())
+    };
+
+    let mut files = vec![
+        (directory.join("solution.rs"), fill(SOLUTION_TEMPLATE)),
+        (directory.join("main.rs"), fill(MAIN_TEMPLATE))
+    ];
+
+    let year_module = year_directory.join("lib.rs");
+    let year_manifest = crate_directory.join("Cargo.toml");
+    let (year_source, year_manifest_source) = match std::fs::read_to_string(root.join(&year_module)) {
+        Ok(source) => (source, std::fs::read_to_string(root.join(&year_manifest))?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let calendar_module = PathBuf::from("src/calendar.rs");
+            let calendar_source = std::fs::read_to_string(root.join(&calendar_module))?;
+            let declaration = format!("#[cfg(feature = \"year{year}\")]\npub use aoc_{year} as y{year};");
+            let registration = format!("    #[cfg(feature = \"year{year}\")]\n    y{year}::register(registry);");
+            let calendar_source = insert_module(&calendar_source, &declaration, &registration)
+                .ok_or_else(|| Error::UnexpectedLayout(calendar_module.clone()))?;
+            files.push((calendar_module, calendar_source));
+
+            let manifest = PathBuf::from("Cargo.toml");
+            let manifest_source = std::fs::read_to_string(root.join(&manifest))?;
+            let manifest_source = add_year(&manifest_source, year)
+                .ok_or_else(|| Error::UnexpectedLayout(manifest.clone()))?;
+            files.push((manifest, manifest_source));
+
+            (fill(YEAR_TEMPLATE), fill(CRATE_TEMPLATE))
+        },
+        Err(err) => return Err(err.into())
+    };
+
+    let declaration = format!("#[path = \"{day_directory}/solution.rs\"]\npub mod d{day:02};");
+    let registration = format!("    registry.register(Box::new(d{day:02}::{name}));");
+    let year_source = insert_module(&year_source, &declaration, &registration)
+        .ok_or_else(|| Error::UnexpectedLayout(year_module.clone()))?;
+    files.push((year_module, year_source));
+
+    let main = Path::new("src").join(&day_directory).join("main.rs");
+    let year_manifest_source = add_binary(&year_manifest_source, date, &main)
+        .ok_or_else(|| Error::UnexpectedLayout(year_manifest.clone()))?;
+    files.push((year_manifest, year_manifest_source));
+
+    for (path, source) in &files {
+        std::fs::create_dir_all(root.join(path).parent().unwrap())?;
+        std::fs::write(root.join(path), source)?;
+    }
+
+    let input_directory = input.parent().unwrap().to_path_buf();
+    std::fs::create_dir_all(root.join(&input_directory))?;
+
+    let mut created: Vec<PathBuf> = files.into_iter().map(|(path, _)| path).collect();
+    created.push(input_directory);
+    Ok(created)
+}
+
+/// The folder of the solution of `day`, whatever its title, if there's one already.
+fn existing_day(year_directory: &Path, day: u8) -> io::Result<Option<String>> {
+    let entries = match std::fs::read_dir(year_directory) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err)
+    };
+
+    for entry in entries {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name.starts_with(&format!("{day:02}_")) {
+            return Ok(Some(name));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Adds a module to the calendar or to a year: its `declaration` among the others of the same
+/// kind (`pub mod` or `pub use`) and its `registration` in `register`, both in order and each
+/// with the attributes on their first lines. `None` if the source has no `register`.
+fn insert_module(source: &str, declaration: &str, registration: &str) -> Option<String> {
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+    let module = declaration.lines().last()?;
+    let kind = &module[..module.match_indices(' ').nth(1)?.0 + 1];
+
+    let modules: Vec<usize> = (0..lines.len()).filter(|index| lines[*index].starts_with(kind)).collect();
+    let register = lines.iter().position(|line| line.starts_with("pub fn register"))?;
+    let (at, new_lines) = match modules.iter().find(|index| lines[**index].as_str() > module) {
+        // Before the `#[path]` or `#[cfg]` of the next module
+        Some(next) if *next > 0 && lines[next - 1].starts_with("#[") => (next - 1, vec![]),
+        Some(next) => (*next, vec![]),
+        None => match modules.last() {
+            Some(last) => (last + 1, vec![]),
+            None => (register, vec![String::new()])
+        }
+    };
+    lines.splice(at..at, declaration.lines().map(str::to_string).chain(new_lines));
+
+    // Registrations can be behind a `#[cfg]` too, which stays with the registration it gates
+    let is_attribute = |line: &str| line.trim_start().starts_with("#[");
+    let key = registration.lines().last()?;
+    let register = lines.iter().position(|line| line.starts_with("pub fn register"))?;
+    let end = register + lines[register..].iter().position(|line| line == "}")?;
+    let mut at = (register + 1..end).find(|index| !is_attribute(&lines[*index]) && lines[*index].as_str() > key).unwrap_or(end);
+    while at > register + 1 && is_attribute(&lines[at - 1]) {
+        at -= 1;
+    }
+    lines.splice(at..at, registration.lines().map(str::to_string));
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the `[[bin]]` of the day running `main` to the manifest of its year, in order among
+/// those of the other days. `None` if the manifest has no binary or heading to follow.
+fn add_binary(manifest: &str, date: PuzzleDate, main: &Path) -> Option<String> {
+    let mut lines: Vec<String> = manifest.lines().map(str::to_string).collect();
+    let name = format!("name = \"aoc_{}_{:02}\"", date.year(), date.day());
+    let binary = [
+        "[[bin]]".to_string(),
+        name.clone(),
+        format!("path = \"{}\"", main.display()),
+        "required-features = [\"fs\"]".to_string()
+    ];
+
+    let days: Vec<usize> = (1..lines.len())
+        .filter(|index| lines[index - 1] == "[[bin]]" && lines[*index].starts_with("name = \"aoc_"))
+        .collect();
+    match days.iter().find(|index| lines[**index] > name) {
+        Some(next) => {
+            let at = next - 1;
+            lines.splice(at..at, binary.into_iter().chain([String::new()]));
+        },
+        None => {
+            // After the last day, or right under the heading of the binaries in a new crate
+            let (last, separator) = match days.last() {
+                Some(last) => (*last, vec![String::new()]),
+                None => (lines.iter().position(|line| line.starts_with("# A binary per day"))?, vec![])
+            };
+            let end = (last + 1..lines.len()).find(|index| lines[*index].is_empty() || lines[*index].starts_with('[')).unwrap_or(lines.len());
+            lines.splice(end..end, separator.into_iter().chain(binary));
+        }
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds the crate of `year` to the root manifest: its dependency and feature, and next to each
+/// item naming the closest year in the other features, the same for `year`. `None` if the
+/// manifest has no year yet.
+fn add_year(manifest: &str, year: u16) -> Option<String> {
+    let closest = manifest
+        .lines()
+        .filter_map(|line| line.strip_prefix("aoc-")?.split_once(" = ")?.0.parse::<u16>().ok())
+        .min_by_key(|other| (other.abs_diff(year), *other > year))?;
+    let names = [format!("aoc-{closest}"), format!("year{closest}")];
+    let of_year = |text: &str| text.replace(&names[0], &format!("aoc-{year}")).replace(&names[1], &format!("year{year}"));
+    let in_order = |item: String| match closest < year {
+        true => [item.clone(), of_year(&item)],
+        false => [of_year(&item), item]
+    };
+
+    let mut lines: Vec<String> = vec![];
+    for line in manifest.lines() {
+        if names.iter().any(|name| line.starts_with(&format!("{name} = "))) {
+            lines.extend(in_order(line.to_string()));
+            continue;
+        }
+
+        // The quoted item naming the closest year, e.g. `"dep:aoc-2023"` or `"year2023"`
+        let mut line = line.to_string();
+        if let Some(at) = names.iter().find_map(|name| line.find(name)).filter(|_| !line.starts_with('#')) {
+            let start = line[..at].rfind('"')?;
+            let end = at + line[at..].find('"')?;
+            let items = in_order(line[start..=end].to_string()).join(", ");
+            line.replace_range(start..=end, &items);
+        }
+        lines.push(line);
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const YEAR: &str = "\
+use aoc_core::solution::Register;
+
+#[path = \"01_First/solution.rs\"]
+pub mod d01;
+#[path = \"05_Fifth/solution.rs\"]
+pub mod d05;
+
+pub fn register(registry: &mut impl Register) {
+    registry.register(Box::new(d01::First));
+    registry.register(Box::new(d05::Fifth));
+}
+";
+
+    const YEAR_MANIFEST: &str = "\
+[package]
+name = \"aoc-2023\"
+
+# A binary per day, solving its input
+[[bin]]
+name = \"aoc_2023_01\"
+path = \"src/01_First/main.rs\"
+required-features = [\"fs\"]
+
+[[bin]]
+name = \"aoc_2023_05\"
+path = \"src/05_Fifth/main.rs\"
+required-features = [\"fs\"]
+
+[dependencies]
+";
+
+    const MANIFEST: &str = "\
+[dependencies]
+aoc-2023 = { path = \"crates/aoc-2023\", default-features = false, optional = true }
+aoc-core.workspace = true
+
+[features]
+default = [\"std\", \"all-years\"]
+all-years = [\"year2023\"]
+year2023 = [\"std\", \"dep:aoc-2023\"]
+serde = [\"aoc-core/serde\", \"aoc-2023?/serde\"]
+";
+
+    const CALENDAR: &str = "\
+use crate::registry::Registry;
+
+#[cfg(feature = \"year2023\")]
+pub use aoc_2023 as y2023;
+
+pub fn register_all(registry: &mut Registry) {
+    #[cfg(feature = \"year2023\")]
+    y2023::register(registry);
+}
+";
+
+    fn insert_day(source: &str, day: u8, name: &str) -> String {
+        let declaration = format!("#[path = \"{day:02}_{name}/solution.rs\"]\npub mod d{day:02};");
+        insert_module(source, &declaration, &format!("    registry.register(Box::new(d{day:02}::{name}));")).unwrap()
+    }
+
+    #[test]
+    fn test_insert_module() {
+        let between = insert_day(YEAR, 3, "Third");
+        assert!(between.contains("pub mod d01;\n#[path = \"03_Third/solution.rs\"]\npub mod d03;\n#[path = \"05_Fifth"));
+        assert!(between.contains("(d01::First));\n    registry.register(Box::new(d03::Third));\n    registry.register(Box::new(d05"));
+
+        let last = insert_day(YEAR, 9, "Ninth");
+        assert!(last.contains("pub mod d05;\n#[path = \"09_Ninth/solution.rs\"]\npub mod d09;\n\npub fn"));
+        assert!(last.contains("(d05::Fifth));\n    registry.register(Box::new(d09::Ninth));\n}"));
+
+        let first = insert_day(YEAR_TEMPLATE, 2, "Second");
+        assert!(first.ends_with("use aoc_core::*;\n\n#[path = \"02_Second/solution.rs\"]\npub mod d02;\n\npub fn register(registry: &mut impl Register) {\n    registry.register(Box::new(d02::Second));\n}\n"));
+        assert_eq!(insert_module("pub mod d01;", "pub mod d02;", "    registry.register(Box::new(d02::Second));"), None);
+
+        let declaration = "#[cfg(feature = \"year2022\")]\npub use aoc_2022 as y2022;";
+        let year = insert_module(CALENDAR, declaration, "    #[cfg(feature = \"year2022\")]\n    y2022::register(registry);").unwrap();
+        assert!(year.contains("Registry;\n\n#[cfg(feature = \"year2022\")]\npub use aoc_2022 as y2022;\n#[cfg(feature = \"year2023\")]\npub use"));
+        assert!(year.contains("{\n    #[cfg(feature = \"year2022\")]\n    y2022::register(registry);\n    #[cfg(feature = \"year2023\")]\n    y2023::register(registry);\n}"));
+    }
+
+    #[test]
+    fn test_add_binary() {
+        let date = |day| PuzzleDate::new(2023, day).unwrap();
+        let main = |day| PathBuf::from(format!("src/{day:02}_New/main.rs"));
+
+        let between = add_binary(YEAR_MANIFEST, date(3), &main(3)).unwrap();
+        assert!(between.contains("01_First/main.rs\"\nrequired-features = [\"fs\"]\n\n[[bin]]\nname = \"aoc_2023_03\"\npath = \"src/03_New/main.rs\"\nrequired-features = [\"fs\"]\n\n[[bin]]\nname = \"aoc_2023_05\""));
+
+        let last = add_binary(YEAR_MANIFEST, date(7), &main(7)).unwrap();
+        assert!(last.contains("05_Fifth/main.rs\"\nrequired-features = [\"fs\"]\n\n[[bin]]\nname = \"aoc_2023_07\"\npath = \"src/07_New/main.rs\"\nrequired-features = [\"fs\"]\n\n[dependencies]"));
+
+        let first = add_binary(CRATE_TEMPLATE, date(2), &main(2)).unwrap();
+        assert!(first.contains("# A binary per day, solving its input\n[[bin]]\nname = \"aoc_2023_02\"\npath = \"src/02_New/main.rs\"\nrequired-features = [\"fs\"]\n\n[dependencies]"));
+
+        assert_eq!(add_binary("[dependencies]\n", date(2), &main(2)), None);
+    }
+
+    #[test]
+    fn test_add_year() {
+        let later = add_year(MANIFEST, 2024).unwrap();
+        assert!(later.contains("optional = true }\naoc-2024 = { path = \"crates/aoc-2024\", default-features = false, optional = true }\naoc-core"));
+        assert!(later.contains("all-years = [\"year2023\", \"year2024\"]\nyear2023 = [\"std\", \"dep:aoc-2023\"]\nyear2024 = [\"std\", \"dep:aoc-2024\"]\n"));
+        assert!(later.contains("serde = [\"aoc-core/serde\", \"aoc-2023?/serde\", \"aoc-2024?/serde\"]"));
+
+        let earlier = add_year(MANIFEST, 2022).unwrap();
+        assert!(earlier.contains("[dependencies]\naoc-2022 = { path = \"crates/aoc-2022\", default-features = false, optional = true }\naoc-2023"));
+        assert!(earlier.contains("year2022 = [\"std\", \"dep:aoc-2022\"]\nyear2023"));
+
+        assert_eq!(add_year("[dependencies]\n", 2024), None);
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("crates/aoc-2023/src")).unwrap();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        std::fs::write(root.join("src/calendar.rs"), CALENDAR).unwrap();
+        std::fs::write(root.join("crates/aoc-2023/Cargo.toml"), YEAR_MANIFEST).unwrap();
+        std::fs::write(root.join("crates/aoc-2023/src/lib.rs"), YEAR).unwrap();
+
+        let date = PuzzleDate::new(2024, 6).unwrap();
+        let created = create(&root, date, "Guard Gallivant").unwrap();
+        assert!(created.contains(&PathBuf::from("src/calendar.rs")));
+        assert!(created.contains(&PathBuf::from("crates/aoc-2024/Cargo.toml")));
+        assert!(created.contains(&PathBuf::from("inputs/2024/06")));
+        assert!(root.join("inputs/2024/06").is_dir());
+
+        let solution = std::fs::read_to_string(root.join("crates/aoc-2024/src/06_GuardGallivant/solution.rs")).unwrap();
+        assert!(solution.contains("pub struct GuardGallivant;") && solution.contains("title: \"Guard Gallivant\","));
+        let main = std::fs::read_to_string(root.join("crates/aoc-2024/src/06_GuardGallivant/main.rs")).unwrap();
+        assert!(main.contains("use aoc_2024::d06::GuardGallivant;") && main.contains("\"inputs/2024/06/input.txt\""));
+        assert!(std::fs::read_to_string(root.join("crates/aoc-2024/src/lib.rs")).unwrap().contains("registry.register(Box::new(d06::GuardGallivant));"));
+        assert!(std::fs::read_to_string(root.join("crates/aoc-2024/Cargo.toml")).unwrap().contains("name = \"aoc_2024_06\"\npath = \"src/06_GuardGallivant/main.rs\""));
+        assert!(std::fs::read_to_string(root.join("src/calendar.rs")).unwrap().contains("y2024::register(registry);"));
+        assert!(std::fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("year2024 = [\"std\", \"dep:aoc-2024\"]"));
+
+        let next = create(&root, PuzzleDate::new(2023, 3).unwrap(), "Gear Ratios").unwrap();
+        assert!(!next.contains(&PathBuf::from("src/calendar.rs")));
+        assert!(std::fs::read_to_string(root.join("crates/aoc-2023/Cargo.toml")).unwrap().contains("name = \"aoc_2023_03\""));
+
+        assert!(matches!(create(&root, date, "Another Title"), Err(Error::AlreadyExists(_))));
+        assert!(matches!(create(&root, PuzzleDate::new(2024, 7).unwrap(), "?!"), Err(Error::InvalidTitle)));
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
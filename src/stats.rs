@@ -0,0 +1,227 @@
+//! The state of the whole repository, year by year for `aoc stats` and `aoc status`, and puzzle
+//! by puzzle for `aoc report`.
+
+use crate::{ answer::Answer, cache::{ Stars, Timings }, counter::Counter, date::PuzzleDate, registry::Registry, runner::Report, solution::Metadata, verify::ExpectedAnswers };
+use std::time::Duration;
+
+/// What there is to know about the solutions of one year.
+pub struct YearStats {
+    pub year: u16,
+    pub days: usize,
+    /// Stars earned on the site, if they were ever synced
+    pub stars: Option<u8>,
+    /// The sum of the last recorded timing of each day, for the `timed` days that have one
+    pub total: Duration,
+    pub timed: usize,
+    pub slowest: Option<(u8, Duration)>,
+    /// How many days have each tag, the most common first
+    pub tags: Vec<(&'static str, usize)>
+}
+
+pub fn collect(registry: &Registry, timings: &Timings, stars: &Stars) -> Vec<YearStats> {
+    registry
+        .years()
+        .into_iter()
+        .map(|year| {
+            let days: Vec<_> = registry.iter().map(|solution| solution.metadata()).filter(|metadata| metadata.year == year).collect();
+            let times: Vec<_> = days.iter().filter_map(|metadata| Some((metadata.day, timings.get(metadata.date())?))).collect();
+
+            let mut tags = Counter::new();
+            for tag in days.iter().flat_map(|metadata| metadata.tags) {
+                tags.add(*tag);
+            }
+
+            let mut tags: Vec<_> = tags.iter().map(|(tag, count)| (*tag, *count)).collect();
+            tags.sort_by(|(left, left_count), (right, right_count)| right_count.cmp(left_count).then(left.cmp(right)));
+
+            YearStats {
+                year,
+                days: days.len(),
+                stars: stars.get(year),
+                total: times.iter().map(|(_, time)| *time).sum(),
+                timed: times.len(),
+                slowest: times.iter().copied().max_by_key(|(_, time)| *time),
+                tags
+            }
+        })
+        .collect()
+}
+
+/// A table with a row per year, with the most common tags below it.
+pub fn table(stats: &[YearStats]) -> String {
+    const MAX_TAGS: usize = 8;
+
+    let mut table = format!("{:<6} {:>5} {:>6} {:>12} {:>20}\n", "Year", "Days", "Stars", "Total", "Slowest");
+    for year in stats {
+        let stars = year.stars.map_or("-".to_string(), |stars| format!("{stars}/50"));
+        let total = match year.timed {
+            0 => "-".to_string(),
+            timed if timed < year.days => format!("{:.2?} ({timed})", year.total),
+            _ => format!("{:.2?}", year.total)
+        };
+        let slowest = year.slowest.map_or("-".to_string(), |(day, time)| format!("day {day}, {time:.2?}"));
+        table += &format!("{:<6} {:>5} {stars:>6} {total:>12} {slowest:>20}\n", year.year, year.days);
+
+        if !year.tags.is_empty() {
+            let tags: Vec<_> = year.tags.iter().take(MAX_TAGS).map(|(tag, count)| format!("{tag} {count}")).collect();
+            table += &format!("       {}\n", tags.join(", "));
+        }
+    }
+
+    table
+}
+
+/// How far along a day is, for `aoc status`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayStatus {
+    Missing,
+    /// Solved, but without expected answers for `aoc verify` to check
+    Solved,
+    Verified
+}
+
+/// The status of every day of a year, in order.
+pub struct YearStatus {
+    pub year: u16,
+    pub days: Vec<DayStatus>
+}
+
+/// The status of each year with a solution.
+pub fn status(registry: &Registry, expected: &ExpectedAnswers) -> Vec<YearStatus> {
+    registry
+        .years()
+        .into_iter()
+        .map(|year| {
+            let days = PuzzleDate::days_of(year)
+                .unwrap()
+                .map(|date| match (registry.get(date), expected.get(date)) {
+                    (None, _) => DayStatus::Missing,
+                    (Some(_), None) => DayStatus::Solved,
+                    (Some(_), Some(_)) => DayStatus::Verified
+                })
+                .collect();
+
+            YearStatus { year, days }
+        })
+        .collect()
+}
+
+/// A row per year, with a column per day: `*` if it's verified, `o` if it's only solved, `.` if
+/// it's missing.
+pub fn status_table(status: &[YearStatus]) -> String {
+    let days = 1..=PuzzleDate::LAST_DAY;
+    let tens: String = days.clone().map(|day| if day < 10 { ' ' } else { char::from(b'0' + day / 10) }).collect();
+    let units: String = days.map(|day| char::from(b'0' + day % 10)).collect();
+    let mut table = format!("{:6}{tens}
+{:<6}{units} {:>7} {:>9}
+", "", "Year", "Solved", "Verified");
+    for year in status {
+        let count = |status: &[DayStatus]| year.days.iter().filter(|day| status.contains(day)).count();
+        let days: String = year
+            .days
+            .iter()
+            .map(|day| match day {
+                DayStatus::Missing => '.',
+                DayStatus::Solved => 'o',
+                DayStatus::Verified => '*'
+            })
+            .collect();
+        let solved = format!("{}/{}", count(&[DayStatus::Solved, DayStatus::Verified]), year.days.len());
+        table += &format!("{:<6}{days} {solved:>7} {:>9}
+", year.year, count(&[DayStatus::Verified]));
+    }
+
+    table
+}
+
+/// A Markdown table of the answers and runtime of each puzzle, for `aoc report` to keep an
+/// overview of the repository up to date.
+pub fn results_markdown(reports: &[Report]) -> String {
+    let cell = |answer: &Answer| {
+        let answer = answer.to_string();
+        match answer.contains('\n') {
+            // A picture OCR couldn't read
+            true => "(picture)".to_string(),
+            false => answer.replace('|', "\\|")
+        }
+    };
+
+    let mut table = "| Year | Day | Puzzle | Part 1 | Part 2 | Runtime |\n| ---: | --: | :----- | -----: | -----: | ------: |\n".to_string();
+    for report in reports {
+        let Metadata { year, day, title, .. } = report.metadata;
+        let row = match &report.answers {
+            Ok((solution_1, solution_2)) => format!("{} | {} | {:.2?}", cell(solution_1), cell(solution_2), report.elapsed),
+            Err(_) => "- | - | failed".to_string()
+        };
+        table += &format!("| {year} | {day} | {title} | {row} |\n");
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let directory = std::env::temp_dir().join(format!("aoc_stats_{}", std::process::id()));
+        let mut timings = Timings::open(directory.join("timings.tsv")).unwrap();
+        let mut stars = Stars::open(directory.join("stars.tsv")).unwrap();
+        let registry = Registry::builtin();
+        timings.record(&registry.get("2023-06".parse().unwrap()).unwrap().metadata(), Duration::from_millis(2));
+        timings.record(&registry.get("2023-08".parse().unwrap()).unwrap().metadata(), Duration::from_millis(5));
+        stars.set(2023, 34);
+
+        let stats = collect(&registry, &timings, &stars);
+        let year = stats.iter().find(|stats| stats.year == 2023).unwrap();
+        assert_eq!(year.days, registry.iter().filter(|solution| solution.metadata().year == 2023).count());
+        assert_eq!((year.stars, year.total, year.timed), (Some(34), Duration::from_millis(7), 2));
+        assert_eq!(year.slowest, Some((8, Duration::from_millis(5))));
+        assert!(year.tags.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(table(&stats).lines().any(|row| row.starts_with("2023") && row.contains("34/50") && row.contains("day 8, 5.00ms")));
+
+        timings.save().unwrap();
+        stars.save().unwrap();
+        assert_eq!(Timings::open(directory.join("timings.tsv")).unwrap().get("2023-08".parse().unwrap()), Some(Duration::from_millis(5)));
+        assert_eq!(Stars::open(directory.join("stars.tsv")).unwrap().get(2023), Some(34));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_status() {
+        let path = std::env::temp_dir().join(format!("aoc_status_{}.tsv", std::process::id()));
+        let mut expected = ExpectedAnswers::open(&path).unwrap();
+        expected.set("2023-01".parse().unwrap(), (1.into(), 2.into()));
+        let registry = Registry::builtin();
+
+        let status = status(&registry, &expected);
+        let year = status.iter().find(|status| status.year == 2023).unwrap();
+        assert_eq!(year.days.len(), 25);
+        assert_eq!(year.days[0], DayStatus::Verified);
+        assert_eq!(year.days[1], DayStatus::Solved);
+        assert_eq!(year.days[24], DayStatus::Missing);
+
+        let table = status_table(&status);
+        let row = table.lines().find(|row| row.starts_with("2023")).unwrap();
+        let solved = registry.iter().filter(|solution| solution.metadata().year == 2023).count();
+        assert!(row.starts_with("2023  *o"));
+        assert!(row.ends_with(&format!(" {solved}/25         1")));
+    }
+
+    #[test]
+    fn test_results_markdown() {
+        let registry = Registry::builtin();
+        let metadata = |date: &str| registry.get(date.parse().unwrap()).unwrap().metadata();
+        let reports = [
+            Report::cached(metadata("2022-05"), ("CMZ".into(), "a|b".into())),
+            Report::failed(metadata("2023-06"), "No input".into())
+        ];
+
+        let markdown = results_markdown(&reports);
+        let rows: Vec<&str> = markdown.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[2], "| 2022 | 5 | Supply Stacks | CMZ | a\\|b | 0.00ns |");
+        assert_eq!(rows[3], "| 2023 | 6 | Wait For It | - | - | failed |");
+    }
+}
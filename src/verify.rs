@@ -0,0 +1,168 @@
+//! The known-correct answers of each puzzle, kept next to the inputs they're for, so that every
+//! solution can be checked against them after a refactor with `aoc verify`.
+
+use crate::{ answer::Answer, cache, date::PuzzleDate, solution::{ Answers, Error } };
+use std::collections::HashMap;
+use std::fmt::{ self, Display };
+use std::io;
+use std::path::{ Path, PathBuf };
+
+/// The accepted answers, one tab-separated `year day part answer` entry per line.
+pub struct ExpectedAnswers {
+    path: PathBuf,
+    answers: HashMap<PuzzleDate, Answers>
+}
+
+impl ExpectedAnswers {
+    pub const DEFAULT_PATH: &str = "inputs/answers.tsv";
+
+    /// Loads the answers at `path`, none if the file doesn't exist yet. Days missing either
+    /// part are left out, as if unknown.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<ExpectedAnswers> {
+        let path = path.as_ref().to_path_buf();
+        let mut parts: HashMap<PuzzleDate, [Option<Answer>; 2]> = HashMap::new();
+        for line in cache::read_or_empty(&path)?.lines() {
+            let [year, day, part, answer] = line.split('\t').collect::<Vec<_>>()[..] else { continue };
+            let (Ok(year), Ok(day), Ok(part @ 1..=2)) = (year.parse(), day.parse(), part.parse::<usize>()) else { continue };
+            let Ok(date) = PuzzleDate::new(year, day) else { continue };
+            parts.entry(date).or_default()[part - 1] = Some(cache::unescape(answer).parse().unwrap());
+        }
+
+        let answers = parts
+            .into_iter()
+            .filter_map(|(date, parts)| match parts {
+                [Some(solution_1), Some(solution_2)] => Some((date, (solution_1, solution_2))),
+                _ => None
+            })
+            .collect();
+
+        Ok(ExpectedAnswers { path, answers })
+    }
+
+    pub fn get(&self, date: PuzzleDate) -> Option<&Answers> {
+        self.answers.get(&date)
+    }
+
+    pub fn set(&mut self, date: PuzzleDate, answers: Answers) {
+        self.answers.insert(date, answers);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let lines = self
+            .answers
+            .iter()
+            .flat_map(|(date, answers)| {
+                [(1, &answers.0), (2, &answers.1)]
+                    .map(|(part, answer)| format!("{}\t{}\t{part}\t{}\n", date.year(), date.day(), cache::escape(&answer.to_string())))
+            })
+            .collect();
+
+        cache::write_sorted(&self.path, lines)
+    }
+}
+
+/// How a solution did against the expected answers.
+#[derive(Debug)]
+pub enum Verdict {
+    Correct,
+    /// The parts that differ, with the expected answer and the one given
+    Wrong(Vec<(u8, Answer, Answer)>),
+    /// There's no expected answer to check against, with the answers given
+    Unknown(Answers),
+    Failed(Error)
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&Answers>, answers: Result<Answers, Error>) -> Verdict {
+        let answers = match answers {
+            Ok(answers) => answers,
+            Err(err) => return Verdict::Failed(err)
+        };
+
+        let Some((expected_1, expected_2)) = expected else {
+            return Verdict::Unknown(answers);
+        };
+
+        let (solution_1, solution_2) = answers;
+        // Answers compare as displayed, so a grid matches the letters recorded for it
+        let wrong: Vec<_> = [(1, expected_1, solution_1), (2, expected_2, solution_2)]
+            .into_iter()
+            .filter(|(_, expected, answer)| *expected != answer)
+            .map(|(part, expected, answer)| (part, expected.clone(), answer))
+            .collect();
+
+        match wrong.is_empty() {
+            true => Verdict::Correct,
+            false => Verdict::Wrong(wrong)
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(wrong) => {
+                write!(f, "wrong")?;
+                for (part, expected, answer) in wrong {
+                    write!(f, "\n  Solution {part}: {answer}, expected {expected}")?;
+                }
+                Ok(())
+            },
+            Verdict::Unknown(_) => write!(f, "no expected answers"),
+            Verdict::Failed(err) => write!(f, "failed\n  Error: {err}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_expected_answers() {
+        let path = std::env::temp_dir().join(format!("aoc_expected_answers_{}.tsv", std::process::id()));
+        let date = PuzzleDate::new(2022, 10).unwrap();
+        let mut expected = ExpectedAnswers::open(&path).unwrap();
+        assert_eq!(expected.get(date), None);
+
+        expected.set(date, (13140.into(), "#..#\n#..#".into()));
+        expected.save().unwrap();
+
+        let expected = ExpectedAnswers::open(&path).unwrap();
+        assert_eq!(expected.get(date), Some(&(13140.into(), "#..#\n#..#".into())));
+        assert_eq!(expected.get(PuzzleDate::new(2022, 11).unwrap()), None);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_verdict() {
+        let expected: Answers = (1.into(), 2.into());
+        assert!(matches!(Verdict::new(Some(&expected), Ok((1.into(), 2.into()))), Verdict::Correct));
+        assert!(matches!(Verdict::new(None, Ok((1.into(), 2.into()))), Verdict::Unknown(_)));
+        assert!(matches!(Verdict::new(Some(&expected), Err("oops".into())), Verdict::Failed(_)));
+
+        let wrong = Verdict::new(Some(&expected), Ok((1.into(), 3.into())));
+        assert_eq!(wrong.to_string(), "wrong\n  Solution 2: 3, expected 2");
+    }
+
+    #[test]
+    fn test_verify_grid() {
+        // The letters H and L, read back from the file as text
+        let grid = || {
+            let rows = ["#..#.#...", "#..#.#...", "####.#...", "#..#.#...", "#..#.#...", "#..#.####"];
+            Answer::Grid(rows.iter().map(|row| row.chars().map(|ch| ch == '#').collect()).collect())
+        };
+
+        let path = std::env::temp_dir().join(format!("aoc_expected_grid_{}.tsv", std::process::id()));
+        let date = PuzzleDate::new(2022, 10).unwrap();
+        let mut expected = ExpectedAnswers::open(&path).unwrap();
+        expected.set(date, (13140.into(), grid()));
+        expected.save().unwrap();
+
+        let expected = ExpectedAnswers::open(&path).unwrap();
+        assert!(matches!(expected.get(date), Some((_, Answer::Text(letters))) if letters == "HL"));
+        assert!(matches!(Verdict::new(expected.get(date), Ok((13140.into(), grid()))), Verdict::Correct));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
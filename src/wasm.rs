@@ -0,0 +1,43 @@
+//! Bindings for running the solutions from JavaScript, e.g. in a browser. Build them with
+//! `wasm-pack build --no-default-features --features wasm,all-years`, or with cargo for the
+//! `wasm32-unknown-unknown` target followed by `wasm-bindgen`. Pick years instead of
+//! `all-years` for a smaller module.
+
+use crate::{ date::PuzzleDate, registry::Registry };
+use lazy_static::lazy_static;
+use wasm_bindgen::prelude::*;
+
+lazy_static! {
+    static ref REGISTRY: Registry = Registry::builtin();
+}
+
+/// Solves the puzzle of the given date. Returns both answers as strings, or throws an `Error`
+/// with the failure message.
+#[wasm_bindgen]
+pub fn solve(year: u16, day: u8, input: &str) -> Result<Vec<String>, JsError> {
+    let (solution_1, solution_2) = PuzzleDate::new(year, day)
+        .map_err(Into::into)
+        .and_then(|date| REGISTRY.solve(date, input))
+        .map_err(|err| JsError::new(&err.to_string()))?;
+    Ok(vec![solution_1.to_string(), solution_2.to_string()])
+}
+
+/// What the playground needs to list a solution.
+#[wasm_bindgen(getter_with_clone)]
+pub struct SolutionInfo {
+    pub year: u16,
+    pub day: u8,
+    pub title: String
+}
+
+/// Every available solution, sorted by date.
+#[wasm_bindgen]
+pub fn solutions() -> Vec<SolutionInfo> {
+    REGISTRY
+        .iter()
+        .map(|solution| {
+            let metadata = solution.metadata();
+            SolutionInfo { year: metadata.year, day: metadata.day, title: metadata.title.to_string() }
+        })
+        .collect()
+}
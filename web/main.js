@@ -0,0 +1,39 @@
+import init, { solve, solutions } from "./pkg/advent_of_code.js";
+
+const form = document.getElementById("form");
+const puzzle = document.getElementById("puzzle");
+const input = document.getElementById("input");
+const results = document.getElementById("results");
+const error = document.getElementById("error");
+
+await init();
+
+for (const solution of solutions()) {
+    const option = document.createElement("option");
+    option.value = `${solution.year}/${solution.day}`;
+    option.textContent = `${solution.year} day ${solution.day}: ${solution.title}`;
+    puzzle.append(option);
+    solution.free();
+}
+
+form.addEventListener("submit", (event) => {
+    event.preventDefault();
+    const [year, day] = puzzle.value.split("/").map(Number);
+
+    try {
+        const start = performance.now();
+        const [solution1, solution2] = solve(year, day, input.value);
+        const elapsed = performance.now() - start;
+
+        document.getElementById("solution-1").textContent = solution1;
+        document.getElementById("solution-2").textContent = solution2;
+        document.getElementById("time").textContent = `${elapsed.toFixed(1)} ms`;
+        results.hidden = false;
+        error.hidden = true;
+    }
+    catch (err) {
+        error.textContent = err.message;
+        error.hidden = false;
+        results.hidden = true;
+    }
+});